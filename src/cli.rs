@@ -0,0 +1,516 @@
+use clap::error::ErrorKind;
+use clap::parser::ValueSource;
+use clap::{Args, CommandFactory, FromArgMatches, Parser, Subcommand};
+use crate::output::OutputFormat;
+
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[command(flatten)]
+    pub display: DisplayArgs,
+
+    #[command(flatten)]
+    pub legacy: LegacyArgs,
+}
+
+impl Cli {
+    /// Parse the command line, legacy flags given along with a command are rejected (e.g. `-o json search example`)
+    pub fn parse_args() -> Self {
+        let matches = Cli::command().get_matches();
+        let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|error| error.exit());
+
+        if let Some(command_name) = matches.subcommand_name() {
+            let legacy_args = LegacyArgs::augment_args(clap::Command::new("legacy"));
+            let legacy_flag = legacy_args
+                .get_arguments()
+                .find(|arg| matches.value_source(arg.get_id().as_str()) == Some(ValueSource::CommandLine));
+
+            if let Some(arg) = legacy_flag {
+                let flag = arg.get_long().map(|long| format!("--{}", long)).unwrap_or_else(|| arg.get_id().to_string());
+                Cli::command()
+                    .error(ErrorKind::ArgumentConflict, format!("'{}' cannot be used with the '{}' command", flag, command_name))
+                    .exit();
+            }
+        }
+
+        cli
+    }
+}
+
+// Options shared by every command
+#[derive(Args, Debug)]
+pub struct DisplayArgs {
+    /// Only show results matching a filter expression (e.g. "available && len(name) <= 8 && tld in (com, io)")
+    #[arg(short, long, global = true, default_value = "")]
+    pub filter: String,
+
+    /// Hide names containing offensive or negative words (same as adding "!blocked" to the filter)
+    #[arg(long, global = true, default_value_t = false)]
+    pub hide_blocked: bool,
+
+    /// Only check the trademarks registered in comma separated Nice classes (e.g. "9, 42")
+    #[arg(long, global = true, default_value = "")]
+    pub trademark_classes: String,
+
+    /// Minimum delay between two requests to the provider, in milliseconds (default: none, 500 for scan jobs)
+    #[arg(long, global = true, value_name = "MS")]
+    pub request_delay: Option<u64>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Init app and database
+    Init,
+
+    /// Search domain names, labels (e.g. "example") or complete domain names (e.g. "example.io")
+    Search {
+        /// Names to search, comma separated or not (e.g. "example, example.io")
+        #[arg(required = true)]
+        names: Vec<String>,
+
+        /// Also search hyphen, numeral and plural variants of a multi-word search (e.g. "my cool app")
+        #[arg(long, default_value_t = false)]
+        variants: bool,
+
+        /// Also search synonyms and related words of the names (e.g. "cloud")
+        #[arg(long, default_value_t = false, conflicts_with = "variants")]
+        expand: bool,
+
+        /// Number of expansion levels with --expand (related words of related words...)
+        #[arg(long, default_value_t = 1, requires = "expand")]
+        depth: usize,
+
+        /// Maximum number of names searched with --expand
+        #[arg(long, default_value_t = 20, requires = "expand")]
+        limit: usize,
+
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+
+    /// Show and manage favorite domains
+    Wishlist {
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+
+    /// Show and manage domain extensions
+    Extensions {
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+
+    /// Generate brandable names with a Markov chain and search them
+    Markov {
+        /// Number of names to generate
+        count: usize,
+
+        /// Word list used to train the Markov chain (default: bundled corpus)
+        #[arg(long, default_value = "")]
+        corpus: String,
+
+        /// Number of previous characters used to pick the next one
+        #[arg(long, default_value_t = 2)]
+        order: usize,
+
+        /// Minimum length of generated names
+        #[arg(long, default_value_t = 4)]
+        min_length: usize,
+
+        /// Maximum length of generated names
+        #[arg(long, default_value_t = 8)]
+        max_length: usize,
+
+        /// Randomness of generated names (higher values give more unusual names)
+        #[arg(long, default_value_t = 1.0)]
+        temperature: f64,
+    },
+
+    /// Blend two comma separated keyword lists (e.g. "cloud, data" "forge, nest") and search them
+    Blend {
+        left: String,
+        right: String,
+
+        #[command(flatten)]
+        limit: LimitArgs,
+    },
+
+    /// Search typos and lookalikes of a domain name you own (brand protection)
+    Typos {
+        name: String,
+
+        #[command(flatten)]
+        limit: LimitArgs,
+    },
+
+    /// Search every label matching a letter pattern (e.g. "CVCV", "??x?", "[aeiou]zz[a-z]")
+    Pattern {
+        pattern: String,
+
+        #[command(flatten)]
+        limit: LimitArgs,
+    },
+
+    /// Search comma separated words along with their synonyms and related words (same as search --expand)
+    Expand {
+        words: String,
+
+        /// Number of expansion levels (related words of related words...)
+        #[arg(long, default_value_t = 1)]
+        depth: usize,
+
+        #[command(flatten)]
+        limit: LimitArgs,
+    },
+
+    /// Search every combination of words from two or more word list files
+    Combine {
+        #[arg(num_args = 2.., required = true, value_name = "FILE")]
+        files: Vec<String>,
+
+        /// Separators used between combined words (e.g. --separator "" --separator "-")
+        #[arg(long, value_name = "SEPARATOR")]
+        separator: Vec<String>,
+
+        /// Search a random sample of combinations instead of the first ones when there are more than the limit
+        #[arg(long, default_value_t = false)]
+        sample: bool,
+
+        #[command(flatten)]
+        limit: LimitArgs,
+    },
+
+    /// Search acronyms and abbreviations of a phrase (e.g. "International Widget Logistics")
+    Acronyms {
+        phrase: String,
+
+        #[command(flatten)]
+        limit: LimitArgs,
+    },
+
+    /// Search domain hacks of a word using known extensions (e.g. "delicious" => "delicio.us")
+    Hacks {
+        word: String,
+
+        #[command(flatten)]
+        limit: LimitArgs,
+    },
+
+    /// Start a resumable scan job of every label matching a letter pattern
+    Scan {
+        pattern: String,
+    },
+
+    /// Show and manage scan jobs
+    Jobs {
+        #[command(subcommand)]
+        command: Option<JobsCommand>,
+    },
+
+    /// Import thesaurus, reserved names, valuation rules, blocklist or trademarks
+    Import {
+        #[command(subcommand)]
+        command: ImportCommand,
+    },
+
+    /// Show the valuation rules giving the value tier of available domains
+    ValueRules,
+
+    /// Show and add the domains you own, names close to them are flagged
+    Owned {
+        #[command(subcommand)]
+        command: Option<OwnedCommand>,
+    },
+}
+
+#[derive(Args, Debug)]
+pub struct OutputArgs {
+    /// Print results for scripts instead of showing the interactive table
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+}
+
+#[derive(Args, Debug)]
+pub struct LimitArgs {
+    /// Maximum number of generated names to search
+    #[arg(long, default_value_t = 20)]
+    pub limit: usize,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum JobsCommand {
+    /// Show scan jobs
+    List,
+
+    /// Show the available domains found by a scan job
+    Show { id: i64 },
+
+    /// Resume a paused or interrupted scan job
+    Resume { id: i64 },
+
+    /// Pause a running scan job
+    Pause { id: i64 },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ImportCommand {
+    /// Import a thesaurus file ("word: related, words" on each line)
+    Thesaurus { file: String },
+
+    /// Import a list of reserved names published by a registry ("name" or "name, reason" on each line)
+    Reserved {
+        file: String,
+
+        /// Extension of the imported reserved names ("*" for every extension)
+        #[arg(long, default_value = "*")]
+        tld: String,
+    },
+
+    /// Import valuation rules from a JSON file (e.g. [{"kind":"tld","value":"xyz","points":5,"description":".xyz"}])
+    ValueRules { file: String },
+
+    /// Import a blocklist of offensive or negative words ("word", "=word" for whole names or "language: words")
+    Blocklist {
+        file: String,
+
+        /// Language of the imported words when not given on the line
+        #[arg(long, default_value = "custom")]
+        language: String,
+
+        /// Flag the words in every workspace instead of the current directory only
+        #[arg(long)]
+        global: bool,
+    },
+
+    /// Import trademarks from a CSV or XML export of a trademark office
+    Trademarks {
+        file: String,
+
+        /// Office of the imported trademarks when not given in the file (e.g. "EUIPO")
+        #[arg(long, default_value = "")]
+        office: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum OwnedCommand {
+    /// Show the domains you own
+    List,
+
+    /// Add comma separated domains you own (e.g. "example.com, example.io")
+    Add { domains: String },
+}
+
+// Flags used before subcommands, kept as deprecated aliases (e.g. `--search example` for `search example`)
+#[derive(Args, Debug)]
+pub struct LegacyArgs {
+    #[arg(short, long, hide = true, default_value = "")]
+    search: String,
+
+    #[arg(long, hide = true, default_value_t = false)]
+    variants: bool,
+
+    #[arg(short, long, hide = true, default_value_t = false)]
+    extensions: bool,
+
+    #[arg(short, long, hide = true, default_value_t = false)]
+    wishlist: bool,
+
+    #[arg(short, long, hide = true, default_value_t = false)]
+    init: bool,
+
+    #[arg(short, long, hide = true, default_value_t = 0)]
+    markov: usize,
+
+    #[arg(long, hide = true, default_value = "")]
+    corpus: String,
+
+    #[arg(long, hide = true, default_value_t = 2)]
+    order: usize,
+
+    #[arg(long, hide = true, default_value_t = 4)]
+    min_length: usize,
+
+    #[arg(long, hide = true, default_value_t = 8)]
+    max_length: usize,
+
+    #[arg(long, hide = true, default_value_t = 1.0)]
+    temperature: f64,
+
+    #[arg(short, long, hide = true, num_args = 2, value_names = ["LEFT", "RIGHT"])]
+    blend: Vec<String>,
+
+    #[arg(short, long, hide = true, default_value = "")]
+    typos: String,
+
+    #[arg(short, long, hide = true, default_value = "")]
+    pattern: String,
+
+    #[arg(short = 'x', long, hide = true, default_value = "")]
+    expand: String,
+
+    #[arg(long, hide = true, default_value_t = 1)]
+    depth: usize,
+
+    #[arg(long, hide = true, default_value = "")]
+    import_thesaurus: String,
+
+    #[arg(short, long, hide = true, num_args = 2.., value_name = "FILE")]
+    combine: Vec<String>,
+
+    #[arg(long, hide = true, value_name = "SEPARATOR")]
+    separator: Vec<String>,
+
+    #[arg(long, hide = true, default_value_t = false)]
+    sample: bool,
+
+    #[arg(short, long, hide = true, default_value = "")]
+    acronyms: String,
+
+    #[arg(long, hide = true, default_value = "")]
+    import_reserved: String,
+
+    #[arg(long, hide = true, default_value = "*")]
+    reserved_tld: String,
+
+    #[arg(long, hide = true, default_value_t = false)]
+    value_rules: bool,
+
+    #[arg(long, hide = true, default_value = "")]
+    import_value_rules: String,
+
+    #[arg(long, hide = true, default_value = "")]
+    import_blocklist: String,
+
+    #[arg(long, hide = true, default_value = "custom")]
+    blocklist_language: String,
+
+    #[arg(long, hide = true, default_value = "")]
+    import_trademarks: String,
+
+    #[arg(long, hide = true, default_value = "")]
+    trademark_office: String,
+
+    #[arg(long, hide = true, default_value = "")]
+    add_owned: String,
+
+    #[arg(long, hide = true, default_value_t = false)]
+    owned: bool,
+
+    #[arg(long, hide = true, default_value = "")]
+    hacks: String,
+
+    #[arg(long, hide = true, default_value = "")]
+    scan: String,
+
+    #[arg(long, hide = true, default_value_t = false)]
+    jobs: bool,
+
+    #[arg(long, hide = true, value_name = "ID")]
+    job: Option<i64>,
+
+    #[arg(long, hide = true, value_name = "ID")]
+    resume: Option<i64>,
+
+    #[arg(long, hide = true, value_name = "ID")]
+    pause: Option<i64>,
+
+    #[arg(long, hide = true, default_value_t = 20)]
+    limit: usize,
+
+    #[arg(short, long, hide = true, value_enum, default_value_t = OutputFormat::Table)]
+    output: OutputFormat,
+}
+
+impl LegacyArgs {
+    /// Get the subcommand matching the legacy flags, along with the flag used (e.g. "--search")
+    pub fn into_command(self) -> Option<(&'static str, Command)> {
+        let limit = LimitArgs { limit: self.limit };
+
+        let command = if !self.search.is_empty() {
+            ("--search", Command::Search {
+                names: vec![self.search],
+                variants: self.variants,
+                expand: false,
+                depth: self.depth,
+                limit: self.limit,
+                output: OutputArgs { output: self.output },
+            })
+        } else if self.markov > 0 {
+            ("--markov", Command::Markov {
+                count: self.markov,
+                corpus: self.corpus,
+                order: self.order,
+                min_length: self.min_length,
+                max_length: self.max_length,
+                temperature: self.temperature,
+            })
+        } else if let [left, right] = &self.blend[..] {
+            ("--blend", Command::Blend { left: left.clone(), right: right.clone(), limit })
+        } else if !self.typos.is_empty() {
+            ("--typos", Command::Typos { name: self.typos, limit })
+        } else if !self.pattern.is_empty() {
+            ("--pattern", Command::Pattern { pattern: self.pattern, limit })
+        } else if !self.expand.is_empty() {
+            ("--expand", Command::Search {
+                names: vec![self.expand],
+                variants: false,
+                expand: true,
+                depth: self.depth,
+                limit: self.limit,
+                output: OutputArgs { output: self.output },
+            })
+        } else if !self.import_thesaurus.is_empty() {
+            ("--import-thesaurus", Command::Import { command: ImportCommand::Thesaurus { file: self.import_thesaurus } })
+        } else if !self.combine.is_empty() {
+            ("--combine", Command::Combine { files: self.combine, separator: self.separator, sample: self.sample, limit })
+        } else if !self.acronyms.is_empty() {
+            ("--acronyms", Command::Acronyms { phrase: self.acronyms, limit })
+        } else if !self.import_reserved.is_empty() {
+            ("--import-reserved", Command::Import {
+                command: ImportCommand::Reserved { file: self.import_reserved, tld: self.reserved_tld },
+            })
+        } else if !self.import_value_rules.is_empty() {
+            ("--import-value-rules", Command::Import { command: ImportCommand::ValueRules { file: self.import_value_rules } })
+        } else if self.value_rules {
+            ("--value-rules", Command::ValueRules)
+        } else if !self.import_blocklist.is_empty() {
+            ("--import-blocklist", Command::Import {
+                command: ImportCommand::Blocklist { file: self.import_blocklist, language: self.blocklist_language, global: true },
+            })
+        } else if !self.import_trademarks.is_empty() {
+            ("--import-trademarks", Command::Import {
+                command: ImportCommand::Trademarks { file: self.import_trademarks, office: self.trademark_office },
+            })
+        } else if !self.add_owned.is_empty() {
+            ("--add-owned", Command::Owned { command: Some(OwnedCommand::Add { domains: self.add_owned }) })
+        } else if self.owned {
+            ("--owned", Command::Owned { command: None })
+        } else if !self.hacks.is_empty() {
+            ("--hacks", Command::Hacks { word: self.hacks, limit })
+        } else if !self.scan.is_empty() {
+            ("--scan", Command::Scan { pattern: self.scan })
+        } else if let Some(id) = self.resume {
+            ("--resume", Command::Jobs { command: Some(JobsCommand::Resume { id }) })
+        } else if let Some(id) = self.pause {
+            ("--pause", Command::Jobs { command: Some(JobsCommand::Pause { id }) })
+        } else if let Some(id) = self.job {
+            ("--job", Command::Jobs { command: Some(JobsCommand::Show { id }) })
+        } else if self.jobs {
+            ("--jobs", Command::Jobs { command: None })
+        } else if self.extensions {
+            ("--extensions", Command::Extensions { output: OutputArgs { output: self.output } })
+        } else if self.wishlist {
+            ("--wishlist", Command::Wishlist { output: OutputArgs { output: self.output } })
+        } else if self.init {
+            ("--init", Command::Init)
+        } else {
+            return None;
+        };
+
+        Some(command)
+    }
+}
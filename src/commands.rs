@@ -2,3 +2,17 @@ pub mod extensions_command;
 pub mod search_command;
 pub mod wishlist_command;
 pub mod init_command;
+pub mod markov_command;
+pub mod blend_command;
+pub mod typo_command;
+pub mod pattern_command;
+pub mod jobs_command;
+pub mod hack_command;
+pub mod expand_command;
+pub mod combine_command;
+pub mod acronym_command;
+pub mod reserved_command;
+pub mod value_command;
+pub mod blocklist_command;
+pub mod trademark_command;
+pub mod owned_command;
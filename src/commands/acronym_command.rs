@@ -0,0 +1,18 @@
+use crate::commands::search_command::search_domain_candidates;
+use crate::generators::acronym_generator::generate_acronyms;
+
+/// Generate acronyms and abbreviations of a phrase (e.g. a company name) and search them
+pub async fn handle_acronyms(phrase: &str, limit: usize) {
+  let candidates: Vec<(String, String)> = generate_acronyms(phrase)
+      .into_iter()
+      .take(limit)
+      .map(|acronym| (acronym, phrase.to_string()))
+      .collect();
+
+  if candidates.is_empty() {
+    println!("No acronym could be generated for '{}'", phrase);
+    return;
+  }
+
+  search_domain_candidates(candidates).await;
+}
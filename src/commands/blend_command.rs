@@ -0,0 +1,17 @@
+use crate::commands::search_command::search_domain_names;
+use crate::generators::blend_generator::generate_blends;
+use crate::generators::parse_keywords;
+
+/// Generate blends of two keyword lists and search the most pronounceable ones
+pub async fn handle_blend(left_keywords: &str, right_keywords: &str, limit: usize) {
+  let left: Vec<String> = parse_keywords(left_keywords);
+  let right: Vec<String> = parse_keywords(right_keywords);
+  let names: Vec<String> = generate_blends(&left, &right, limit);
+
+  if names.is_empty() {
+    println!("No blend could be generated from these keywords");
+    return;
+  }
+
+  search_domain_names(names).await;
+}
@@ -0,0 +1,19 @@
+use crate::database::blocklist_api::{import_blocked_words, parse_blocked_words};
+use crate::files::{get_workspace, read_word_list};
+
+/// Import a blocklist, one word per line or "language: words" lines (e.g. "fr: merde, putain")
+/// Words starting with "=" are only flagged when they are the whole name
+/// Words are only flagged in the current workspace (directory), or in every workspace when `global` is set
+pub fn handle_import_blocklist(path: &str, language: &str, global: bool) {
+  let lines: Vec<String> = read_word_list(path);
+  let blocked_words = parse_blocked_words(&lines, language);
+  let workspace = if global { String::new() } else { get_workspace() };
+
+  if let Some(count) = import_blocked_words(&blocked_words, &workspace) {
+    if global {
+      println!("BLOCKLIST => Successfully imported {} blocked words.", count);
+    } else {
+      println!("BLOCKLIST => Successfully imported {} blocked words in workspace '{}'.", count, workspace);
+    }
+  }
+}
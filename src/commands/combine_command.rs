@@ -0,0 +1,30 @@
+use crate::commands::search_command::stream_domain_names;
+use crate::files::read_word_list;
+use crate::generators::combination_generator::WordCombinations;
+
+/// Search every combination of words from several word list files
+/// When there are more combinations than `limit`, the first ones are searched, or a random sample if `sample` is set
+pub async fn handle_combine(paths: &[String], separators: Vec<String>, limit: usize, sample: bool) {
+  let lists: Vec<Vec<String>> = paths.iter().map(|path| read_word_list(path)).collect();
+
+  if let Some(index) = lists.iter().position(|list| list.is_empty()) {
+    eprintln!("Word list '{}' is empty", paths[index]);
+    return;
+  }
+
+  let combinations = WordCombinations::new(lists, separators);
+  let count = combinations.count();
+
+  if count > limit as u128 {
+    let strategy = if sample { "a random sample of" } else { "the first" };
+    eprintln!("{} combinations found, only {} {} are searched", count, strategy, limit);
+  }
+
+  let names: Vec<String> = if sample {
+    combinations.sample(limit)
+  } else {
+    combinations.first(limit)
+  };
+
+  stream_domain_names(names).await;
+}
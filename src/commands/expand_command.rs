@@ -0,0 +1,28 @@
+use crate::commands::search_command::search_domain_candidates;
+use crate::database::thesaurus_api::{find_related_words, import_thesaurus_entries, parse_thesaurus};
+use crate::files::read_word_list;
+use crate::generators::expansion_generator::expand_words;
+use crate::generators::parse_keywords;
+
+/// Search a list of words along with their synonyms and related words
+pub async fn handle_expand(keywords: &str, depth: usize, limit: usize) {
+  let words: Vec<String> = parse_keywords(keywords);
+  let candidates: Vec<(String, String)> = expand_words(&words, depth, limit, find_related_words);
+
+  if candidates.is_empty() {
+    println!("No word to search");
+    return;
+  }
+
+  search_domain_candidates(candidates).await;
+}
+
+/// Import a thesaurus file with one word per line followed by its related words (e.g. "cloud: sky, nimbus")
+pub fn handle_import_thesaurus(path: &str) {
+  let lines: Vec<String> = read_word_list(path);
+  let entries = parse_thesaurus(&lines);
+
+  if let Some(count) = import_thesaurus_entries(&entries) {
+    println!("THESAURUS => Successfully imported {} entries.", count);
+  }
+}
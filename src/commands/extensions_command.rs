@@ -1,8 +1,12 @@
 use crate::database::extensions_api::find_all_extensions;
 use crate::tables::extensions_table::display_extensions;
 use crate::models::Extension;
+use crate::output::{get_output_format, print_extensions, OutputFormat};
 
 pub fn handle_extensions() {
   let extensions: Vec<Extension> = find_all_extensions();
-  display_extensions(extensions).expect("An error occurred while displaying extensions");
+  match get_output_format() {
+    OutputFormat::Table => display_extensions(extensions).expect("An error occurred while displaying extensions"),
+    format => print_extensions(&extensions, format),
+  }
 }
@@ -0,0 +1,25 @@
+use crate::commands::search_command::search_exact_domains;
+use crate::database::extensions_api::find_all_extensions;
+use crate::generators::hack_generator::{find_domain_hacks, DomainHack};
+
+/// Find domain hacks of a word using every known extension and search them
+pub async fn handle_hacks(word: &str, limit: usize) {
+  let tlds: Vec<String> = find_all_extensions().into_iter().map(|e| e.tld).collect();
+  let mut hacks: Vec<DomainHack> = find_domain_hacks(word, &tlds);
+  hacks.truncate(limit);
+
+  if hacks.is_empty() {
+    println!("No domain hack found for '{}'", word);
+    return;
+  }
+
+  // The complete hack (e.g. "del.icio.us") is shown as the source of each result
+  let mut domain_names: Vec<(String, String, String)> = vec![];
+  for hack in hacks.iter() {
+    if !domain_names.iter().any(|(label, tld, _)| *label == hack.label && *tld == hack.tld) {
+      domain_names.push((hack.label.clone(), hack.tld.clone(), hack.hack_name()));
+    }
+  }
+
+  search_exact_domains(domain_names).await;
+}
@@ -1,7 +1,7 @@
 use crate::database::migrations::run_migrations;
 
 pub fn handle_init() {
-  match run_migrations() {
+  match run_migrations(true) {
     Ok(_) => println!("Ok"),
     Err(e) => println!("{}", e)
   }
@@ -0,0 +1,172 @@
+use std::time::Duration;
+use crate::commands::search_command::{fetch_domains, get_selected_extensions};
+use crate::database::jobs_api::{create_job, find_all_jobs, find_job, find_job_results, find_job_status, mark_interrupted_jobs, save_job_progress, update_job};
+use crate::generators::pattern_generator::LetterPattern;
+use crate::models::{Domain, Job};
+use crate::output::EXIT_SEARCH_FAILED;
+use crate::rate_limiter::{get_request_delay, set_request_delay};
+use crate::tables::domains_table::display_domains;
+use crate::tables::jobs_table::display_jobs;
+
+/// Delay between two requests when no delay is given with `--request-delay`, to avoid flooding the provider during
+/// long scans
+const SCAN_DELAY: Duration = Duration::from_millis(500);
+
+/// Running jobs not updated for this time plus the request delay were interrupted without being paused
+const INTERRUPTED_JOB_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Create a scan job for every label matching a pattern and start it
+pub async fn handle_scan(pattern: &str) {
+  let letter_pattern = match LetterPattern::parse(pattern) {
+    Ok(letter_pattern) => letter_pattern,
+    Err(error) => {
+      println!("{}", error);
+      return;
+    }
+  };
+
+  let mut job = Job {
+    id: 0,
+    pattern: pattern.to_string(),
+    tlds: serde_json::to_string(&get_selected_extensions()).unwrap_or_default(),
+    cursor: 0,
+    total: letter_pattern.count().min(i64::MAX as u128) as i64,
+    found: 0,
+    status: String::from(Job::RUNNING),
+  };
+
+  match create_job(&job) {
+    Some(id) => job.id = id,
+    None => return,
+  }
+
+  set_request_delay(SCAN_DELAY);
+  println!("JOB {} => Created, press Ctrl+C to pause", job.id);
+  run_job(job, letter_pattern).await;
+}
+
+/// Resume a paused or interrupted scan job
+pub async fn handle_resume(id: i64) {
+  set_request_delay(SCAN_DELAY);
+  mark_interrupted();
+
+  let mut job = match find_job(id) {
+    Some(job) => job,
+    None => {
+      println!("JOB {} => Not found", id);
+      return;
+    }
+  };
+
+  if job.status == Job::FINISHED {
+    println!("JOB {} => Already finished", id);
+    return;
+  }
+
+  if job.status == Job::RUNNING {
+    println!("JOB {} => Already running, pause it first", id);
+    return;
+  }
+
+  let letter_pattern = match LetterPattern::parse(&job.pattern) {
+    Ok(letter_pattern) => letter_pattern,
+    Err(error) => {
+      println!("{}", error);
+      return;
+    }
+  };
+
+  job.status = String::from(Job::RUNNING);
+  update_job(&job);
+
+  println!("JOB {} => Resumed at {}, press Ctrl+C to pause", job.id, job.progress());
+  run_job(job, letter_pattern).await;
+}
+
+/// Pause a running scan job, the running process stops after the current label
+pub fn handle_pause(id: i64) {
+  mark_interrupted();
+
+  match find_job(id) {
+    Some(mut job) if job.status == Job::RUNNING => {
+      job.status = String::from(Job::PAUSED);
+      update_job(&job);
+      println!("JOB {} => Paused at {}", job.id, job.progress());
+    }
+    Some(job) => println!("JOB {} => Cannot be paused ({})", job.id, job.status),
+    None => println!("JOB {} => Not found", id),
+  }
+}
+
+/// Show all scan jobs
+pub fn handle_jobs() {
+  mark_interrupted();
+  let jobs: Vec<Job> = find_all_jobs();
+  display_jobs(jobs).expect("An error occurred while displaying jobs");
+}
+
+/// Show the available domains found by a scan job
+pub fn handle_job_results(id: i64) {
+  let domains: Vec<Domain> = find_job_results(id);
+  display_domains(domains).expect("An error occurred while displaying job results");
+}
+
+/// Mark running jobs as interrupted when their process stopped without pausing them
+fn mark_interrupted() {
+  mark_interrupted_jobs(INTERRUPTED_JOB_TIMEOUT + get_request_delay());
+}
+
+/// Scan labels from the job cursor, saving progress after each label
+/// Requests are spaced by the delay given with `--request-delay`, or by `SCAN_DELAY`
+async fn run_job(mut job: Job, letter_pattern: LetterPattern) {
+  let extensions: Vec<String> = serde_json::from_str(&job.tlds).unwrap_or_default();
+
+  // Listen to Ctrl+C once for the whole job, so that a Ctrl+C pressed while saving progress is not missed
+  let interrupt = tokio::signal::ctrl_c();
+  tokio::pin!(interrupt);
+
+  while job.cursor < job.total {
+    if find_job_status(job.id).as_deref() == Some(Job::PAUSED) {
+      println!("JOB {} => Paused at {}", job.id, job.progress());
+      return;
+    }
+
+    let labels = letter_pattern.enumerate(job.cursor as u128, 1);
+
+    for label in labels {
+      let domains = tokio::select! {
+        domains = fetch_domains(label, &extensions) => domains,
+        _ = &mut interrupt => {
+          job.status = String::from(Job::PAUSED);
+          update_job(&job);
+          println!("JOB {} => Paused at {}", job.id, job.progress());
+          return;
+        }
+      };
+
+      // Pause the job so that it can be resumed from the same label once the provider answers again
+      let domains = match domains {
+        Ok(domains) => domains,
+        Err(error) => {
+          job.status = String::from(Job::PAUSED);
+          update_job(&job);
+          eprintln!("JOB {} => Paused at {}: {}", job.id, job.progress(), error);
+          std::process::exit(EXIT_SEARCH_FAILED);
+        }
+      };
+
+      let available: Vec<&Domain> = domains.iter().filter(|domain| domain.status == "True").collect();
+      job.found += available.len() as i64;
+      job.cursor += 1;
+      save_job_progress(&job, &available);
+
+      for domain in available {
+        println!("JOB {} => Found {}", job.id, domain.domain_name());
+      }
+    }
+  }
+
+  job.status = String::from(Job::FINISHED);
+  update_job(&job);
+  println!("JOB {} => Finished, {} available domains found", job.id, job.found);
+}
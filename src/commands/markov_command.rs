@@ -0,0 +1,22 @@
+use crate::commands::search_command::search_domain_names;
+use crate::files::read_word_list;
+use crate::generators::markov_generator::{get_default_corpus, MarkovModel, MarkovOptions};
+
+/// Generate brandable names with a Markov chain and search them
+pub async fn handle_markov(corpus_path: String, options: MarkovOptions) {
+  let corpus: Vec<String> = if corpus_path.is_empty() {
+    get_default_corpus()
+  } else {
+    read_word_list(&corpus_path)
+  };
+
+  let model = MarkovModel::train(&corpus, options.order);
+  let names: Vec<String> = model.generate(&options);
+
+  if names.is_empty() {
+    println!("No name could be generated, try another corpus or wider length limits");
+    return;
+  }
+
+  search_domain_names(names).await;
+}
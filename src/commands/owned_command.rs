@@ -0,0 +1,33 @@
+use crate::database::extensions_api::find_all_extensions;
+use crate::database::owned_api::{add_owned_domains, find_owned_domains};
+use crate::validation::{parse_domain_name, to_unicode};
+
+/// Add comma separated domains owned by the user (e.g. "example.com, example.io")
+pub fn handle_add_owned(inputs: &str) {
+  let known_tlds: Vec<String> = find_all_extensions().into_iter().map(|e| e.tld).collect();
+  let mut domains: Vec<(String, String)> = vec![];
+
+  for input in inputs.split(',').map(|input| input.trim()).filter(|input| !input.is_empty()) {
+    match parse_domain_name(input, &known_tlds) {
+      Ok(domain) => domains.push(domain),
+      Err(error) => println!("{}", error),
+    }
+  }
+
+  if let Some(count) = add_owned_domains(&domains) {
+    println!("OWNED => Successfully added {} domains.", count);
+  }
+}
+
+/// Print the domains owned by the user
+pub fn handle_owned() {
+  let domains: Vec<(String, String)> = find_owned_domains();
+
+  if domains.is_empty() {
+    println!("No owned domain, add them with the 'domain-hunter owned add' command");
+  }
+
+  for (domain, tld) in domains {
+    println!("{}", to_unicode(&format!("{}.{}", domain, tld)));
+  }
+}
@@ -0,0 +1,21 @@
+use crate::commands::search_command::search_domain_names;
+use crate::generators::pattern_generator::LetterPattern;
+
+/// Enumerate the labels matching a letter pattern and search them
+pub async fn handle_pattern(pattern: &str, limit: usize) {
+  let pattern = match LetterPattern::parse(pattern) {
+    Ok(pattern) => pattern,
+    Err(error) => {
+      eprintln!("{}", error);
+      return;
+    }
+  };
+
+  let count = pattern.count();
+  if count > limit as u128 {
+    eprintln!("Pattern matches {} labels, only the first {} are searched", count, limit);
+  }
+
+  let names: Vec<String> = pattern.enumerate(0, limit);
+  search_domain_names(names).await;
+}
@@ -0,0 +1,13 @@
+use crate::database::reserved_api::{import_reserved_names, parse_reserved_names};
+use crate::files::read_lines;
+
+/// Import a list of reserved names published by a registry, one name per line optionally followed by a reason
+/// (e.g. "example, Registry reserved")
+pub fn handle_import_reserved(path: &str, tld: &str) {
+  let lines: Vec<String> = read_lines(path);
+  let reserved_names = parse_reserved_names(&lines, tld, "Registry reserved");
+
+  if let Some(count) = import_reserved_names(&reserved_names) {
+    println!("RESERVED => Successfully imported {} reserved names.", count);
+  }
+}
@@ -1,38 +1,277 @@
 use reqwest::Url;
 use regex::Regex;
-use crate::database::extensions_api::find_selected_extensions;
+use crate::database::extensions_api::{find_all_extensions, find_selected_extensions};
 use crate::database::wishlist_api::find_wishlist;
-use crate::tables::domains_table::display_domains;
-use crate::models::{Domain, Extension};
-
-pub async fn search_domain_names(domain: String) {
-  let extensions: String = get_selected_extensions();
-  let url: Url = get_url(domain, extensions);
-  let data: String = search_domains(url).await;
-  let mut domains: Vec<Domain> = parse_data(data);
+use crate::homographs::check_homograph;
+use crate::generators::variant_generator::{generate_alternatives, generate_variants};
+use crate::tables::domains_table::{display_domain_stream, display_domains};
+use crate::database::policies_api::find_all_policies;
+use crate::database::reserved_api::find_reservations;
+use crate::models::{Domain, Extension, RegistryPolicy, ReservedName};
+use crate::output::{get_output_format, print_domains, set_exit_code, OutputFormat, EXIT_SEARCH_FAILED};
+use crate::policies::{check_policy, get_policy_requirements};
+use crate::rate_limiter::wait_for_turn;
+use crate::validation::{is_domain_name, parse_domain_name, parse_label, to_ascii_label, to_unicode, validate_domain_name};
+
+/// Maximum number of alternatives checked when a domain name is taken for every extension, best scored first
+const MAX_ALTERNATIVES: usize = 8;
+
+/// Search domain names typed by the user, labels (e.g. "example") and complete domain names (e.g. "example.io")
+/// Labels are searched with the selected extensions while complete domain names are searched exactly
+/// When a single label is taken for every selected extension, available alternatives are shown after the results
+pub async fn search_domain_inputs(inputs: Vec<String>) {
+  let known_tlds: Vec<String> = find_all_extensions().into_iter().map(|e| e.tld).collect();
+  let extensions: Vec<String> = get_selected_extensions();
+  let mut domains: Vec<Domain> = vec![];
+  let mut labels: Vec<String> = vec![];
+
+  for input in inputs.iter() {
+    if is_domain_name(input) {
+      if let Some((label, tld)) = keep_valid(parse_domain_name(input, &known_tlds)) {
+        warn_homograph(&label);
+        domains.extend(exit_on_error(fetch_domains(label, &[tld]).await));
+      }
+    } else if let Some(label) = keep_valid(parse_label(input)) {
+      warn_homograph(&label);
+      domains.extend(exit_on_error(fetch_domains(label.clone(), &extensions).await));
+      labels.push(label);
+    }
+  }
+
+  if inputs.len() == 1 && labels.len() == 1 && !domains.is_empty() && domains.iter().all(|d| d.status != "True") {
+    let domain = &labels[0];
+    let source = format!("Alternative to {}", domain);
+
+    for alternative in generate_alternatives(domain).into_iter().take(MAX_ALTERNATIVES) {
+      let mut results = exit_on_error(fetch_domains(alternative, &extensions).await);
+      results.retain(|result| result.status == "True");
+      results.iter_mut().for_each(|result| {
+        result.source = source.clone();
+        result.alternative = true;
+      });
+      domains.extend(results);
+    }
+  }
+
+  display_results(domains);
+}
+
+/// Print a warning when a label could be mistaken for another one
+fn warn_homograph(label: &str) {
+  if let Some(warning) = check_homograph(&to_unicode(label)) {
+    eprintln!("Possible homograph '{}': {}", to_unicode(label), warning);
+  }
+}
+
+/// Search the hyphen, numeral and plural variants of a query, grouped under the query
+pub async fn search_domain_variants(query: String) {
+  let candidates: Vec<(String, String)> = generate_variants(&query)
+      .into_iter()
+      .map(|variant| (variant, query.clone()))
+      .collect();
+
+  let domains = fetch_candidates(candidates).await;
+  display_results(domains);
+}
+
+/// Search one or several domain names and display all results in a single table
+pub async fn search_domain_names(domain_names: Vec<String>) {
+  let candidates: Vec<(String, String)> = domain_names
+      .into_iter()
+      .map(|domain| (domain, String::new()))
+      .collect();
+
+  search_domain_candidates(candidates).await;
+}
+
+/// Search domain names generated from other words (name and source pairs)
+/// The source is shown alongside each result
+pub async fn search_domain_candidates(candidates: Vec<(String, String)>) {
+  let domains = fetch_candidates(candidates).await;
+  display_results(domains);
+}
+
+/// Check the availability of generated domain names (name and source pairs) with the selected extensions
+async fn fetch_candidates(candidates: Vec<(String, String)>) -> Vec<Domain> {
+  let extensions: Vec<String> = get_selected_extensions();
+  let mut domains: Vec<Domain> = vec![];
+
+  let candidates = candidates
+      .into_iter()
+      .filter_map(|(domain, source)| keep_valid(to_ascii_label(&domain)).map(|domain| (domain, source)));
+
+  for (domain, source) in candidates {
+    let mut results = exit_on_error(fetch_domains(domain, &extensions).await);
+    results.iter_mut().for_each(|result| result.source = source.clone());
+    domains.extend(results);
+  }
+
+  domains
+}
+
+/// Search exact domain names (label, extension and source triples), bypassing the extensions selected by the user
+/// The source is shown alongside each result
+pub async fn search_exact_domains(domain_names: Vec<(String, String, String)>) {
+  let mut domains: Vec<Domain> = vec![];
+
+  for (domain, tld, source) in domain_names {
+    let domain = match keep_valid(to_ascii_label(&domain)) {
+      Some(domain) => domain,
+      None => continue,
+    };
+    if keep_valid(validate_domain_name(&format!("{}.{}", domain, tld))).is_none() {
+      continue;
+    }
+
+    let mut results = exit_on_error(fetch_domains(domain, &[tld]).await);
+    results.iter_mut().for_each(|result| result.source = source.clone());
+    domains.extend(results);
+  }
+
+  display_results(domains);
+}
+
+/// Search a large number of domain names, displaying results as soon as they are received
+pub async fn stream_domain_names(domain_names: Vec<String>) {
+  let extensions: Vec<String> = get_selected_extensions();
+  let wishlist: Vec<Domain> = find_wishlist();
+  let domain_names: Vec<String> = domain_names
+      .into_iter()
+      .filter_map(|domain| keep_valid(to_ascii_label(&domain)))
+      .collect();
+  let total = domain_names.len();
+  let (sender, receiver) = std::sync::mpsc::channel::<Vec<Domain>>();
+
+  let search_task = tokio::spawn(async move {
+    for domain in domain_names {
+      let mut results = fetch_domains(domain, &extensions).await?;
+      set_wishlisted_domain(&mut results, wishlist.clone());
+
+      // The table has been closed, stop searching
+      if sender.send(results).is_err() {
+        break;
+      }
+    }
+
+    Ok(())
+  });
+
+  tokio::task::block_in_place(|| display_domain_stream(receiver, total))
+      .expect("An error occurred while displaying results");
+
+  // The error is printed once the table is closed, so that the terminal is restored
+  if search_task.is_finished() {
+    if let Ok(result) = search_task.await {
+      exit_on_error(result);
+    }
+  } else {
+    search_task.abort();
+  }
+}
+
+/// Print the reason why an invalid domain name is skipped
+fn keep_valid<T>(validation: Result<T, String>) -> Option<T> {
+  match validation {
+    Ok(value) => Some(value),
+    Err(error) => {
+      eprintln!("{}", error);
+      None
+    }
+  }
+}
+
+/// Print the reason why a search failed and exit with `EXIT_SEARCH_FAILED`
+fn exit_on_error<T>(result: Result<T, String>) -> T {
+  match result {
+    Ok(value) => value,
+    Err(error) => {
+      eprintln!("{}", error);
+      std::process::exit(EXIT_SEARCH_FAILED);
+    }
+  }
+}
+
+/// Mark wishlisted domains and display search results, or print them in the format given with `--output`
+/// Printed results also set the exit code telling whether the printed domains are available
+fn display_results(mut domains: Vec<Domain>) {
   let wishlist : Vec<Domain> = find_wishlist();
   set_wishlisted_domain(&mut domains, wishlist);
-  display_domains(domains).expect("An error occurred while displaying results");
+  set_exit_code(&domains);
+
+  match get_output_format() {
+    OutputFormat::Table => display_domains(domains).expect("An error occurred while displaying results"),
+    format => print_domains(&domains, format),
+  }
+}
+
+/// Check the availability of a domain name for the given extensions
+/// Extensions whose registry policy rejects the domain name are marked as not registrable, and extensions where the
+/// domain name is reserved are marked as reserved, without being searched
+pub async fn fetch_domains(domain: String, extensions: &[String]) -> Result<Vec<Domain>, String> {
+  let policies: Vec<RegistryPolicy> = find_all_policies();
+  let reservations: Vec<ReservedName> = find_reservations(&domain);
+  let mut domains: Vec<Domain> = vec![];
+  let mut searched_extensions: Vec<String> = vec![];
+
+  for tld in extensions {
+    let policy = policies.iter().find(|policy| policy.tld == *tld);
+    let reservation = reservations.iter().find(|reserved| reserved.tld == *tld || reserved.tld == "*");
+
+    let rejection = match (policy.map(|policy| check_policy(&domain, policy)), reservation) {
+      (Some(Err(reason)), _) => Some((Domain::NOT_REGISTRABLE, reason)),
+      (_, Some(reserved)) => Some((Domain::RESERVED, reserved.reason.clone())),
+      _ => None,
+    };
+
+    match rejection {
+      Some((status, reason)) => domains.push(Domain {
+        domain: domain.clone(),
+        tld: tld.clone(),
+        status: String::from(status),
+        selected: false,
+        source: String::new(),
+        reason,
+        alternative: false,
+      }),
+      None => searched_extensions.push(tld.clone()),
+    }
+  }
+
+  if !searched_extensions.is_empty() {
+    let url: Url = get_url(domain, &searched_extensions);
+    let data: String = search_domains(url).await?;
+    let mut results: Vec<Domain> = parse_data(data)?;
+
+    for result in results.iter_mut() {
+      if let Some(policy) = policies.iter().find(|policy| policy.tld == result.tld) {
+        result.reason = get_policy_requirements(policy);
+      }
+    }
+
+    domains.extend(results);
+  }
+
+  domains.sort_by(|a, b| a.tld.cmp(&b.tld));
+  Ok(domains)
 }
 
 /// Get the extensions selected by the user
-pub fn get_selected_extensions() -> String {
+pub fn get_selected_extensions() -> Vec<String> {
   let selected_extensions: Vec<Extension> = find_selected_extensions();
-  let selected_extensions_tlds: String = selected_extensions
-      .iter()
-      .map(|e| format!("\"{}\"", e.tld))
-      .collect::<Vec<_>>()
-      .join(",");
-
-  format!("[{}]", selected_extensions_tlds)
+  selected_extensions.into_iter().map(|e| e.tld).collect()
 }
 
 /// Generate the URL to fetch domain names
-pub fn get_url(domain: String, extensions: String) -> Url {
+pub fn get_url(domain: String, extensions: &[String]) -> Url {
   let url = "https://domaintyper.com/API/DomainCheckAsync";
+  let tlds: String = extensions
+      .iter()
+      .map(|tld| format!("\"{}\"", tld))
+      .collect::<Vec<_>>()
+      .join(",");
   let params = [
     ("domain", domain),
-    ("tlds", extensions)
+    ("tlds", format!("[{}]", tlds))
   ];
 
   match Url::parse_with_params(url, &params) {
@@ -41,27 +280,30 @@ pub fn get_url(domain: String, extensions: String) -> Url {
   }
 }
 
-/// Search for domain names
-pub async fn search_domains(url: Url) -> String {
+/// Search for domain names, waiting for the delay given with `--request-delay` since the previous request
+pub async fn search_domains(url: Url) -> Result<String, String> {
+  wait_for_turn().await;
+
   let response = match reqwest::get(url).await {
     Ok(response) => response,
-    Err(error) => { panic!("{}", error) }
+    Err(error) => return Err(format!("Search failed: {}", error)),
   };
 
   match response.text().await {
-    Ok(response) => response,
-    Err(error) => { panic!("{}", error) }
+    Ok(response) => Ok(response),
+    Err(error) => Err(format!("Search failed: {}", error)),
   }
 }
 
 /// Parse search result and transform data into a vector of Domain
-pub fn parse_data(raw_data: String) -> Vec<Domain> {
+pub fn parse_data(raw_data: String) -> Result<Vec<Domain>, String> {
   let re = Regex::new(r"}\{").unwrap();
-  let formatted_data = re.replace_all(&*raw_data, "},{");
+  let formatted_data = re.replace_all(&raw_data, "},{");
   let json_data = format!("[{}]", formatted_data);
-  let mut parsed_data: Vec<Domain> = serde_json::from_str(&*json_data).unwrap();
+  let mut parsed_data: Vec<Domain> = serde_json::from_str(&json_data)
+      .map_err(|error| format!("Invalid search response: {}", error))?;
   parsed_data.sort_by(|a, b| a.tld.cmp(&b.tld));
-  parsed_data
+  Ok(parsed_data)
 }
 
 /// Retrieve the list of wishlisted domains to check if the user has already added domains in the wishlist
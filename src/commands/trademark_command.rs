@@ -0,0 +1,27 @@
+use std::fs;
+use crate::database::trademarks_api::{import_trademarks, parse_trademark_csv, parse_trademark_xml};
+use crate::files::read_csv;
+use crate::models::Trademark;
+
+/// Import trademarks from a CSV or XML export of a trademark office
+pub fn handle_import_trademarks(path: &str, office: &str) {
+  let trademarks: Vec<Trademark> = if path.to_lowercase().ends_with(".xml") {
+    let content = match fs::read_to_string(path) {
+      Ok(content) => content,
+      Err(error) => { panic!("Could not read trademarks '{}': {}", path, error) }
+    };
+    parse_trademark_xml(&content, office)
+  } else {
+    match parse_trademark_csv(&read_csv(path), office) {
+      Ok(trademarks) => trademarks,
+      Err(error) => {
+        println!("{}", error);
+        return;
+      }
+    }
+  };
+
+  if let Some(count) = import_trademarks(&trademarks) {
+    println!("TRADEMARKS => Successfully imported {} trademarks, {} records read.", count, trademarks.len());
+  }
+}
@@ -0,0 +1,31 @@
+use crate::commands::search_command::{search_domain_names, search_exact_domains};
+use crate::database::extensions_api::find_all_extensions;
+use crate::generators::typo_generator::generate_typos;
+use crate::validation::{is_domain_name, normalize_domain_name, split_domain_name};
+
+/// Generate typosquats of an owned domain name and search them
+/// Typos of a complete domain name (e.g. "example.com") are searched with its extension only
+pub async fn handle_typos(name: &str, limit: usize) {
+  let (label, tld) = if is_domain_name(name) {
+    let known_tlds: Vec<String> = find_all_extensions().into_iter().map(|e| e.tld).collect();
+    match split_domain_name(&normalize_domain_name(name), &known_tlds) {
+      Some((label, tld)) => (label, Some(tld)),
+      None => (name.to_string(), None),
+    }
+  } else {
+    (name.to_string(), None)
+  };
+
+  let mut names: Vec<String> = generate_typos(&label);
+  names.truncate(limit);
+
+  if names.is_empty() {
+    println!("No typo could be generated for '{}'", name);
+    return;
+  }
+
+  match tld {
+    Some(tld) => search_exact_domains(names.into_iter().map(|typo| (typo, tld.clone(), String::new())).collect()).await,
+    None => search_domain_names(names).await,
+  }
+}
@@ -0,0 +1,37 @@
+use std::fs;
+use crate::database::value_rules_api::{find_all_value_rules, import_value_rules};
+use crate::models::ValueRule;
+
+/// Print the valuation rules, tiers first
+pub fn handle_value_rules() {
+  let rules: Vec<ValueRule> = find_all_value_rules();
+  let (tiers, rules): (Vec<ValueRule>, Vec<ValueRule>) = rules.into_iter().partition(|rule| rule.kind == "tier");
+
+  for tier in tiers {
+    println!("{:<12} from {} points", tier.value, tier.points);
+  }
+  println!();
+  for rule in rules {
+    println!("{:<12} {:<40} {:+4}  {}", rule.kind, rule.value, rule.points, rule.description);
+  }
+}
+
+/// Import valuation rules from a JSON file, rules with the same kind and value are replaced
+pub fn handle_import_value_rules(path: &str) {
+  let content = match fs::read_to_string(path) {
+    Ok(content) => content,
+    Err(error) => { panic!("Could not read valuation rules '{}': {}", path, error) }
+  };
+
+  let rules: Vec<ValueRule> = match serde_json::from_str(&content) {
+    Ok(result) => result,
+    Err(error) => {
+      println!("Invalid valuation rules '{}': {}", path, error);
+      return;
+    }
+  };
+
+  if let Some(count) = import_value_rules(&rules) {
+    println!("VALUE RULES => Successfully imported {} new rules, {} rules read.", count, rules.len());
+  }
+}
@@ -1,8 +1,12 @@
 use crate::database::wishlist_api::find_wishlist;
 use crate::tables::domains_table::display_domains;
 use crate::models::Domain;
+use crate::output::{get_output_format, print_domains, OutputFormat};
 
 pub fn handle_wishlist() {
   let wishlist: Vec<Domain> = find_wishlist();
-  display_domains(wishlist).expect("An error occurred while displaying wishlisted domains");
+  match get_output_format() {
+    OutputFormat::Table => display_domains(wishlist).expect("An error occurred while displaying wishlisted domains"),
+    format => print_domains(&wishlist, format),
+  }
 }
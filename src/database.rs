@@ -2,3 +2,11 @@ pub mod connection;
 pub(crate) mod migrations;
 pub mod extensions_api;
 pub mod wishlist_api;
+pub mod jobs_api;
+pub mod thesaurus_api;
+pub mod policies_api;
+pub mod reserved_api;
+pub mod value_rules_api;
+pub mod blocklist_api;
+pub mod trademarks_api;
+pub mod owned_api;
@@ -0,0 +1,101 @@
+use std::sync::MutexGuard;
+use rusqlite::Connection;
+use crate::database::connection::DB_CONNECTION;
+use crate::models::BlockedWord;
+
+/// Parse blocklist lines such as "merde", "=mist" (whole name only) or "fr: merde, putain, =bite"
+pub fn parse_blocked_words(lines: &[String], default_language: &str) -> Vec<BlockedWord> {
+    let mut blocked_words: Vec<BlockedWord> = vec![];
+
+    for line in lines {
+        let (language, words) = match line.split_once(':') {
+            Some((language, words)) => (language.trim(), words),
+            None => (default_language, line.as_str()),
+        };
+
+        for word in words.split(',') {
+            let word = word.trim().to_lowercase();
+            let (word, exact) = match word.strip_prefix('=') {
+                Some(stripped) => (stripped.trim().to_string(), true),
+                None => (word, false),
+            };
+
+            if !word.is_empty() {
+                blocked_words.push(BlockedWord { word, language: language.to_lowercase(), exact });
+            }
+        }
+    }
+
+    blocked_words
+}
+
+/// Create blocked words of a workspace (empty for every workspace) in a single transaction, returns the number of
+/// words added. Words which already exist in the workspace get the new whole name flag when `replace` is set
+pub fn create_blocked_words(conn: &MutexGuard<Connection>, blocked_words: &[BlockedWord], workspace: &str, replace: bool) -> Option<usize> {
+    let response = conn.unchecked_transaction()
+        .and_then(|transaction| {
+            let mut count = 0;
+            {
+                let mut update_stmt = transaction.prepare(
+                    "UPDATE blocked_word SET exact = ?3 WHERE word = ?1 AND language = ?2 AND workspace = ?4"
+                )?;
+                let mut insert_stmt = transaction.prepare(
+                    "INSERT INTO blocked_word (word, language, exact, workspace)
+                     SELECT ?1, ?2, ?3, ?4
+                     WHERE NOT EXISTS (SELECT 1 FROM blocked_word WHERE word = ?1 AND language = ?2 AND workspace = ?4)"
+                )?;
+                for blocked_word in blocked_words {
+                    let params = (&blocked_word.word, &blocked_word.language, &blocked_word.exact, workspace);
+                    if replace {
+                        update_stmt.execute(params)?;
+                    }
+                    count += insert_stmt.execute(params)?;
+                }
+            }
+            transaction.commit()?;
+            Ok(count)
+        })
+        .map_err(|e| format!("Failed to add blocked words: {}", e));
+
+    match response {
+        Ok(count) => Some(count),
+        Err(error) => {
+            eprintln!("BLOCKLIST API => {}", error);
+            None
+        }
+    }
+}
+
+/// Import blocked words in a workspace (empty for every workspace)
+pub fn import_blocked_words(blocked_words: &[BlockedWord], workspace: &str) -> Option<usize> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+    create_blocked_words(&conn, blocked_words, workspace, true)
+}
+
+/// Get the words blocked in every workspace and in the given workspace
+pub fn find_all_blocked_words(workspace: &str) -> Vec<BlockedWord> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    let mut stmt = match conn.prepare("SELECT word, language, exact FROM blocked_word WHERE workspace IN ('', ?1)") {
+        Ok(result) => result,
+        Err(error) => {
+            eprintln!("BLOCKLIST API => {}", error);
+            return vec![];
+        }
+    };
+
+    let blocked_iter = stmt.query_map([workspace], |row| {
+        Ok(BlockedWord {
+            word: row.get(0)?,
+            language: row.get(1)?,
+            exact: row.get(2)?,
+        })
+    });
+
+    let blocked_result = match blocked_iter {
+        Ok(result) => result.collect::<Result<Vec<BlockedWord>, rusqlite::Error>>(),
+        Err(_) => Ok(vec![])
+    };
+
+    blocked_result.unwrap_or_default()
+}
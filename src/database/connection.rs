@@ -3,6 +3,7 @@ use std::path::PathBuf;
 use rusqlite::{Connection, Result};
 use std::sync::Mutex;
 use lazy_static::lazy_static;
+use crate::database::migrations::{run_migrations, SCHEMA_VERSION};
 
 lazy_static! {
     pub static ref DB_CONNECTION: Mutex<Connection> = Mutex::new(
@@ -13,9 +14,19 @@ lazy_static! {
     );
 }
 
+/// Open the database, upgrading its tables when it was created by an older version
+/// Only a summary line is printed, `init` prints every migration step
 pub fn establish_connection() -> Result<()> {
-    let _conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
-    // Additional initialization code for the connection can go here if needed.
+    let schema_version: u32 = {
+        let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+        conn.query_row("PRAGMA user_version", [], |row| row.get(0))?
+    };
+
+    if schema_version < SCHEMA_VERSION {
+        run_migrations(false)?;
+        eprintln!("DATABASE => Upgraded from version {} to {}", schema_version, SCHEMA_VERSION);
+    }
+
     Ok(())
 }
 
@@ -1,7 +1,7 @@
 use std::sync::MutexGuard;
 use rusqlite::{Connection, Statement};
 use crate::database::connection::DB_CONNECTION;
-use crate::models::Extension;
+use crate::models::{Extension, RegistryPolicy};
 
 /// Create a domain name extension
 pub fn create_extension(conn: &MutexGuard<Connection>, extension: &Extension) -> Option<()> {
@@ -14,7 +14,7 @@ pub fn create_extension(conn: &MutexGuard<Connection>, extension: &Extension) ->
     match response {
         Ok(_) => Some(()),
         Err(error) => {
-            println!("{}", error);
+            eprintln!("{}", error);
             None
         }
     }
@@ -33,7 +33,7 @@ pub fn update_extension(extension: &Extension) -> Option<()> {
     match response {
         Ok(_) => Some(()),
         Err(error) => {
-            println!("{}", error);
+            eprintln!("{}", error);
             None
         }
     }
@@ -43,9 +43,13 @@ pub fn update_extension(extension: &Extension) -> Option<()> {
 pub fn find_all_extensions() -> Vec<Extension> {
     let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
 
-    let mut stmt = match conn.prepare("SELECT id, tld, name, selected FROM extension") {
+    let mut stmt = match conn.prepare("SELECT e.id, e.tld, e.name, e.selected, p.min_length, p.max_length, p.allow_numeric_only, p.idn_scripts, p.local_presence
+         FROM extension e LEFT JOIN extension_policy p ON p.tld = e.tld") {
         Ok(result) => result,
-        Err(error) => panic!("{}", error)
+        Err(error) => {
+            eprintln!("{}", error);
+            return vec![];
+        }
     };
 
     handle_extensions_result(&mut stmt)
@@ -55,9 +59,13 @@ pub fn find_all_extensions() -> Vec<Extension> {
 pub fn find_selected_extensions() -> Vec<Extension> {
     let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
 
-    let mut stmt = match conn.prepare("SELECT id, tld, name, selected FROM extension WHERE selected = TRUE") {
+    let mut stmt = match conn.prepare("SELECT e.id, e.tld, e.name, e.selected, p.min_length, p.max_length, p.allow_numeric_only, p.idn_scripts, p.local_presence
+         FROM extension e LEFT JOIN extension_policy p ON p.tld = e.tld WHERE e.selected = TRUE") {
         Ok(result) => result,
-        Err(error) => panic!("{}", error)
+        Err(error) => {
+            eprintln!("{}", error);
+            return vec![];
+        }
     };
 
     handle_extensions_result(&mut stmt)
@@ -66,10 +74,26 @@ pub fn find_selected_extensions() -> Vec<Extension> {
 /// Trigger a SQL query to get extensions
 fn handle_extensions_result(stmt: &mut Statement) -> Vec<Extension> {
     let extensions_iter = stmt.query_map([], |row| {
+        let tld: String = row.get(1)?;
+        let min_length: Option<usize> = row.get(4)?;
+
+        let policy = match min_length {
+            Some(min_length) => Some(RegistryPolicy {
+                tld: tld.clone(),
+                min_length,
+                max_length: row.get(5)?,
+                allow_numeric_only: row.get(6)?,
+                idn_scripts: row.get(7)?,
+                local_presence: row.get(8)?,
+            }),
+            None => None,
+        };
+
         Ok(Extension {
-            tld: row.get(1)?,
+            tld,
             name: row.get(2)?,
             selected: row.get(3)?,
+            policy,
         })
     });
 
@@ -78,8 +102,5 @@ fn handle_extensions_result(stmt: &mut Statement) -> Vec<Extension> {
         Err(_) => Ok(vec![])
     };
 
-    match extensions_result {
-        Ok(result) => result,
-        Err(_) => vec![]
-    }
+    extensions_result.unwrap_or_default()
 }
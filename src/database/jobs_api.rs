@@ -0,0 +1,191 @@
+use std::time::Duration;
+use crate::database::connection::DB_CONNECTION;
+use crate::models::{Domain, Job};
+
+/// Create a scan job and return its ID
+pub fn create_job(job: &Job) -> Option<i64> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    let response = conn.execute(
+        "INSERT INTO scan_job (pattern, tlds, cursor, total, found, status, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, CAST(strftime('%s', 'now') AS INTEGER))",
+        (&job.pattern, &job.tlds, &job.cursor, &job.total, &job.found, &job.status),
+    )
+        .map_err(|e| format!("Failed to create job: {}", e));
+
+    match response {
+        Ok(_) => Some(conn.last_insert_rowid()),
+        Err(error) => {
+            eprintln!("JOBS API => {}", error);
+            None
+        }
+    }
+}
+
+/// Update the progress and status of a scan job
+pub fn update_job(job: &Job) -> Option<()> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    let response = conn.execute(
+        "UPDATE scan_job SET cursor = ?1, found = ?2, status = ?3, updated_at = CAST(strftime('%s', 'now') AS INTEGER)
+         WHERE id = ?4",
+        (&job.cursor, &job.found, &job.status, &job.id),
+    )
+        .map_err(|e| format!("Failed to update job: {}", e));
+
+    match response {
+        Ok(_) => Some(()),
+        Err(error) => {
+            eprintln!("JOBS API => {}", error);
+            None
+        }
+    }
+}
+
+/// Mark the running jobs which were not updated for a while as interrupted, so that they can be resumed
+/// (e.g. when the process running them was killed)
+pub fn mark_interrupted_jobs(timeout: Duration) -> Option<usize> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    let response = conn.execute(
+        "UPDATE scan_job SET status = ?1 WHERE status = ?2 AND updated_at < CAST(strftime('%s', 'now') AS INTEGER) - ?3",
+        (Job::INTERRUPTED, Job::RUNNING, timeout.as_secs() as i64),
+    )
+        .map_err(|e| format!("Failed to update interrupted jobs: {}", e));
+
+    match response {
+        Ok(count) => Some(count),
+        Err(error) => {
+            eprintln!("JOBS API => {}", error);
+            None
+        }
+    }
+}
+
+/// Delete a scan job and its results in a single transaction, returns false when the job is running
+pub fn delete_job(job: &Job) -> Option<bool> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    let response = conn.unchecked_transaction()
+        .and_then(|transaction| {
+            let count = transaction.execute("DELETE FROM scan_job WHERE id = ?1 AND status != ?2", (&job.id, Job::RUNNING))?;
+            if count > 0 {
+                transaction.execute("DELETE FROM scan_result WHERE job_id = ?1", [&job.id])?;
+            }
+            transaction.commit()?;
+            Ok(count > 0)
+        })
+        .map_err(|e| format!("Failed to delete job: {}", e));
+
+    match response {
+        Ok(deleted) => Some(deleted),
+        Err(error) => {
+            eprintln!("JOBS API => {}", error);
+            None
+        }
+    }
+}
+
+/// Get all scan jobs
+pub fn find_all_jobs() -> Vec<Job> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    let mut stmt = match conn.prepare("SELECT id, pattern, tlds, cursor, total, found, status FROM scan_job") {
+        Ok(result) => result,
+        Err(error) => panic!("{}", error)
+    };
+
+    let jobs_iter = stmt.query_map([], |row| {
+        Ok(Job {
+            id: row.get(0)?,
+            pattern: row.get(1)?,
+            tlds: row.get(2)?,
+            cursor: row.get(3)?,
+            total: row.get(4)?,
+            found: row.get(5)?,
+            status: row.get(6)?,
+        })
+    });
+
+    let jobs_result = match jobs_iter {
+        Ok(result) => result.collect::<Result<Vec<Job>, rusqlite::Error>>(),
+        Err(_) => Ok(vec![])
+    };
+
+    jobs_result.unwrap_or_default()
+}
+
+/// Get a scan job by ID
+pub fn find_job(id: i64) -> Option<Job> {
+    find_all_jobs().into_iter().find(|job| job.id == id)
+}
+
+/// Get the status of a scan job, used to detect a pause requested from another process
+pub fn find_job_status(id: i64) -> Option<String> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    conn.query_row("SELECT status FROM scan_job WHERE id = ?1", [&id], |row| row.get(0)).ok()
+}
+
+/// Save the available domains found for a label along with the job progress in a single transaction, so that a label
+/// is never scanned twice or skipped when the job is interrupted
+pub fn save_job_progress(job: &Job, domains: &[&Domain]) -> Option<()> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    let response = conn.unchecked_transaction()
+        .and_then(|transaction| {
+            {
+                let mut stmt = transaction.prepare(
+                    "INSERT INTO scan_result (job_id, tld, domain, status) VALUES (?1, ?2, ?3, ?4)"
+                )?;
+                for domain in domains {
+                    stmt.execute((&job.id, &domain.tld, &domain.domain, &domain.status))?;
+                }
+            }
+            // The status is kept, it may have been paused from another process
+            transaction.execute(
+                "UPDATE scan_job SET cursor = ?1, found = ?2, updated_at = CAST(strftime('%s', 'now') AS INTEGER)
+                 WHERE id = ?3",
+                (&job.cursor, &job.found, &job.id),
+            )?;
+            transaction.commit()
+        })
+        .map_err(|e| format!("Failed to save job progress: {}", e));
+
+    match response {
+        Ok(_) => Some(()),
+        Err(error) => {
+            eprintln!("JOBS API => {}", error);
+            None
+        }
+    }
+}
+
+/// Get the available domains found by a scan job
+pub fn find_job_results(job_id: i64) -> Vec<Domain> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    let mut stmt = match conn.prepare("SELECT tld, domain, status FROM scan_result WHERE job_id = ?1") {
+        Ok(result) => result,
+        Err(error) => panic!("{}", error)
+    };
+
+    let domains_iter = stmt.query_map([&job_id], |row| {
+        Ok(Domain {
+            tld: row.get(0)?,
+            domain: row.get(1)?,
+            status: row.get(2)?,
+            selected: false,
+            source: String::new(),
+            reason: String::new(),
+            alternative: false,
+        })
+    });
+
+    let domains_result = match domains_iter {
+        Ok(result) => result.collect::<Result<Vec<Domain>, rusqlite::Error>>(),
+        Err(_) => Ok(vec![])
+    };
+
+    domains_result.unwrap_or_default()
+}
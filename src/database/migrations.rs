@@ -1,15 +1,60 @@
 use std::sync::MutexGuard;
+use std::sync::atomic::{AtomicBool, Ordering};
 use rusqlite::{Connection, Result};
 use crate::database::extensions_api::create_extension;
+use crate::database::thesaurus_api::{create_thesaurus_entries, parse_thesaurus};
+use crate::database::policies_api::create_policy;
+use crate::database::reserved_api::{create_reserved_names, parse_reserved_names};
+use crate::database::value_rules_api::create_value_rules;
+use crate::database::blocklist_api::{create_blocked_words, parse_blocked_words};
 use crate::database::connection::DB_CONNECTION;
-use crate::models::Extension;
+use crate::models::{BlockedWord, Extension, RegistryPolicy, ValueRule};
+use crate::thesaurus::DEFAULT_THESAURUS;
 
-pub fn run_migrations() -> Result<()> {
+/// Version of the tables created by the migrations, increased whenever a migration or default data is added
+pub const SCHEMA_VERSION: u32 = 5;
+
+/// Whether the migrations print every step, errors are always printed
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+/// Print a migration step when the migrations are verbose
+macro_rules! log {
+    ($($arg:tt)*) => {
+        if VERBOSE.load(Ordering::Relaxed) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Create or upgrade the tables and add the default data, printing every step when `verbose` is set (e.g. for `init`)
+pub fn run_migrations(verbose: bool) -> Result<()> {
+    VERBOSE.store(verbose, Ordering::Relaxed);
     let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
 
     create_wishlist_table(&conn)?;
+    add_wishlist_unicode_column(&conn)?;
     create_extension_table(&conn)?;
     create_default_extensions(&conn)?;
+    create_extension_policy_table(&conn)?;
+    rename_policy_numeric_only_column(&conn)?;
+    create_default_policies(&conn)?;
+    create_reserved_name_table(&conn)?;
+    create_default_reserved_names(&conn)?;
+    create_value_rule_table(&conn)?;
+    split_dictionary_value_rules(&conn)?;
+    create_default_value_rules(&conn)?;
+    create_blocked_word_table(&conn)?;
+    add_blocked_word_workspace_column(&conn)?;
+    create_default_blocked_words(&conn)?;
+    create_trademark_table(&conn)?;
+    create_owned_domain_table(&conn)?;
+    create_scan_job_table(&conn)?;
+    add_scan_job_updated_at_column(&conn)?;
+    create_scan_result_table(&conn)?;
+    create_thesaurus_table(&conn)?;
+    create_default_thesaurus(&conn)?;
+
+    conn.pragma_update(None, "user_version", SCHEMA_VERSION)?;
 
     Ok(())
 }
@@ -29,11 +74,38 @@ fn create_wishlist_table(conn: &MutexGuard<Connection>) -> Result<()> {
 
     match wishlist_result {
         Ok(_) => {
-            println!("MIGRATION => Successfully created 'wishlist' table.");
+            log!("MIGRATION => Successfully created 'wishlist' table.");
+        }
+        Err(error) => {
+            eprintln!("MIGRATION => Could not create 'wishlist' table.");
+            eprintln!("MIGRATION => {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Add the `unicode_domain` column to the `wishlist` table, storing the Unicode form of internationalized domains
+fn add_wishlist_unicode_column(conn: &MutexGuard<Connection>) -> Result<()> {
+    let mut stmt = conn.prepare("SELECT name FROM pragma_table_info('wishlist') WHERE name = 'unicode_domain'")?;
+
+    if stmt.exists([])? {
+        log!("MIGRATION => Column 'unicode_domain' already added to 'wishlist' table.");
+        return Ok(());
+    }
+
+    let column_result = conn.execute(
+        "ALTER TABLE wishlist ADD COLUMN unicode_domain VARCHAR(70) NOT NULL DEFAULT ''",
+        [],
+    );
+
+    match column_result {
+        Ok(_) => {
+            log!("MIGRATION => Successfully added 'unicode_domain' column to 'wishlist' table.");
         }
         Err(error) => {
-            println!("MIGRATION => Could not create 'wishlist' table.");
-            println!("MIGRATION => {}", error);
+            eprintln!("MIGRATION => Could not add 'unicode_domain' column to 'wishlist' table.");
+            eprintln!("MIGRATION => {}", error);
         }
     }
 
@@ -54,26 +126,447 @@ fn create_extension_table(conn: &MutexGuard<Connection>) -> Result<()> {
 
     match extension_result {
         Ok(_) => {
-            println!("MIGRATION => Successfully created 'extension' table.");
+            log!("MIGRATION => Successfully created 'extension' table.");
+        }
+        Err(error) => {
+            eprintln!("MIGRATION => Could not create 'extension' table.");
+            eprintln!("MIGRATION => {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Create the `extension_policy` table
+fn create_extension_policy_table(conn: &MutexGuard<Connection>) -> Result<()> {
+    let policy_result = conn.execute("
+        CREATE TABLE IF NOT EXISTS extension_policy (
+            id INTEGER PRIMARY KEY,
+            tld VARCHAR(20) NOT NULL,
+            min_length INTEGER NOT NULL,
+            max_length INTEGER NOT NULL,
+            allow_numeric_only TINYINT(1) NOT NULL,
+            idn_scripts VARCHAR(255) NOT NULL,
+            local_presence TINYINT(1) NOT NULL
+        )",
+                                     [],
+    );
+
+    match policy_result {
+        Ok(_) => {
+            log!("MIGRATION => Successfully created 'extension_policy' table.");
+        }
+        Err(error) => {
+            eprintln!("MIGRATION => Could not create 'extension_policy' table.");
+            eprintln!("MIGRATION => {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Rename the `numeric_only` column of the `extension_policy` table, which tells whether numeric-only names are allowed
+fn rename_policy_numeric_only_column(conn: &MutexGuard<Connection>) -> Result<()> {
+    let mut stmt = conn.prepare("SELECT name FROM pragma_table_info('extension_policy') WHERE name = 'numeric_only'")?;
+
+    if !stmt.exists([])? {
+        log!("MIGRATION => Column 'numeric_only' already renamed in 'extension_policy' table.");
+        return Ok(());
+    }
+
+    let column_result = conn.execute(
+        "ALTER TABLE extension_policy RENAME COLUMN numeric_only TO allow_numeric_only",
+        [],
+    );
+
+    match column_result {
+        Ok(_) => {
+            log!("MIGRATION => Successfully renamed 'numeric_only' column in 'extension_policy' table.");
+        }
+        Err(error) => {
+            eprintln!("MIGRATION => Could not rename 'numeric_only' column in 'extension_policy' table.");
+            eprintln!("MIGRATION => {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Add the default registry policies, existing policies are kept
+fn create_default_policies(conn: &MutexGuard<Connection>) -> Result<()> {
+    let policies: Vec<RegistryPolicy> = match serde_json::from_str(DEFAULT_POLICIES) {
+        Ok(result) => result,
+        Err(error) => { panic!("{}", error) }
+    };
+
+    for policy in policies.iter() {
+        create_policy(conn, policy);
+    }
+
+    log!("MIGRATION => Successfully added default policies.");
+
+    Ok(())
+}
+
+/// Create the `reserved_name` table
+fn create_reserved_name_table(conn: &MutexGuard<Connection>) -> Result<()> {
+    let reserved_result = conn.execute("
+        CREATE TABLE IF NOT EXISTS reserved_name (
+            id INTEGER PRIMARY KEY,
+            tld VARCHAR(20) NOT NULL,
+            name VARCHAR(70) NOT NULL,
+            reason VARCHAR(255) NOT NULL
+        )",
+                                       [],
+    );
+
+    match reserved_result {
+        Ok(_) => {
+            log!("MIGRATION => Successfully created 'reserved_name' table.");
+        }
+        Err(error) => {
+            eprintln!("MIGRATION => Could not create 'reserved_name' table.");
+            eprintln!("MIGRATION => {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Add the default reserved names
+/// - names reserved by ICANN for every extension
+/// - two-letter country codes, reserved under the generic extensions introduced after 2000
+fn create_default_reserved_names(conn: &MutexGuard<Connection>) -> Result<()> {
+    let icann_names: Vec<String> = DEFAULT_RESERVED_NAMES.split_whitespace().map(String::from).collect();
+    let mut reserved_names = parse_reserved_names(&icann_names, "*", "ICANN reserved");
+
+    let country_codes: Vec<String> = get_default_extensions()
+        .into_iter()
+        .map(|extension| extension.tld)
+        .filter(|tld| tld.len() == 2)
+        .collect();
+
+    for tld in TWO_LETTER_RESERVED_TLDS {
+        reserved_names.extend(parse_reserved_names(&country_codes, tld, "Two-letter country code"));
+    }
+
+    if let Some(count) = create_reserved_names(conn, &reserved_names) {
+        log!("MIGRATION => Successfully added {} reserved names.", count);
+    }
+
+    Ok(())
+}
+
+/// Create the `value_rule` table
+fn create_value_rule_table(conn: &MutexGuard<Connection>) -> Result<()> {
+    let value_rule_result = conn.execute("
+        CREATE TABLE IF NOT EXISTS value_rule (
+            id INTEGER PRIMARY KEY,
+            kind VARCHAR(20) NOT NULL,
+            value VARCHAR(255) NOT NULL,
+            points INTEGER NOT NULL,
+            description VARCHAR(255) NOT NULL
+        )",
+                                         [],
+    );
+
+    match value_rule_result {
+        Ok(_) => {
+            log!("MIGRATION => Successfully created 'value_rule' table.");
+        }
+        Err(error) => {
+            eprintln!("MIGRATION => Could not create 'value_rule' table.");
+            eprintln!("MIGRATION => {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Change the "50" dictionary rule to "50-99", so that it does not add its points to the "100" rule
+fn split_dictionary_value_rules(conn: &MutexGuard<Connection>) -> Result<()> {
+    let update_result = conn.execute(
+        "UPDATE value_rule SET value = '50-99' WHERE kind = 'dictionary' AND value = '50'
+         AND EXISTS (SELECT 1 FROM value_rule WHERE kind = 'dictionary' AND value = '100')
+         AND NOT EXISTS (SELECT 1 FROM value_rule WHERE kind = 'dictionary' AND value = '50-99')",
+        [],
+    );
+
+    match update_result {
+        Ok(0) => {}
+        Ok(_) => {
+            log!("MIGRATION => Successfully changed the '50' dictionary rule to '50-99'.");
+        }
+        Err(error) => {
+            eprintln!("MIGRATION => Could not change the '50' dictionary rule.");
+            eprintln!("MIGRATION => {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Add the default valuation rules
+fn create_default_value_rules(conn: &MutexGuard<Connection>) -> Result<()> {
+    let rules = get_default_value_rules();
+
+    if let Some(count) = create_value_rules(conn, &rules, false) {
+        log!("MIGRATION => Successfully added {} valuation rules.", count);
+    }
+
+    Ok(())
+}
+
+/// Create the `blocked_word` table
+fn create_blocked_word_table(conn: &MutexGuard<Connection>) -> Result<()> {
+    let blocked_word_result = conn.execute("
+        CREATE TABLE IF NOT EXISTS blocked_word (
+            id INTEGER PRIMARY KEY,
+            word VARCHAR(70) NOT NULL,
+            language VARCHAR(20) NOT NULL,
+            exact TINYINT(1) NOT NULL
+        )",
+                                           [],
+    );
+
+    match blocked_word_result {
+        Ok(_) => {
+            log!("MIGRATION => Successfully created 'blocked_word' table.");
         }
         Err(error) => {
-            println!("MIGRATION => Could not create 'extension' table.");
-            println!("MIGRATION => {}", error);
+            eprintln!("MIGRATION => Could not create 'blocked_word' table.");
+            eprintln!("MIGRATION => {}", error);
         }
     }
 
     Ok(())
 }
 
+/// Add the `workspace` column to the `blocked_word` table, words imported in a workspace are only flagged there
+fn add_blocked_word_workspace_column(conn: &MutexGuard<Connection>) -> Result<()> {
+    let mut stmt = conn.prepare("SELECT name FROM pragma_table_info('blocked_word') WHERE name = 'workspace'")?;
+
+    if stmt.exists([])? {
+        log!("MIGRATION => Column 'workspace' already added to 'blocked_word' table.");
+        return Ok(());
+    }
+
+    let column_result = conn.execute(
+        "ALTER TABLE blocked_word ADD COLUMN workspace VARCHAR(255) NOT NULL DEFAULT ''",
+        [],
+    );
+
+    match column_result {
+        Ok(_) => {
+            log!("MIGRATION => Successfully added 'workspace' column to 'blocked_word' table.");
+        }
+        Err(error) => {
+            eprintln!("MIGRATION => Could not add 'workspace' column to 'blocked_word' table.");
+            eprintln!("MIGRATION => {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Add the default blocklist to every workspace
+/// Default words which already exist get the whole name flag of the current defaults
+fn create_default_blocked_words(conn: &MutexGuard<Connection>) -> Result<()> {
+    let blocked_words = get_default_blocked_words();
+
+    if let Some(count) = create_blocked_words(conn, &blocked_words, "", true) {
+        log!("MIGRATION => Successfully added {} blocked words.", count);
+    }
+
+    Ok(())
+}
+
+/// Create the `trademark` table, indexed on the normalized mark (e.g. "cocacola" for "Coca-Cola")
+fn create_trademark_table(conn: &MutexGuard<Connection>) -> Result<()> {
+    let trademark_result = conn.execute_batch("
+        CREATE TABLE IF NOT EXISTS trademark (
+            id INTEGER PRIMARY KEY,
+            mark VARCHAR(255) NOT NULL,
+            normalized_mark VARCHAR(255) NOT NULL,
+            owner VARCHAR(255) NOT NULL,
+            number VARCHAR(70) NOT NULL,
+            classes VARCHAR(255) NOT NULL,
+            office VARCHAR(20) NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS trademark_normalized_mark ON trademark (normalized_mark);",
+    );
+
+    match trademark_result {
+        Ok(_) => {
+            log!("MIGRATION => Successfully created 'trademark' table.");
+        }
+        Err(error) => {
+            eprintln!("MIGRATION => Could not create 'trademark' table.");
+            eprintln!("MIGRATION => {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Create the `owned_domain` table
+fn create_owned_domain_table(conn: &MutexGuard<Connection>) -> Result<()> {
+    let owned_result = conn.execute("
+        CREATE TABLE IF NOT EXISTS owned_domain (
+            id INTEGER PRIMARY KEY,
+            domain VARCHAR(70) NOT NULL,
+            tld VARCHAR(20) NOT NULL
+        )",
+                                    [],
+    );
+
+    match owned_result {
+        Ok(_) => {
+            log!("MIGRATION => Successfully created 'owned_domain' table.");
+        }
+        Err(error) => {
+            eprintln!("MIGRATION => Could not create 'owned_domain' table.");
+            eprintln!("MIGRATION => {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Create the `scan_job` table
+fn create_scan_job_table(conn: &MutexGuard<Connection>) -> Result<()> {
+    let scan_job_result = conn.execute("
+        CREATE TABLE IF NOT EXISTS scan_job (
+            id INTEGER PRIMARY KEY,
+            pattern VARCHAR(70) NOT NULL,
+            tlds TEXT NOT NULL,
+            cursor INTEGER NOT NULL,
+            total INTEGER NOT NULL,
+            found INTEGER NOT NULL,
+            status VARCHAR(20) NOT NULL
+        )",
+                                       [],
+    );
+
+    match scan_job_result {
+        Ok(_) => {
+            log!("MIGRATION => Successfully created 'scan_job' table.");
+        }
+        Err(error) => {
+            eprintln!("MIGRATION => Could not create 'scan_job' table.");
+            eprintln!("MIGRATION => {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Add the `updated_at` column to the `scan_job` table, used to find jobs interrupted while running
+fn add_scan_job_updated_at_column(conn: &MutexGuard<Connection>) -> Result<()> {
+    let mut stmt = conn.prepare("SELECT name FROM pragma_table_info('scan_job') WHERE name = 'updated_at'")?;
+
+    if stmt.exists([])? {
+        log!("MIGRATION => Column 'updated_at' already added to 'scan_job' table.");
+        return Ok(());
+    }
+
+    let column_result = conn.execute(
+        "ALTER TABLE scan_job ADD COLUMN updated_at INTEGER NOT NULL DEFAULT 0",
+        [],
+    );
+
+    match column_result {
+        Ok(_) => {
+            log!("MIGRATION => Successfully added 'updated_at' column to 'scan_job' table.");
+        }
+        Err(error) => {
+            eprintln!("MIGRATION => Could not add 'updated_at' column to 'scan_job' table.");
+            eprintln!("MIGRATION => {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Create the `scan_result` table
+fn create_scan_result_table(conn: &MutexGuard<Connection>) -> Result<()> {
+    let scan_result_result = conn.execute("
+        CREATE TABLE IF NOT EXISTS scan_result (
+            id INTEGER PRIMARY KEY,
+            job_id INTEGER NOT NULL,
+            tld VARCHAR(20) NOT NULL,
+            domain VARCHAR(70) NOT NULL,
+            status VARCHAR(70) NOT NULL
+        )",
+                                          [],
+    );
+
+    match scan_result_result {
+        Ok(_) => {
+            log!("MIGRATION => Successfully created 'scan_result' table.");
+        }
+        Err(error) => {
+            eprintln!("MIGRATION => Could not create 'scan_result' table.");
+            eprintln!("MIGRATION => {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Create the `thesaurus` table
+fn create_thesaurus_table(conn: &MutexGuard<Connection>) -> Result<()> {
+    let thesaurus_result = conn.execute("
+        CREATE TABLE IF NOT EXISTS thesaurus (
+            id INTEGER PRIMARY KEY,
+            word VARCHAR(70) NOT NULL,
+            related VARCHAR(70) NOT NULL
+        )",
+                                        [],
+    );
+
+    match thesaurus_result {
+        Ok(_) => {
+            log!("MIGRATION => Successfully created 'thesaurus' table.");
+        }
+        Err(error) => {
+            eprintln!("MIGRATION => Could not create 'thesaurus' table.");
+            eprintln!("MIGRATION => {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Add the bundled thesaurus
+fn create_default_thesaurus(conn: &MutexGuard<Connection>) -> Result<()> {
+    let lines: Vec<String> = DEFAULT_THESAURUS.lines().map(String::from).collect();
+    let entries = parse_thesaurus(&lines);
+
+    if let Some(count) = create_thesaurus_entries(conn, &entries) {
+        log!("MIGRATION => Successfully added {} thesaurus entries.", count);
+    }
+
+    Ok(())
+}
+
 /// Create default domain name extensions table
+/// Skipped when extensions already exist, so that migrations can be run again after an update
 fn create_default_extensions(conn: &MutexGuard<Connection>) -> Result<()> {
+    let count: i64 = conn.query_row("SELECT COUNT(*) FROM extension", [], |row| row.get(0))?;
+
+    if count > 0 {
+        log!("MIGRATION => Default extensions already added.");
+        return Ok(());
+    }
+
     let extensions = get_default_extensions();
 
     for extension in extensions.iter() {
-        create_extension(&conn, &extension);
+        create_extension(conn, extension);
     }
 
-    println!("MIGRATION => Successfully added default extensions.");
+    log!("MIGRATION => Successfully added default extensions.");
 
     Ok(())
 }
@@ -343,3 +836,97 @@ pub const DEFAULT_EXTENSIONS: &str = r#"[
   {"tld":"zm","name":"Zambia","selected":false},
   {"tld":"zw","name":"Zimbabwe","selected":false}
 ]"#;
+
+/// Names reserved by ICANN at the second level of every extension
+pub const DEFAULT_RESERVED_NAMES: &str = "
+  example nic whois www rdds iana icann internic localhost invalid
+  afrinic apnic arin lacnic ripe iab ietf irtf istf root-servers
+";
+
+/// Generic extensions where two-letter country codes are reserved at the second level
+pub const TWO_LETTER_RESERVED_TLDS: [&str; 5] = ["info", "biz", "pro", "name", "mobi"];
+
+/// Indicative registry policies, check the registry before registering
+pub const DEFAULT_POLICIES: &str = r#"[
+  {"tld":"com","min_length":1,"max_length":63,"idn_scripts":"*"},
+  {"tld":"net","min_length":1,"max_length":63,"idn_scripts":"*"},
+  {"tld":"org","min_length":1,"max_length":63,"idn_scripts":"Latin,Cyrillic,Greek,Han,Hangul,Arabic,Hebrew,Thai,Devanagari,Georgian,Armenian"},
+  {"tld":"info","min_length":3,"max_length":63,"idn_scripts":"Latin,Cyrillic,Greek,Han,Hangul"},
+  {"tld":"biz","min_length":2,"max_length":63,"idn_scripts":"Latin,Han,Hangul"},
+  {"tld":"pro","min_length":3,"max_length":63,"idn_scripts":""},
+  {"tld":"name","min_length":1,"max_length":63,"idn_scripts":"Latin,Cyrillic,Greek"},
+  {"tld":"mobi","min_length":3,"max_length":63,"idn_scripts":"Latin,Cyrillic,Han"},
+  {"tld":"ca","min_length":2,"max_length":63,"idn_scripts":"Latin","local_presence":true},
+  {"tld":"us","min_length":1,"max_length":63,"idn_scripts":"","local_presence":true},
+  {"tld":"uk","min_length":1,"max_length":63,"idn_scripts":""},
+  {"tld":"de","min_length":1,"max_length":63,"idn_scripts":"Latin,Greek,Cyrillic","local_presence":true},
+  {"tld":"fr","min_length":1,"max_length":63,"idn_scripts":"Latin","local_presence":true},
+  {"tld":"it","min_length":3,"max_length":63,"idn_scripts":"Latin","local_presence":true},
+  {"tld":"es","min_length":3,"max_length":63,"idn_scripts":"Latin"},
+  {"tld":"eu","min_length":2,"max_length":63,"idn_scripts":"Latin,Greek,Cyrillic","local_presence":true},
+  {"tld":"nl","min_length":2,"max_length":63,"idn_scripts":""},
+  {"tld":"au","min_length":2,"max_length":63,"idn_scripts":"","local_presence":true},
+  {"tld":"jp","min_length":1,"max_length":63,"idn_scripts":"Han,Hiragana,Katakana","local_presence":true},
+  {"tld":"cn","min_length":1,"max_length":63,"idn_scripts":"Han"},
+  {"tld":"br","min_length":2,"max_length":26,"idn_scripts":"Latin","local_presence":true},
+  {"tld":"io","min_length":1,"max_length":63,"idn_scripts":""}
+]"#;
+
+pub fn get_default_value_rules() -> Vec<ValueRule> {
+    // Transform content into a vector of ValueRule
+    match serde_json::from_str(DEFAULT_VALUE_RULES) {
+        Ok(result) => result,
+        Err(error) => { panic!("{}", error) }
+    }
+}
+
+pub const DEFAULT_VALUE_RULES: &str = r#"[
+  {"kind":"tier","value":"Premium","points":70},
+  {"kind":"tier","value":"High","points":45},
+  {"kind":"tier","value":"Medium","points":25},
+  {"kind":"tier","value":"Low","points":0},
+  {"kind":"length","value":"1-3","points":40,"description":"Very short"},
+  {"kind":"length","value":"4-5","points":30,"description":"Short"},
+  {"kind":"length","value":"6-8","points":15,"description":"Medium length"},
+  {"kind":"length","value":"9-12","points":5,"description":"Long"},
+  {"kind":"length","value":"13-15","points":0,"description":"Fairly long"},
+  {"kind":"length","value":"16-63","points":-10,"description":"Very long"},
+  {"kind":"tld","value":"com","points":25,"description":".com"},
+  {"kind":"tld","value":"ai","points":20,"description":".ai"},
+  {"kind":"tld","value":"io","points":15,"description":".io"},
+  {"kind":"tld","value":"co","points":10,"description":".co"},
+  {"kind":"tld","value":"net","points":8,"description":".net"},
+  {"kind":"tld","value":"org","points":8,"description":".org"},
+  {"kind":"tld","value":"app","points":8,"description":".app"},
+  {"kind":"tld","value":"dev","points":8,"description":".dev"},
+  {"kind":"tld","value":"info","points":-5,"description":".info"},
+  {"kind":"tld","value":"biz","points":-5,"description":".biz"},
+  {"kind":"dictionary","value":"100","points":12,"description":"Dictionary words only"},
+  {"kind":"dictionary","value":"50-99","points":8,"description":"Dictionary words"},
+  {"kind":"keyword","value":"pay,bank,cash,coin,fund,loan,money,invest,crypto,trade","points":15,"description":"Finance keyword"},
+  {"kind":"keyword","value":"ai,bot,cloud,data,code,tech,soft,web,app,net","points":10,"description":"Tech keyword"},
+  {"kind":"keyword","value":"health,care,med,doc,clinic,fit,diet","points":10,"description":"Health keyword"},
+  {"kind":"keyword","value":"home,house,estate,rent,travel,hotel,trip,food,shop,store","points":8,"description":"Consumer keyword"},
+  {"kind":"charset","value":"letters","points":10,"description":"Letters only"},
+  {"kind":"charset","value":"digits","points":-10,"description":"Contains digits"},
+  {"kind":"charset","value":"hyphen","points":-15,"description":"Contains hyphens"},
+  {"kind":"charset","value":"idn","points":-10,"description":"Internationalized"}
+]"#;
+
+pub fn get_default_blocked_words() -> Vec<BlockedWord> {
+    let lines: Vec<String> = DEFAULT_BLOCKLIST.lines().map(String::from).collect();
+    parse_blocked_words(&lines, "en")
+}
+
+/// Offensive and negative words by language, "=" marks words only flagged when they are the whole name
+/// Words commonly found inside harmless words are whole names only (e.g. "rape" in "grape", "nique" in "unique")
+pub const DEFAULT_BLOCKLIST: &str = r#"
+en: fuck, shit, =cunt, bitch, bastard, whore, slut, wank, twat, piss, porn, =rape, nazi, =dick, =cock, jizz, nigg, =retard, dildo, =ass, =tit, =cum, =fag, =kkk
+en: =scam, fraud, =death, =dead, =kill, =hate, =toxic, =loser, =die, =sucks
+fr: merde, putain, salope, connard, connasse, encule, couille, =nique, chier, batard, =pute, =bite, =con, =mort
+es: mierda, =puta, =puto, joder, cabron, pendejo, polla, =culo, =muerte, =caca
+de: scheisse, =arsch, fotze, wichser, ficken, nutte, =hure, =mist, =gift, =tod
+it: cazzo, stronzo, vaffanculo, puttana, merda, troia, =figa, =morte
+pt: caralho, porra, buceta, foder, viado, =merda
+nl: kanker, klootzak, =lul, =kut, =hoer
+"#;
@@ -0,0 +1,53 @@
+use crate::database::connection::DB_CONNECTION;
+
+/// Add domains owned by the user (label and extension pairs), returns the number of domains added
+pub fn add_owned_domains(domains: &[(String, String)]) -> Option<usize> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    let response = conn.unchecked_transaction()
+        .and_then(|transaction| {
+            let mut count = 0;
+            {
+                let mut stmt = transaction.prepare(
+                    "INSERT INTO owned_domain (domain, tld)
+                     SELECT ?1, ?2 WHERE NOT EXISTS (SELECT 1 FROM owned_domain WHERE domain = ?1 AND tld = ?2)"
+                )?;
+                for (domain, tld) in domains {
+                    count += stmt.execute((domain, tld))?;
+                }
+            }
+            transaction.commit()?;
+            Ok(count)
+        })
+        .map_err(|e| format!("Failed to add owned domains: {}", e));
+
+    match response {
+        Ok(count) => Some(count),
+        Err(error) => {
+            eprintln!("OWNED API => {}", error);
+            None
+        }
+    }
+}
+
+/// Get the domains owned by the user (label and extension pairs)
+pub fn find_owned_domains() -> Vec<(String, String)> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    let mut stmt = match conn.prepare("SELECT domain, tld FROM owned_domain ORDER BY domain, tld") {
+        Ok(result) => result,
+        Err(error) => {
+            eprintln!("OWNED API => {}", error);
+            return vec![];
+        }
+    };
+
+    let owned_iter = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)));
+
+    let owned_result = match owned_iter {
+        Ok(result) => result.collect::<Result<Vec<(String, String)>, rusqlite::Error>>(),
+        Err(_) => Ok(vec![])
+    };
+
+    owned_result.unwrap_or_default()
+}
@@ -0,0 +1,55 @@
+use std::sync::MutexGuard;
+use rusqlite::Connection;
+use crate::database::connection::DB_CONNECTION;
+use crate::models::RegistryPolicy;
+
+/// Create the registry policy of an extension, unless the extension already has one
+pub fn create_policy(conn: &MutexGuard<Connection>, policy: &RegistryPolicy) -> Option<()> {
+    let response = conn.execute(
+        "INSERT INTO extension_policy (tld, min_length, max_length, allow_numeric_only, idn_scripts, local_presence)
+         SELECT ?1, ?2, ?3, ?4, ?5, ?6 WHERE NOT EXISTS (SELECT 1 FROM extension_policy WHERE tld = ?1)",
+        (&policy.tld, &policy.min_length, &policy.max_length, &policy.allow_numeric_only, &policy.idn_scripts, &policy.local_presence),
+    )
+        .map_err(|e| format!("Failed to insert policy: {}", e));
+
+    match response {
+        Ok(_) => Some(()),
+        Err(error) => {
+            eprintln!("{}", error);
+            None
+        }
+    }
+}
+
+/// Get the registry policies of all extensions
+pub fn find_all_policies() -> Vec<RegistryPolicy> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    let mut stmt = match conn.prepare(
+        "SELECT tld, min_length, max_length, allow_numeric_only, idn_scripts, local_presence FROM extension_policy"
+    ) {
+        Ok(result) => result,
+        Err(error) => {
+            eprintln!("{}", error);
+            return vec![];
+        }
+    };
+
+    let policies_iter = stmt.query_map([], |row| {
+        Ok(RegistryPolicy {
+            tld: row.get(0)?,
+            min_length: row.get(1)?,
+            max_length: row.get(2)?,
+            allow_numeric_only: row.get(3)?,
+            idn_scripts: row.get(4)?,
+            local_presence: row.get(5)?,
+        })
+    });
+
+    let policies_result = match policies_iter {
+        Ok(result) => result.collect::<Result<Vec<RegistryPolicy>, rusqlite::Error>>(),
+        Err(_) => Ok(vec![])
+    };
+
+    policies_result.unwrap_or_default()
+}
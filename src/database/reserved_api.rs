@@ -0,0 +1,91 @@
+use std::sync::MutexGuard;
+use rusqlite::Connection;
+use crate::database::connection::DB_CONNECTION;
+use crate::models::ReservedName;
+use crate::validation::to_ascii_label;
+
+/// Parse reserved names lines such as "example" or "example, Registry reserved"
+/// Internationalized names are stored in their ASCII form (e.g. "café" => "xn--caf-dma"), invalid names are skipped
+pub fn parse_reserved_names(lines: &[String], tld: &str, default_reason: &str) -> Vec<ReservedName> {
+    lines
+        .iter()
+        .map(|line| match line.split_once(',') {
+            Some((name, reason)) => (name.trim(), reason.trim()),
+            None => (line.trim(), default_reason),
+        })
+        .filter(|(name, _)| !name.is_empty())
+        .filter_map(|(name, reason)| match to_ascii_label(&name.to_lowercase()) {
+            Ok(name) => Some(ReservedName {
+                tld: tld.to_lowercase(),
+                name,
+                reason: reason.to_string(),
+            }),
+            Err(error) => {
+                eprintln!("RESERVED API => Skipping reserved name: {}", error);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Create reserved names in a single transaction, returns the number of names added
+pub fn create_reserved_names(conn: &MutexGuard<Connection>, reserved_names: &[ReservedName]) -> Option<usize> {
+    let response = conn.unchecked_transaction()
+        .and_then(|transaction| {
+            let mut count = 0;
+            {
+                let mut stmt = transaction.prepare(
+                    "INSERT INTO reserved_name (tld, name, reason)
+                     SELECT ?1, ?2, ?3 WHERE NOT EXISTS (SELECT 1 FROM reserved_name WHERE tld = ?1 AND name = ?2)"
+                )?;
+                for reserved_name in reserved_names {
+                    count += stmt.execute((&reserved_name.tld, &reserved_name.name, &reserved_name.reason))?;
+                }
+            }
+            transaction.commit()?;
+            Ok(count)
+        })
+        .map_err(|e| format!("Failed to add reserved names: {}", e));
+
+    match response {
+        Ok(count) => Some(count),
+        Err(error) => {
+            eprintln!("RESERVED API => {}", error);
+            None
+        }
+    }
+}
+
+/// Import reserved names
+pub fn import_reserved_names(reserved_names: &[ReservedName]) -> Option<usize> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+    create_reserved_names(&conn, reserved_names)
+}
+
+/// Get the reservations of a name, for every extension ("*" for all extensions)
+pub fn find_reservations(name: &str) -> Vec<ReservedName> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    let mut stmt = match conn.prepare("SELECT tld, name, reason FROM reserved_name WHERE name = ?1") {
+        Ok(result) => result,
+        Err(error) => {
+            eprintln!("RESERVED API => {}", error);
+            return vec![];
+        }
+    };
+
+    let reserved_iter = stmt.query_map([name], |row| {
+        Ok(ReservedName {
+            tld: row.get(0)?,
+            name: row.get(1)?,
+            reason: row.get(2)?,
+        })
+    });
+
+    let reserved_result = match reserved_iter {
+        Ok(result) => result.collect::<Result<Vec<ReservedName>, rusqlite::Error>>(),
+        Err(_) => Ok(vec![])
+    };
+
+    reserved_result.unwrap_or_default()
+}
@@ -0,0 +1,80 @@
+use std::sync::MutexGuard;
+use rusqlite::Connection;
+use crate::database::connection::DB_CONNECTION;
+
+/// Parse thesaurus lines such as "cloud: sky, nimbus, vapor" into (word, related word) pairs
+pub fn parse_thesaurus(lines: &[String]) -> Vec<(String, String)> {
+    let mut entries: Vec<(String, String)> = vec![];
+
+    for line in lines {
+        if let Some((word, related_words)) = line.split_once(':') {
+            let word = word.trim().to_lowercase();
+
+            for related in related_words.split(',') {
+                let related = related.trim().to_lowercase();
+                if !word.is_empty() && !related.is_empty() && related != word {
+                    entries.push((word.clone(), related));
+                }
+            }
+        }
+    }
+
+    entries
+}
+
+/// Create thesaurus entries in a single transaction, returns the number of entries added
+pub fn create_thesaurus_entries(conn: &MutexGuard<Connection>, entries: &[(String, String)]) -> Option<usize> {
+    let response = conn.unchecked_transaction()
+        .and_then(|transaction| {
+            let mut count = 0;
+            {
+                let mut stmt = transaction.prepare(
+                    "INSERT INTO thesaurus (word, related)
+                     SELECT ?1, ?2 WHERE NOT EXISTS (SELECT 1 FROM thesaurus WHERE word = ?1 AND related = ?2)"
+                )?;
+                for (word, related) in entries {
+                    count += stmt.execute((word, related))?;
+                }
+            }
+            transaction.commit()?;
+            Ok(count)
+        })
+        .map_err(|e| format!("Failed to add thesaurus entries: {}", e));
+
+    match response {
+        Ok(count) => Some(count),
+        Err(error) => {
+            eprintln!("THESAURUS API => {}", error);
+            None
+        }
+    }
+}
+
+/// Import thesaurus entries
+pub fn import_thesaurus_entries(entries: &[(String, String)]) -> Option<usize> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+    create_thesaurus_entries(&conn, entries)
+}
+
+/// Get the words related to a word, in both directions
+pub fn find_related_words(word: &str) -> Vec<String> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    let mut stmt = match conn.prepare("
+        SELECT related FROM thesaurus WHERE word = ?1
+        UNION
+        SELECT word FROM thesaurus WHERE related = ?1"
+    ) {
+        Ok(result) => result,
+        Err(error) => panic!("{}", error)
+    };
+
+    let words_iter = stmt.query_map([word], |row| row.get(0));
+
+    let words_result = match words_iter {
+        Ok(result) => result.collect::<Result<Vec<String>, rusqlite::Error>>(),
+        Err(_) => Ok(vec![])
+    };
+
+    words_result.unwrap_or_default()
+}
@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::sync::MutexGuard;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use rusqlite::Connection;
+use crate::database::connection::DB_CONNECTION;
+use crate::models::Trademark;
+use crate::trademarks::normalize_mark;
+
+/// Column names used by trademark office exports, most specific first
+const MARK_COLUMNS: [&str; 6] = ["wordmark", "markverbalelementtext", "markidentification", "trademark", "mark", "denomination"];
+const OWNER_COLUMNS: [&str; 5] = ["owner", "applicant", "holder", "proprietor", "registrant"];
+const NUMBER_COLUMNS: [&str; 5] = ["registrationnumber", "applicationnumber", "serialnumber", "number", "id"];
+const CLASS_COLUMNS: [&str; 3] = ["niceclass", "classes", "class"];
+const OFFICE_COLUMNS: [&str; 2] = ["office", "registrationofficecode"];
+
+/// Element names used by trademark office XML exports (EUIPO, WIPO ST.66, USPTO)
+const RECORD_TAGS: [&str; 3] = ["TradeMark", "case-file", "Trademark"];
+const MARK_TAGS: [&str; 3] = ["MarkVerbalElementText", "mark-identification", "WordMark"];
+const OWNER_TAGS: [&str; 4] = ["ApplicantName", "OrganizationName", "party-name", "FreeFormatNameLine"];
+const NUMBER_TAGS: [&str; 4] = ["RegistrationNumber", "ApplicationNumber", "registration-number", "serial-number"];
+const CLASS_TAGS: [&str; 2] = ["ClassNumber", "international-code"];
+const OFFICE_TAGS: [&str; 1] = ["RegistrationOfficeCode"];
+
+/// Parse the rows of a CSV export, the first row being the header
+/// Columns are found by name (e.g. "Word mark", "Owner", "Registration number", "Nice classes", "Office")
+pub fn parse_trademark_csv(rows: &[Vec<String>], default_office: &str) -> Result<Vec<Trademark>, String> {
+    let (header, records) = match rows.split_first() {
+        Some(result) => result,
+        None => return Ok(vec![]),
+    };
+
+    let mark_column = find_column(header, &MARK_COLUMNS)
+        .ok_or_else(|| format!("No mark column found in the header: {}", header.join(", ")))?;
+    let owner_column = find_column(header, &OWNER_COLUMNS);
+    let number_column = find_column(header, &NUMBER_COLUMNS);
+    let class_column = find_column(header, &CLASS_COLUMNS);
+    let office_column = find_column(header, &OFFICE_COLUMNS);
+
+    let get_cell = |record: &Vec<String>, column: Option<usize>| -> String {
+        column.and_then(|column| record.get(column)).cloned().unwrap_or_default()
+    };
+
+    let trademarks = records
+        .iter()
+        .map(|record| Trademark {
+            mark: get_cell(record, Some(mark_column)),
+            owner: get_cell(record, owner_column),
+            number: get_cell(record, number_column),
+            classes: parse_classes(&[get_cell(record, class_column)]),
+            office: Some(get_cell(record, office_column))
+                .filter(|office| !office.is_empty())
+                .unwrap_or_else(|| default_office.to_string()),
+        })
+        .filter(|trademark| !normalize_mark(&trademark.mark).is_empty())
+        .collect();
+
+    Ok(trademarks)
+}
+
+/// Parse an XML export, with one element per trademark (e.g. `<TradeMark>` or `<case-file>`)
+/// Texts can contain entities (e.g. `&#233;`) or CDATA sections, elements containing other elements are skipped
+pub fn parse_trademark_xml(content: &str, default_office: &str) -> Vec<Trademark> {
+    let mut reader = Reader::from_str(content);
+    let mut trademarks: Vec<Trademark> = vec![];
+    let mut record: Option<HashMap<String, Vec<String>>> = None; // Texts of the current record by element name
+    let mut element: Option<String> = None; // Element whose text is being read
+    let mut text = String::new();
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(start)) => {
+                let name = String::from_utf8_lossy(start.local_name().as_ref()).to_string();
+                if RECORD_TAGS.contains(&name.as_str()) {
+                    record = Some(HashMap::new());
+                }
+                element = Some(name);
+                text.clear();
+            }
+            Ok(Event::Text(content)) => match content.unescape() {
+                Ok(unescaped) => text.push_str(&unescaped),
+                Err(_) => text.push_str(&String::from_utf8_lossy(&content)),
+            },
+            Ok(Event::CData(content)) => text.push_str(&String::from_utf8_lossy(&content)),
+            Ok(Event::End(end)) => {
+                let name = String::from_utf8_lossy(end.local_name().as_ref()).to_string();
+                if RECORD_TAGS.contains(&name.as_str()) {
+                    if let Some(texts) = record.take() {
+                        let trademark = get_trademark(&texts, default_office);
+                        if !normalize_mark(&trademark.mark).is_empty() {
+                            trademarks.push(trademark);
+                        }
+                    }
+                } else if let (Some(texts), Some(element)) = (record.as_mut(), element.as_ref()) {
+                    if *element == name && !text.trim().is_empty() {
+                        texts.entry(name).or_default().push(text.trim().to_string());
+                    }
+                }
+                element = None;
+                text.clear();
+            }
+            Ok(Event::Eof) => break,
+            Err(error) => {
+                eprintln!("TRADEMARKS API => Invalid XML at position {}: {}", reader.error_position(), error);
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    trademarks
+}
+
+/// Trademark from the texts of an XML record
+fn get_trademark(texts: &HashMap<String, Vec<String>>, default_office: &str) -> Trademark {
+    let find_texts = |tags: &[&str]| -> Vec<String> {
+        tags.iter().flat_map(|tag| texts.get(*tag).cloned().unwrap_or_default()).collect()
+    };
+
+    Trademark {
+        mark: find_texts(&MARK_TAGS).into_iter().next().unwrap_or_default(),
+        owner: find_texts(&OWNER_TAGS).into_iter().next().unwrap_or_default(),
+        number: find_texts(&NUMBER_TAGS).into_iter().next().unwrap_or_default(),
+        classes: parse_classes(&find_texts(&CLASS_TAGS)),
+        office: find_texts(&OFFICE_TAGS).into_iter().next().unwrap_or_else(|| default_office.to_string()),
+    }
+}
+
+/// Index of the first column matching a name, exact names first (e.g. "Word mark" for "wordmark")
+fn find_column(header: &[String], names: &[&str]) -> Option<usize> {
+    let header: Vec<String> = header
+        .iter()
+        .map(|cell| cell.to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect())
+        .collect();
+
+    names
+        .iter()
+        .find_map(|name| header.iter().position(|cell| cell == name))
+        .or_else(|| names.iter().find_map(|name| header.iter().position(|cell| cell.contains(name))))
+}
+
+/// Normalize Nice classes (e.g. ["009; 042"] => "9,42")
+fn parse_classes(values: &[String]) -> String {
+    let mut classes: Vec<u32> = values
+        .iter()
+        .flat_map(|value| value.split(|c: char| !c.is_ascii_digit()).filter_map(|class| class.parse::<u32>().ok()))
+        .collect();
+    classes.sort();
+    classes.dedup();
+
+    classes.iter().map(|class| class.to_string()).collect::<Vec<String>>().join(",")
+}
+
+/// Create trademarks in a single transaction, returns the number of trademarks added
+pub fn create_trademarks(conn: &MutexGuard<Connection>, trademarks: &[Trademark]) -> Option<usize> {
+    let response = conn.unchecked_transaction()
+        .and_then(|transaction| {
+            let mut count = 0;
+            {
+                let mut stmt = transaction.prepare(
+                    "INSERT INTO trademark (mark, normalized_mark, owner, number, classes, office)
+                     SELECT ?1, ?2, ?3, ?4, ?5, ?6
+                     WHERE NOT EXISTS (SELECT 1 FROM trademark WHERE normalized_mark = ?2 AND number = ?4 AND office = ?6)"
+                )?;
+                for trademark in trademarks {
+                    count += stmt.execute((
+                        &trademark.mark,
+                        &normalize_mark(&trademark.mark),
+                        &trademark.owner,
+                        &trademark.number,
+                        &trademark.classes,
+                        &trademark.office,
+                    ))?;
+                }
+            }
+            transaction.commit()?;
+            Ok(count)
+        })
+        .map_err(|e| format!("Failed to add trademarks: {}", e));
+
+    match response {
+        Ok(count) => Some(count),
+        Err(error) => {
+            eprintln!("TRADEMARKS API => {}", error);
+            None
+        }
+    }
+}
+
+/// Import trademarks
+pub fn import_trademarks(trademarks: &[Trademark]) -> Option<usize> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+    create_trademarks(&conn, trademarks)
+}
+
+/// Get every trademark
+pub fn find_all_trademarks() -> Vec<Trademark> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    let mut stmt = match conn.prepare("SELECT mark, owner, number, classes, office FROM trademark") {
+        Ok(result) => result,
+        Err(error) => {
+            eprintln!("TRADEMARKS API => {}", error);
+            return vec![];
+        }
+    };
+
+    let trademarks_iter = stmt.query_map([], |row| {
+        Ok(Trademark {
+            mark: row.get(0)?,
+            owner: row.get(1)?,
+            number: row.get(2)?,
+            classes: row.get(3)?,
+            office: row.get(4)?,
+        })
+    });
+
+    let trademarks_result = match trademarks_iter {
+        Ok(result) => result.collect::<Result<Vec<Trademark>, rusqlite::Error>>(),
+        Err(_) => Ok(vec![])
+    };
+
+    trademarks_result.unwrap_or_default()
+}
@@ -0,0 +1,75 @@
+use std::sync::MutexGuard;
+use rusqlite::Connection;
+use crate::database::connection::DB_CONNECTION;
+use crate::models::ValueRule;
+
+/// Create valuation rules in a single transaction, returns the number of rules added
+/// Rules which already exist with the same kind and value get the new points and description when `replace` is set
+pub fn create_value_rules(conn: &MutexGuard<Connection>, rules: &[ValueRule], replace: bool) -> Option<usize> {
+    let response = conn.unchecked_transaction()
+        .and_then(|transaction| {
+            let mut count = 0;
+            {
+                let mut update_stmt = transaction.prepare(
+                    "UPDATE value_rule SET points = ?3, description = ?4 WHERE kind = ?1 AND value = ?2"
+                )?;
+                let mut insert_stmt = transaction.prepare(
+                    "INSERT INTO value_rule (kind, value, points, description)
+                     SELECT ?1, ?2, ?3, ?4 WHERE NOT EXISTS (SELECT 1 FROM value_rule WHERE kind = ?1 AND value = ?2)"
+                )?;
+                for rule in rules {
+                    let kind = rule.kind.to_lowercase();
+                    if replace {
+                        update_stmt.execute((&kind, &rule.value, &rule.points, &rule.description))?;
+                    }
+                    count += insert_stmt.execute((&kind, &rule.value, &rule.points, &rule.description))?;
+                }
+            }
+            transaction.commit()?;
+            Ok(count)
+        })
+        .map_err(|e| format!("Failed to add valuation rules: {}", e));
+
+    match response {
+        Ok(count) => Some(count),
+        Err(error) => {
+            eprintln!("VALUE RULES API => {}", error);
+            None
+        }
+    }
+}
+
+/// Import valuation rules
+pub fn import_value_rules(rules: &[ValueRule]) -> Option<usize> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+    create_value_rules(&conn, rules, true)
+}
+
+/// Get every valuation rule
+pub fn find_all_value_rules() -> Vec<ValueRule> {
+    let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
+
+    let mut stmt = match conn.prepare("SELECT kind, value, points, description FROM value_rule ORDER BY kind, points DESC") {
+        Ok(result) => result,
+        Err(error) => {
+            eprintln!("VALUE RULES API => {}", error);
+            return vec![];
+        }
+    };
+
+    let rules_iter = stmt.query_map([], |row| {
+        Ok(ValueRule {
+            kind: row.get(0)?,
+            value: row.get(1)?,
+            points: row.get(2)?,
+            description: row.get(3)?,
+        })
+    });
+
+    let rules_result = match rules_iter {
+        Ok(result) => result.collect::<Result<Vec<ValueRule>, rusqlite::Error>>(),
+        Err(_) => Ok(vec![])
+    };
+
+    rules_result.unwrap_or_default()
+}
@@ -6,15 +6,15 @@ pub fn add_to_wishlist(domain: &Domain) -> Option<()> {
     let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
 
     let response = conn.execute(
-        "INSERT INTO wishlist (tld, domain, status, selected) VALUES (?1, ?2, ?3, ?4)",
-        (&domain.tld, &domain.domain, &domain.status, &domain.selected),
+        "INSERT INTO wishlist (tld, domain, status, selected, unicode_domain) VALUES (?1, ?2, ?3, ?4, ?5)",
+        (&domain.tld, &domain.domain, &domain.status, &domain.selected, &domain.unicode_domain_name()),
     )
         .map_err(|e| format!("Failed to add domain to wishlist: {}", e));
 
     match response {
         Ok(_) => Some(()),
         Err(error) => {
-            println!("WISHLIST API => {}", error);
+            eprintln!("WISHLIST API => {}", error);
             None
         }
     }
@@ -33,7 +33,7 @@ pub fn remove_from_wishlist(domain: &Domain) -> Option<()> {
     match response {
         Ok(_) => Some(()),
         Err(error) => {
-            println!("WISHLIST API => {}", error);
+            eprintln!("WISHLIST API => {}", error);
             None
         }
     }
@@ -43,7 +43,7 @@ pub fn remove_from_wishlist(domain: &Domain) -> Option<()> {
 pub fn find_wishlist() -> Vec<Domain> {
     let conn = DB_CONNECTION.lock().expect("Failed to lock the database connection");
 
-    let mut stmt = match conn.prepare("SELECT * FROM wishlist") {
+    let mut stmt = match conn.prepare("SELECT id, tld, domain, status, selected FROM wishlist") {
         Ok(result) => result,
         Err(error) => panic!("{}", error)
     };
@@ -54,6 +54,9 @@ pub fn find_wishlist() -> Vec<Domain> {
             domain: row.get(2)?,
             status: row.get(3)?,
             selected: row.get(4)?,
+            source: String::new(),
+            reason: String::new(),
+            alternative: false,
         })
     });
 
@@ -62,8 +65,5 @@ pub fn find_wishlist() -> Vec<Domain> {
         Err(_) => Ok(vec![])
     };
 
-    match domains_result {
-        Ok(result) => result,
-        Err(_) => vec![]
-    }
+    domains_result.unwrap_or_default()
 }
@@ -0,0 +1,78 @@
+use std::{env, fs};
+
+/// Read a word list file (one word per line), words are lowercased
+/// Empty lines and lines starting with `#` are ignored
+pub fn read_word_list(path: &str) -> Vec<String> {
+  read_lines(path).iter().map(|line| line.to_lowercase()).collect()
+}
+
+/// Read the trimmed lines of a file, keeping their case (e.g. reasons following names)
+/// Empty lines and lines starting with `#` are ignored
+pub fn read_lines(path: &str) -> Vec<String> {
+  let content = match fs::read_to_string(path) {
+    Ok(content) => content,
+    Err(error) => { panic!("Could not read word list '{}': {}", path, error) }
+  };
+
+  content
+      .lines()
+      .map(|line| line.trim().to_string())
+      .filter(|line| !line.is_empty() && !line.starts_with('#'))
+      .collect()
+}
+
+/// Read a CSV file exported by a spreadsheet or a public office, one row of cells per line
+/// Cells can be quoted ("Coca-Cola, Inc."), and the separator is guessed from the header (comma, semicolon or tab)
+pub fn read_csv(path: &str) -> Vec<Vec<String>> {
+  let content = match fs::read_to_string(path) {
+    Ok(content) => content,
+    Err(error) => { panic!("Could not read CSV file '{}': {}", path, error) }
+  };
+
+  let header = content.lines().next().unwrap_or_default();
+  let separator = [',', ';', '\t']
+      .into_iter()
+      .max_by_key(|separator| header.matches(*separator).count())
+      .unwrap_or(',');
+
+  let mut rows: Vec<Vec<String>> = vec![];
+  let mut row: Vec<String> = vec![];
+  let mut cell = String::new();
+  let mut is_quoted = false;
+  let mut chars = content.chars().peekable();
+
+  while let Some(c) = chars.next() {
+    match c {
+      '"' if is_quoted && chars.peek() == Some(&'"') => {
+        cell.push('"');
+        chars.next();
+      }
+      '"' => is_quoted = !is_quoted,
+      c if c == separator && !is_quoted => row.push(std::mem::take(&mut cell).trim().to_string()),
+      '\n' if !is_quoted => {
+        row.push(std::mem::take(&mut cell).trim().to_string());
+        if row.iter().any(|cell| !cell.is_empty()) {
+          rows.push(std::mem::take(&mut row));
+        }
+        row.clear();
+      }
+      '\r' if !is_quoted => {}
+      c => cell.push(c),
+    }
+  }
+
+  row.push(cell.trim().to_string());
+  if row.iter().any(|cell| !cell.is_empty()) {
+    rows.push(row);
+  }
+
+  rows
+}
+
+/// Get the workspace of the current directory, where blocked words can be imported for a single project
+pub fn get_workspace() -> String {
+  env::current_dir()
+      .and_then(|dir| dir.canonicalize())
+      .map(|dir| dir.display().to_string())
+      .unwrap_or_default()
+}
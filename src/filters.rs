@@ -0,0 +1,468 @@
+use std::sync::OnceLock;
+use crate::models::Domain;
+use crate::similarity::KnownDomain;
+use crate::validation::to_unicode;
+
+/// Filter given with `--filter`, applied to every result table
+static INITIAL_FILTER: OnceLock<Filter> = OnceLock::new();
+
+/// Fields which can be used in filter expressions
+const FIELDS: [&str; 15] = [
+  "name", "domain", "tld", "available", "status", "wishlisted", "score", "value", "tier", "source", "reason",
+  "homograph", "blocked", "trademark", "similar",
+];
+
+/// Functions which can be used in filter expressions
+const FUNCTIONS: [&str; 4] = ["len", "contains", "starts_with", "ends_with"];
+
+/// Value of a field, a literal or a function call
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+  Bool(bool),
+  Number(f64),
+  Text(String),
+}
+
+impl Value {
+  fn is_true(&self) -> bool {
+    match self {
+      Value::Bool(value) => *value,
+      Value::Number(value) => *value != 0.0,
+      Value::Text(value) => !value.is_empty(),
+    }
+  }
+
+  fn as_text(&self) -> String {
+    match self {
+      Value::Bool(value) => value.to_string(),
+      Value::Number(value) => value.to_string(),
+      Value::Text(value) => value.to_lowercase(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+  Word(String),
+  Number(f64),
+  Text(String),
+  Symbol(&'static str),
+}
+
+#[derive(Debug, Clone)]
+enum Expression {
+  Or(Box<Expression>, Box<Expression>),
+  And(Box<Expression>, Box<Expression>),
+  Not(Box<Expression>),
+  Compare(Box<Expression>, &'static str, Box<Expression>),
+  In(Box<Expression>, Vec<Expression>),
+  Call(String, Vec<Expression>),
+  Field(String),
+  Literal(Value),
+}
+
+/// Filter
+/// Parsed filter expression (e.g. `available && len(name) <= 8 && tld in (com, io) && !contains("-")`)
+#[derive(Debug, Clone)]
+pub struct Filter {
+  pub(crate) text: String,
+  expression: Expression,
+}
+
+impl Filter {
+  /// Check if a domain matches the filter, the known domains being the owned and wishlisted domains it is compared to
+  pub fn matches(&self, domain: &Domain, known_domains: &[KnownDomain]) -> bool {
+    evaluate(&self.expression, domain, known_domains).is_true()
+  }
+}
+
+/// Parse a filter expression
+/// - fields: name, domain, tld, available, status, wishlisted, score, value, tier, source, reason, homograph, blocked,
+///   trademark, similar
+/// - functions: len(field), contains(text), starts_with(text), ends_with(text), or with a field as first argument
+/// - operators: `&&` (and), `||` (or), `!` (not), `==`, `!=`, `<`, `<=`, `>`, `>=`, `in (a, b)`
+pub fn parse_filter(text: &str) -> Result<Filter, String> {
+  let tokens = tokenize(text)?;
+  let mut parser = Parser { tokens, position: 0 };
+  let expression = parser.parse_or()?;
+
+  if let Some(token) = parser.peek() {
+    return Err(format!("Invalid filter: unexpected {}", describe(token)));
+  }
+
+  Ok(Filter { text: text.trim().to_string(), expression })
+}
+
+/// Set the filter given with `--filter`
+pub fn set_initial_filter(filter: Filter) {
+  let _ = INITIAL_FILTER.set(filter);
+}
+
+/// Get the filter given with `--filter`, if any
+pub fn get_initial_filter() -> Option<Filter> {
+  INITIAL_FILTER.get().cloned()
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+  let chars: Vec<char> = text.chars().collect();
+  let mut tokens: Vec<Token> = vec![];
+  let mut i = 0;
+
+  while i < chars.len() {
+    let c = chars[i];
+    let next = chars.get(i + 1).copied();
+
+    if c.is_whitespace() {
+      i += 1;
+    } else if c == '"' || c == '\'' {
+      let end = chars[i + 1..]
+          .iter()
+          .position(|quote| *quote == c)
+          .ok_or_else(|| String::from("Invalid filter: unterminated string"))?;
+      tokens.push(Token::Text(chars[i + 1..i + 1 + end].iter().collect()));
+      i += end + 2;
+    } else if is_word_char(c) {
+      let start = i;
+      while i < chars.len() && is_word_char(chars[i]) {
+        i += 1;
+      }
+      let word: String = chars[start..i].iter().collect();
+      match word.parse::<f64>() {
+        Ok(number) => tokens.push(Token::Number(number)),
+        Err(_) => tokens.push(Token::Word(word)),
+      }
+    } else {
+      let (symbol, length) = match (c, next) {
+        ('&', Some('&')) => ("&&", 2),
+        ('|', Some('|')) => ("||", 2),
+        ('=', Some('=')) => ("==", 2),
+        ('!', Some('=')) => ("!=", 2),
+        ('<', Some('=')) => ("<=", 2),
+        ('>', Some('=')) => (">=", 2),
+        ('=', _) => ("==", 1),
+        ('!', _) => ("!", 1),
+        ('<', _) => ("<", 1),
+        ('>', _) => (">", 1),
+        ('(', _) => ("(", 1),
+        (')', _) => (")", 1),
+        (',', _) => (",", 1),
+        _ => return Err(format!("Invalid filter: unexpected '{}'", c)),
+      };
+      i += length;
+      tokens.push(Token::Symbol(symbol));
+    }
+  }
+
+  Ok(tokens)
+}
+
+fn is_word_char(c: char) -> bool {
+  c.is_alphanumeric() || c == '_' || c == '.' || c == '-'
+}
+
+fn describe(token: &Token) -> String {
+  match token {
+    Token::Word(word) => format!("'{}'", word),
+    Token::Number(number) => format!("'{}'", number),
+    Token::Text(text) => format!("\"{}\"", text),
+    Token::Symbol(symbol) => format!("'{}'", symbol),
+  }
+}
+
+struct Parser {
+  tokens: Vec<Token>,
+  position: usize,
+}
+
+impl Parser {
+  fn peek(&self) -> Option<&Token> {
+    self.tokens.get(self.position)
+  }
+
+  fn next(&mut self) -> Option<Token> {
+    let token = self.tokens.get(self.position).cloned();
+    self.position += 1;
+    token
+  }
+
+  /// Consume a symbol or a keyword (e.g. "&&" or "and")
+  fn accept(&mut self, symbol: &str, keyword: &str) -> bool {
+    let matched = match self.peek() {
+      Some(Token::Symbol(s)) => *s == symbol,
+      Some(Token::Word(word)) => !keyword.is_empty() && word.eq_ignore_ascii_case(keyword),
+      _ => false,
+    };
+    if matched {
+      self.position += 1;
+    }
+    matched
+  }
+
+  fn expect(&mut self, symbol: &str) -> Result<(), String> {
+    if self.accept(symbol, "") {
+      Ok(())
+    } else {
+      match self.peek() {
+        Some(token) => Err(format!("Invalid filter: expected '{}' instead of {}", symbol, describe(token))),
+        None => Err(format!("Invalid filter: expected '{}'", symbol)),
+      }
+    }
+  }
+
+  fn parse_or(&mut self) -> Result<Expression, String> {
+    let mut expression = self.parse_and()?;
+    while self.accept("||", "or") {
+      expression = Expression::Or(Box::new(expression), Box::new(self.parse_and()?));
+    }
+    Ok(expression)
+  }
+
+  fn parse_and(&mut self) -> Result<Expression, String> {
+    let mut expression = self.parse_not()?;
+    while self.accept("&&", "and") {
+      expression = Expression::And(Box::new(expression), Box::new(self.parse_not()?));
+    }
+    Ok(expression)
+  }
+
+  fn parse_not(&mut self) -> Result<Expression, String> {
+    if self.accept("!", "not") {
+      return Ok(Expression::Not(Box::new(self.parse_not()?)));
+    }
+    self.parse_comparison()
+  }
+
+  fn parse_comparison(&mut self) -> Result<Expression, String> {
+    let left = self.parse_primary(false)?;
+
+    if self.accept("", "in") {
+      self.expect("(")?;
+      let mut values = vec![self.parse_primary(true)?];
+      while self.accept(",", "") {
+        values.push(self.parse_primary(true)?);
+      }
+      self.expect(")")?;
+      return Ok(Expression::In(Box::new(left), values));
+    }
+
+    for operator in ["==", "!=", "<=", ">=", "<", ">"] {
+      if self.accept(operator, "") {
+        let right = self.parse_primary(true)?;
+        return Ok(Expression::Compare(Box::new(left), operator, Box::new(right)));
+      }
+    }
+
+    Ok(left)
+  }
+
+  /// Parse a value, words which are not fields are read as text when `allow_words` is set (e.g. "com" in `tld == com`)
+  fn parse_primary(&mut self, allow_words: bool) -> Result<Expression, String> {
+    match self.next() {
+      Some(Token::Number(number)) => Ok(Expression::Literal(Value::Number(number))),
+      Some(Token::Text(text)) => Ok(Expression::Literal(Value::Text(text))),
+      Some(Token::Symbol("(")) => {
+        let expression = self.parse_or()?;
+        self.expect(")")?;
+        Ok(expression)
+      }
+      Some(Token::Word(word)) => {
+        let lowercase = word.to_lowercase();
+
+        if self.accept("(", "") {
+          if !FUNCTIONS.contains(&lowercase.as_str()) {
+            return Err(format!("Invalid filter: unknown function '{}'", word));
+          }
+
+          let mut arguments: Vec<Expression> = vec![];
+          if !self.accept(")", "") {
+            arguments.push(self.parse_primary(true)?);
+            while self.accept(",", "") {
+              arguments.push(self.parse_primary(true)?);
+            }
+            self.expect(")")?;
+          }
+          Ok(Expression::Call(lowercase, arguments))
+        } else if lowercase == "true" || lowercase == "false" {
+          Ok(Expression::Literal(Value::Bool(lowercase == "true")))
+        } else if FIELDS.contains(&lowercase.as_str()) {
+          Ok(Expression::Field(lowercase))
+        } else if allow_words {
+          Ok(Expression::Literal(Value::Text(word)))
+        } else {
+          Err(format!("Invalid filter: unknown field '{}', available fields: {}", word, FIELDS.join(", ")))
+        }
+      }
+      Some(token) => Err(format!("Invalid filter: unexpected {}", describe(&token))),
+      None => Err(String::from("Invalid filter: unexpected end of expression")),
+    }
+  }
+}
+
+fn evaluate(expression: &Expression, domain: &Domain, known_domains: &[KnownDomain]) -> Value {
+  match expression {
+    Expression::Or(left, right) => {
+      Value::Bool(evaluate(left, domain, known_domains).is_true() || evaluate(right, domain, known_domains).is_true())
+    }
+    Expression::And(left, right) => {
+      Value::Bool(evaluate(left, domain, known_domains).is_true() && evaluate(right, domain, known_domains).is_true())
+    }
+    Expression::Not(expression) => Value::Bool(!evaluate(expression, domain, known_domains).is_true()),
+    Expression::Compare(left, operator, right) => {
+      Value::Bool(compare(&evaluate(left, domain, known_domains), operator, &evaluate(right, domain, known_domains)))
+    }
+    Expression::In(left, values) => {
+      let left = evaluate(left, domain, known_domains);
+      Value::Bool(values.iter().any(|value| compare(&left, "==", &evaluate(value, domain, known_domains))))
+    }
+    Expression::Call(function, arguments) => {
+      let arguments: Vec<Value> = arguments.iter().map(|argument| evaluate(argument, domain, known_domains)).collect();
+      call(function, &arguments, domain, known_domains)
+    }
+    Expression::Field(field) => get_field(field, domain, known_domains),
+    Expression::Literal(value) => value.clone(),
+  }
+}
+
+fn compare(left: &Value, operator: &str, right: &Value) -> bool {
+  let ordering = match (left, right) {
+    (Value::Number(left), Value::Number(right)) => left.partial_cmp(right),
+    (Value::Bool(left), Value::Bool(right)) => Some(left.cmp(right)),
+    _ => Some(left.as_text().cmp(&right.as_text())),
+  };
+
+  match (operator, ordering) {
+    ("==", Some(ordering)) => ordering.is_eq(),
+    ("!=", Some(ordering)) => ordering.is_ne(),
+    ("<", Some(ordering)) => ordering.is_lt(),
+    ("<=", Some(ordering)) => ordering.is_le(),
+    (">", Some(ordering)) => ordering.is_gt(),
+    (">=", Some(ordering)) => ordering.is_ge(),
+    _ => false,
+  }
+}
+
+/// Call a function, text functions apply to the name when no field is given (e.g. `contains("-")`)
+fn call(function: &str, arguments: &[Value], domain: &Domain, known_domains: &[KnownDomain]) -> Value {
+  if function == "len" {
+    let subject = arguments.first().cloned().unwrap_or_else(|| get_field("name", domain, known_domains));
+    return Value::Number(subject.as_text().chars().count() as f64);
+  }
+
+  let (subject, argument) = match arguments {
+    [argument] => (get_field("name", domain, known_domains).as_text(), argument.as_text()),
+    [subject, argument, ..] => (subject.as_text(), argument.as_text()),
+    [] => return Value::Bool(false),
+  };
+
+  match function {
+    "contains" => Value::Bool(subject.contains(&argument)),
+    "starts_with" => Value::Bool(subject.starts_with(&argument)),
+    "ends_with" => Value::Bool(subject.ends_with(&argument)),
+    _ => Value::Bool(false),
+  }
+}
+
+fn get_field(field: &str, domain: &Domain, known_domains: &[KnownDomain]) -> Value {
+  match field {
+    "name" => Value::Text(to_unicode(&domain.domain)),
+    "domain" => Value::Text(domain.unicode_domain_name()),
+    "tld" => Value::Text(domain.tld.clone()),
+    "available" => Value::Bool(domain.status == "True"),
+    "status" => Value::Text(domain.status_label()),
+    "wishlisted" => Value::Bool(domain.selected),
+    "score" => Value::Number(domain.score() as f64),
+    "value" => Value::Number(domain.valuation().map(|valuation| valuation.points).unwrap_or(0) as f64),
+    "tier" => Value::Text(domain.valuation().map(|valuation| valuation.tier).unwrap_or_default()),
+    "source" => Value::Text(domain.source.clone()),
+    "reason" => Value::Text(domain.reason.clone()),
+    "homograph" => Value::Bool(domain.homograph_warning().is_some()),
+    "blocked" => Value::Bool(domain.blocklist_warning().is_some()),
+    "trademark" => Value::Bool(!domain.trademark_conflicts().is_empty()),
+    "similar" => Value::Bool(!domain.similar_domains(known_domains).is_empty()),
+    _ => Value::Bool(false),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn domain(label: &str, tld: &str, status: &str) -> Domain {
+    Domain {
+      domain: label.to_string(),
+      tld: tld.to_string(),
+      status: status.to_string(),
+      selected: false,
+      source: String::from("Alternative to cloud"),
+      reason: String::new(),
+      alternative: false,
+    }
+  }
+
+  fn matches(filter: &str, domain: &Domain) -> bool {
+    parse_filter(filter).unwrap().matches(domain, &[])
+  }
+
+  #[test]
+  fn and_binds_tighter_than_or() {
+    let taken = domain("cloud", "io", "False");
+    assert!(matches("tld == io || tld == com && available", &taken));
+    assert!(!matches("(tld == io || tld == com) && available", &taken));
+    assert!(matches("tld == io or tld == com and available", &taken));
+  }
+
+  #[test]
+  fn not_binds_tighter_than_and() {
+    let taken = domain("cloud", "com", "False");
+    assert!(matches("!available && tld == com", &taken));
+    assert!(!matches("!(available || tld == com)", &taken));
+    assert!(matches("NOT available AND tld == com", &taken));
+    assert!(matches("!!(tld == com)", &taken));
+  }
+
+  #[test]
+  fn comparisons_and_lists() {
+    let available = domain("cloudnet", "io", "True");
+    assert!(matches("available && len(name) <= 8", &available));
+    assert!(!matches("len(name) < 8", &available));
+    assert!(matches("tld in (com, io)", &available));
+    assert!(!matches("tld in (com, net)", &available));
+    assert!(matches("TLD == IO", &available));
+    assert!(matches("len(tld) == 2", &available));
+  }
+
+  #[test]
+  fn quoted_text() {
+    let hyphenated = domain("my-cloud", "com", "True");
+    assert!(matches("contains(\"-\")", &hyphenated));
+    assert!(matches("contains('-')", &hyphenated));
+    assert!(matches("!contains(\"&& ||\")", &hyphenated));
+    assert!(matches("source == \"Alternative to cloud\"", &hyphenated));
+    assert!(matches("ends_with(source, 'CLOUD')", &hyphenated));
+    assert!(matches("starts_with(my-)", &hyphenated));
+  }
+
+  #[test]
+  fn status_and_reason_are_separate_fields() {
+    let mut reserved = domain("nic", "com", Domain::RESERVED);
+    reserved.reason = String::from("Registry name");
+    assert!(matches("status == reserved", &reserved));
+    assert!(matches("reason == \"registry name\"", &reserved));
+    assert!(!matches("status == \"available\"", &reserved));
+    assert!(matches("status == \"available\"", &domain("cloud", "com", "True")));
+    assert!(matches("status == \"not available\"", &domain("cloud", "com", "False")));
+  }
+
+  #[test]
+  fn invalid_filters() {
+    assert_eq!(parse_filter("contains(\"-)").unwrap_err(), "Invalid filter: unterminated string");
+    assert!(parse_filter("price > 10").unwrap_err().contains("unknown field 'price'"));
+    assert!(parse_filter("size(name) > 3").unwrap_err().contains("unknown function 'size'"));
+    assert!(parse_filter("available tld").unwrap_err().contains("unexpected 'tld'"));
+    assert!(parse_filter("(available").unwrap_err().contains("expected ')'"));
+    assert!(parse_filter("tld in (com").unwrap_err().contains("expected ')'"));
+    assert!(parse_filter("available &&").unwrap_err().contains("unexpected end"));
+    assert!(parse_filter("").unwrap_err().contains("unexpected end"));
+    assert!(parse_filter("score @ 3").unwrap_err().contains("unexpected '@'"));
+  }
+}
@@ -0,0 +1,28 @@
+pub mod markov_generator;
+pub mod blend_generator;
+pub mod typo_generator;
+pub mod pattern_generator;
+pub mod hack_generator;
+pub mod expansion_generator;
+pub mod variant_generator;
+pub mod combination_generator;
+pub mod acronym_generator;
+
+/// Get a random number between 0 and 1
+pub fn random_ratio() -> f64 {
+  fakeit::misc::random::<u32>(0, u32::MAX) as f64 / u32::MAX as f64
+}
+
+/// Check if a character is a vowel (`y` included)
+pub fn is_vowel(c: char) -> bool {
+  matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
+
+/// Split a comma separated list of keywords (e.g. "cloud, data")
+pub fn parse_keywords(keywords: &str) -> Vec<String> {
+  keywords
+      .split(',')
+      .map(|keyword| keyword.trim().to_lowercase())
+      .filter(|keyword| !keyword.is_empty())
+      .collect()
+}
@@ -0,0 +1,199 @@
+use crate::generators::is_vowel;
+
+/// Words ignored when building acronyms (e.g. "Bank of America" => "ba")
+const STOP_WORDS: [&str; 7] = ["a", "an", "and", "at", "for", "of", "the"];
+
+/// Usual abbreviations of words found in company names
+const ABBREVIATIONS: [(&str, &str); 24] = [
+  ("international", "intl"),
+  ("national", "natl"),
+  ("corporation", "corp"),
+  ("company", "co"),
+  ("limited", "ltd"),
+  ("incorporated", "inc"),
+  ("management", "mgmt"),
+  ("technology", "tech"),
+  ("technologies", "tech"),
+  ("services", "svc"),
+  ("service", "svc"),
+  ("solutions", "sol"),
+  ("group", "grp"),
+  ("development", "dev"),
+  ("association", "assn"),
+  ("university", "univ"),
+  ("laboratory", "lab"),
+  ("laboratories", "labs"),
+  ("manufacturing", "mfg"),
+  ("systems", "sys"),
+  ("department", "dept"),
+  ("government", "govt"),
+  ("marketing", "mktg"),
+  ("consulting", "cnsltg"),
+];
+
+/// Generate short forms of a phrase (e.g. "International Widget Logistics"), shortest forms first
+/// - acronyms (e.g. "iwl")
+/// - initials and word (e.g. "iwlogistics", "internationalwl")
+/// - abbreviations, vowels dropped (e.g. "intlwdgtlgstcs", "intl-wdgt-lgstcs")
+/// - syllable truncations (e.g. "intwidlog")
+pub fn generate_acronyms(phrase: &str) -> Vec<String> {
+  let words: Vec<String> = phrase
+      .to_lowercase()
+      .split(|c: char| c.is_whitespace() || c == '-' || c == '_' || c == ',' || c == '.')
+      .map(|word| word.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>())
+      .filter(|word| !word.is_empty())
+      .collect();
+  let main_words: Vec<String> = words
+      .iter()
+      .filter(|word| !STOP_WORDS.contains(&word.as_str()))
+      .cloned()
+      .collect();
+
+  if main_words.is_empty() {
+    return vec![];
+  }
+
+  let mut forms: Vec<String> = vec![];
+
+  forms.push(get_initials(&main_words));
+  forms.push(get_initials(&words));
+
+  if main_words.len() > 1 {
+    let (last, others) = main_words.split_last().unwrap();
+    let (first, rest) = main_words.split_first().unwrap();
+    forms.push(format!("{}{}", get_initials(others), last));
+    forms.push(format!("{}-{}", get_initials(others), last));
+    forms.push(format!("{}{}", first, get_initials(rest)));
+  }
+
+  let abbreviations: Vec<String> = main_words.iter().map(|word| abbreviate(word)).collect();
+  forms.push(abbreviations.join(""));
+  forms.push(abbreviations.join("-"));
+
+  if abbreviations.len() > 2 {
+    let first_two = &abbreviations[..2];
+    forms.push(first_two.join(""));
+    forms.push(first_two.join("-"));
+  }
+
+  let truncations: Vec<String> = main_words.iter().map(|word| truncate_syllable(word)).collect();
+  forms.push(truncations.join(""));
+  forms.push(truncations.join("-"));
+
+  let mut result: Vec<String> = vec![];
+  for form in forms {
+    if form.len() >= 2 && !result.contains(&form) {
+      result.push(form);
+    }
+  }
+
+  result.sort_by_key(|form| form.len());
+  result
+}
+
+/// First letter of each word
+fn get_initials(words: &[String]) -> String {
+  words.iter().filter_map(|word| word.chars().next()).collect()
+}
+
+/// Usual abbreviation of a word, or the word without its vowels (first letter kept)
+fn abbreviate(word: &str) -> String {
+  if let Some((_, abbreviation)) = ABBREVIATIONS.iter().find(|(full, _)| *full == word) {
+    return abbreviation.to_string();
+  }
+
+  if word.len() <= 3 {
+    return word.to_string();
+  }
+
+  word
+      .chars()
+      .enumerate()
+      .filter(|(i, c)| *i == 0 || !is_vowel(*c))
+      .map(|(_, c)| c)
+      .collect()
+}
+
+/// First syllable of a word, with at least 3 letters when possible (e.g. "international" => "int")
+fn truncate_syllable(word: &str) -> String {
+  let chars: Vec<char> = word.chars().collect();
+  let mut end = 0;
+
+  while end < chars.len() && !is_vowel(chars[end]) {
+    end += 1;
+  }
+  while end < chars.len() && is_vowel(chars[end]) {
+    end += 1;
+  }
+  if end < chars.len() {
+    end += 1;
+  }
+  if end < 3 && end < chars.len() && !is_vowel(chars[end]) {
+    end += 1;
+  }
+
+  chars[..end].iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn every_kind_of_form() {
+    let forms = generate_acronyms("International Widget Logistics");
+    for form in ["iwl", "iwlogistics", "iw-logistics", "internationalwl", "intlwdgtlgstcs", "intl-wdgt-lgstcs",
+      "intlwdgt", "intwidlog", "int-wid-log"] {
+      assert!(forms.contains(&String::from(form)), "missing {}", form);
+    }
+  }
+
+  #[test]
+  fn shortest_forms_first() {
+    let forms = generate_acronyms("International Widget Logistics");
+    assert_eq!(forms[0], "iwl");
+    assert!(forms.windows(2).all(|pair| pair[0].len() <= pair[1].len()));
+  }
+
+  #[test]
+  fn stop_words() {
+    let forms = generate_acronyms("Bank of America");
+    assert_eq!(forms[0], "ba");
+    assert!(forms.contains(&String::from("boa")));
+    assert!(forms.contains(&String::from("bamerica")));
+    assert_eq!(generate_acronyms("Procter & Gamble")[0], "pg");
+  }
+
+  #[test]
+  fn only_valid_label_characters() {
+    for phrase in ["AT&T", "Procter & Gamble", "Johnson's Co.", "R_D, Labs"] {
+      let forms = generate_acronyms(phrase);
+      assert!(!forms.is_empty(), "no form for {}", phrase);
+      assert!(forms.iter().all(|form| form.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')), "{:?}", forms);
+    }
+    assert_eq!(generate_acronyms("AT&T")[0], "att");
+  }
+
+  #[test]
+  fn no_form_without_main_words() {
+    assert!(generate_acronyms("").is_empty());
+    assert!(generate_acronyms("the of").is_empty());
+    assert!(generate_acronyms(" - , ").is_empty());
+  }
+
+  #[test]
+  fn single_word() {
+    let forms = generate_acronyms("Apple");
+    assert_eq!(forms, ["app", "appl"]);
+  }
+
+  #[test]
+  fn abbreviations_and_syllables() {
+    assert_eq!(abbreviate("international"), "intl");
+    assert_eq!(abbreviate("widget"), "wdgt");
+    assert_eq!(abbreviate("app"), "app");
+    assert_eq!(truncate_syllable("international"), "int");
+    assert_eq!(truncate_syllable("strength"), "stren");
+    assert_eq!(truncate_syllable("a"), "a");
+  }
+}
@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+use crate::generators::is_vowel;
+use crate::scoring::pronounceability;
+
+/// Minimum length of a blended name
+const MIN_BLEND_LENGTH: usize = 3;
+
+/// Generate blends for every pair of keywords, ranked by pronounceability
+/// Returns at most `limit` names
+pub fn generate_blends(left: &[String], right: &[String], limit: usize) -> Vec<String> {
+  let mut seen: HashSet<String> = HashSet::new();
+  let mut blends: Vec<String> = vec![];
+
+  for first in left {
+    for second in right {
+      for blend in blend_words(first, second) {
+        if seen.insert(blend.clone()) {
+          blends.push(blend);
+        }
+      }
+    }
+  }
+
+  blends.sort_by(|a, b| {
+    pronounceability(b)
+        .total_cmp(&pronounceability(a))
+        .then(a.len().cmp(&b.len()))
+        .then(a.cmp(b))
+  });
+  blends.truncate(limit);
+  blends
+}
+
+/// Generate all blends of two words
+/// - concatenation (e.g. "data" + "nest" => "datanest")
+/// - letter overlap (e.g. "cloud" + "udon" => "cloudon")
+/// - syllable blend (e.g. "cloud" + "forge" => "clorge")
+fn blend_words(first: &str, second: &str) -> Vec<String> {
+  let first = sanitize(first);
+  let second = sanitize(second);
+  let mut blends: Vec<String> = vec![];
+
+  if first.is_empty() || second.is_empty() {
+    return blends;
+  }
+
+  blends.push(format!("{}{}", first, second));
+
+  for size in 1..first.len().min(second.len()) {
+    if first.ends_with(&second[..size]) {
+      blends.push(format!("{}{}", first, &second[size..]));
+    }
+  }
+
+  for prefix_end in get_cut_points(&first) {
+    for suffix_start in get_cut_points(&second) {
+      let blend = format!("{}{}", &first[..prefix_end], &second[suffix_start..]);
+      blends.push(blend);
+    }
+  }
+
+  blends
+      .into_iter()
+      .filter(|blend| blend.len() >= MIN_BLEND_LENGTH && blend.chars().any(is_vowel))
+      .filter(|blend| *blend != first && *blend != second)
+      .collect()
+}
+
+/// Get the positions where a word can be cut, at the start and end of each vowel group
+fn get_cut_points(word: &str) -> Vec<usize> {
+  let chars: Vec<char> = word.chars().collect();
+  let mut points: Vec<usize> = vec![];
+
+  for i in 1..chars.len() {
+    if is_vowel(chars[i]) != is_vowel(chars[i - 1]) {
+      points.push(i);
+    }
+  }
+
+  points
+}
+
+/// Keep only lowercase ASCII letters and digits
+fn sanitize(word: &str) -> String {
+  word
+      .to_lowercase()
+      .chars()
+      .filter(|c| c.is_ascii_alphanumeric())
+      .collect()
+}
@@ -0,0 +1,66 @@
+use std::collections::BTreeSet;
+use crate::generators::random_ratio;
+
+/// WordCombinations
+/// Cartesian product of several word lists, joined with one of the separators
+/// The same separator is used between every word of a combination
+pub struct WordCombinations {
+  lists: Vec<Vec<String>>,
+  separators: Vec<String>,
+}
+
+impl WordCombinations {
+  pub fn new(lists: Vec<Vec<String>>, separators: Vec<String>) -> Self {
+    let separators = if separators.is_empty() { vec![String::new()] } else { separators };
+    Self { lists, separators }
+  }
+
+  /// Total number of combinations
+  pub fn count(&self) -> u128 {
+    if self.lists.is_empty() {
+      return 0;
+    }
+
+    self.lists
+        .iter()
+        .fold(self.separators.len() as u128, |total, list| total.saturating_mul(list.len() as u128))
+  }
+
+  /// Get the combination at a given index, the last word changing fastest
+  pub fn combination_at(&self, index: u128) -> String {
+    let mut remainder = index;
+    let mut words: Vec<&str> = vec![""; self.lists.len()];
+
+    for (i, list) in self.lists.iter().enumerate().rev() {
+      let size = list.len() as u128;
+      words[i] = &list[(remainder % size) as usize];
+      remainder /= size;
+    }
+
+    let separator = &self.separators[(remainder % self.separators.len() as u128) as usize];
+    words.join(separator)
+  }
+
+  /// Get the first combinations, at most `limit`
+  pub fn first(&self, limit: usize) -> Vec<String> {
+    let end = self.count().min(limit as u128);
+    (0..end).map(|index| self.combination_at(index)).collect()
+  }
+
+  /// Get a random sample of combinations, at most `limit`, in index order
+  pub fn sample(&self, limit: usize) -> Vec<String> {
+    let count = self.count();
+
+    if count <= limit as u128 {
+      return self.first(limit);
+    }
+
+    let mut indexes: BTreeSet<u128> = BTreeSet::new();
+    while indexes.len() < limit {
+      let index = ((random_ratio() * count as f64) as u128).min(count - 1);
+      indexes.insert(index);
+    }
+
+    indexes.into_iter().map(|index| self.combination_at(index)).collect()
+  }
+}
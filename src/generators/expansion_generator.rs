@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+
+/// Expand words with their related words, up to `depth` levels and `limit` candidates
+/// Returns (candidate, source) pairs where the source is the path from the original word (e.g. "cloud > sky")
+/// Original words come first with themselves as source
+pub fn expand_words<F>(words: &[String], depth: usize, limit: usize, find_related_words: F) -> Vec<(String, String)>
+where
+    F: Fn(&str) -> Vec<String>,
+{
+  let mut seen: HashSet<String> = HashSet::new();
+  let mut candidates: Vec<(String, String)> = vec![];
+  let mut current_level: Vec<(String, String)> = vec![];
+
+  for word in words {
+    if seen.insert(word.clone()) {
+      candidates.push((compact(word), word.clone()));
+      current_level.push((word.clone(), word.clone()));
+    }
+  }
+
+  for _ in 0..depth {
+    let mut next_level: Vec<(String, String)> = vec![];
+
+    for (word, path) in current_level.iter() {
+      let mut related_words = find_related_words(word);
+      related_words.sort();
+
+      for related in related_words {
+        if seen.insert(related.clone()) {
+          candidates.push((compact(&related), path.clone()));
+          next_level.push((related.clone(), format!("{} > {}", path, related)));
+        }
+      }
+    }
+
+    current_level = next_level;
+  }
+
+  candidates.retain(|(candidate, _)| !candidate.is_empty());
+  candidates.truncate(limit);
+  candidates
+}
+
+/// Turn a word or phrase into a domain label (e.g. "silver lining" => "silverlining")
+fn compact(word: &str) -> String {
+  word
+      .to_lowercase()
+      .chars()
+      .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+      .collect()
+}
@@ -0,0 +1,78 @@
+/// Minimum length of the registrable label of a multi-label hack (e.g. "icio" in "del.icio.us")
+const MIN_MULTI_LABEL_LENGTH: usize = 3;
+
+/// DomainHack
+/// Split of a word where the end of the word is a top level domain (e.g. "delicio.us")
+#[derive(Debug)]
+pub struct DomainHack {
+  pub(crate) subdomain: String,
+  pub(crate) label: String,
+  pub(crate) tld: String,
+}
+
+impl DomainHack {
+  // Complete hack, subdomain included (e.g. "del.icio.us")
+  pub(crate) fn hack_name(&self) -> String {
+    if self.subdomain.is_empty() {
+      format!("{}.{}", self.label, self.tld)
+    } else {
+      format!("{}.{}.{}", self.subdomain, self.label, self.tld)
+    }
+  }
+}
+
+/// Find the domain hacks of a word using a list of top level domains
+/// Single label hacks (e.g. "delicio.us") come first, then multi-label hacks (e.g. "del.icio.us")
+/// Longer top level domains are listed first as they give shorter registrable labels
+pub fn find_domain_hacks(word: &str, tlds: &[String]) -> Vec<DomainHack> {
+  let word: String = word
+      .to_lowercase()
+      .chars()
+      .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+      .collect();
+
+  let mut tlds: Vec<(String, String)> = tlds
+      .iter()
+      .map(|tld| (tld.to_lowercase(), tld.to_lowercase().replace('.', "")))
+      .filter(|(_, compact)| !compact.is_empty())
+      .collect();
+  tlds.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then(a.0.cmp(&b.0)));
+  tlds.dedup();
+
+  let mut single_label_hacks: Vec<DomainHack> = vec![];
+  let mut multi_label_hacks: Vec<DomainHack> = vec![];
+
+  for (tld, compact) in tlds.iter() {
+    if word.len() <= compact.len() || !word.ends_with(compact.as_str()) {
+      continue;
+    }
+
+    let base = &word[..word.len() - compact.len()];
+
+    for start in 0..base.len() {
+      let hack = DomainHack {
+        subdomain: base[..start].to_string(),
+        label: base[start..].to_string(),
+        tld: tld.clone(),
+      };
+
+      if !is_valid_label(&hack.label) || (!hack.subdomain.is_empty() && !is_valid_label(&hack.subdomain)) {
+        continue;
+      }
+
+      if hack.subdomain.is_empty() {
+        single_label_hacks.push(hack);
+      } else if hack.label.len() >= MIN_MULTI_LABEL_LENGTH {
+        multi_label_hacks.push(hack);
+      }
+    }
+  }
+
+  single_label_hacks.extend(multi_label_hacks);
+  single_label_hacks
+}
+
+/// A label cannot be empty, nor start or end with a hyphen
+fn is_valid_label(label: &str) -> bool {
+  !label.is_empty() && !label.starts_with('-') && !label.ends_with('-')
+}
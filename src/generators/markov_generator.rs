@@ -0,0 +1,158 @@
+use std::collections::{BTreeMap, HashSet};
+use crate::generators::random_ratio;
+
+/// Characters used to pad the beginning and the end of training words
+const START_CHAR: char = '^';
+const END_CHAR: char = '$';
+
+/// Maximum number of attempts per requested name before giving up
+const MAX_ATTEMPTS_PER_NAME: usize = 200;
+
+/// Options used to generate names with a Markov chain
+pub struct MarkovOptions {
+  pub(crate) count: usize,
+  pub(crate) order: usize,
+  pub(crate) min_length: usize,
+  pub(crate) max_length: usize,
+  pub(crate) temperature: f64,
+}
+
+/// MarkovModel
+/// Character-level Markov chain trained on a list of words
+pub struct MarkovModel {
+  order: usize,
+  transitions: BTreeMap<String, BTreeMap<char, u32>>,
+  training_words: HashSet<String>,
+}
+
+impl MarkovModel {
+  /// Train a new model on a list of words
+  pub fn train(words: &[String], order: usize) -> Self {
+    let order = order.max(1);
+    let mut transitions: BTreeMap<String, BTreeMap<char, u32>> = BTreeMap::new();
+    let mut training_words: HashSet<String> = HashSet::new();
+
+    for word in words {
+      let word: String = word
+          .to_lowercase()
+          .chars()
+          .filter(|c| c.is_ascii_lowercase())
+          .collect();
+
+      if word.is_empty() {
+        continue;
+      }
+
+      let padded: Vec<char> = std::iter::repeat_n(START_CHAR, order)
+          .chain(word.chars())
+          .chain(std::iter::once(END_CHAR))
+          .collect();
+
+      for window in padded.windows(order + 1) {
+        let state: String = window[..order].iter().collect();
+        let next = window[order];
+        *transitions.entry(state).or_default().entry(next).or_insert(0) += 1;
+      }
+
+      training_words.insert(word);
+    }
+
+    Self { order, transitions, training_words }
+  }
+
+  /// Generate a list of unique names which are not part of the training words
+  pub fn generate(&self, options: &MarkovOptions) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+    let max_attempts = options.count * MAX_ATTEMPTS_PER_NAME;
+    let mut attempts = 0;
+
+    while names.len() < options.count && attempts < max_attempts {
+      attempts += 1;
+
+      if let Some(name) = self.generate_name(options.max_length, options.temperature) {
+        let is_valid_length = name.len() >= options.min_length && name.len() <= options.max_length;
+
+        if is_valid_length && !self.training_words.contains(&name) && !names.contains(&name) {
+          names.push(name);
+        }
+      }
+    }
+
+    names
+  }
+
+  /// Walk the chain from the start state until the end character is reached
+  fn generate_name(&self, max_length: usize, temperature: f64) -> Option<String> {
+    let mut state: String = std::iter::repeat_n(START_CHAR, self.order).collect();
+    let mut name = String::new();
+
+    loop {
+      let next = self.next_char(&state, temperature)?;
+
+      if next == END_CHAR {
+        return Some(name);
+      }
+
+      name.push(next);
+
+      if name.len() > max_length {
+        return None;
+      }
+
+      state.remove(0);
+      state.push(next);
+    }
+  }
+
+  /// Pick the next character according to the transition weights
+  /// A temperature above 1 flattens the distribution, below 1 sharpens it
+  fn next_char(&self, state: &str, temperature: f64) -> Option<char> {
+    let candidates = self.transitions.get(state)?;
+    let temperature = temperature.max(0.01);
+
+    let weights: Vec<(char, f64)> = candidates
+        .iter()
+        .map(|(c, count)| (*c, (*count as f64).powf(1.0 / temperature)))
+        .collect();
+
+    let total: f64 = weights.iter().map(|(_, weight)| weight).sum();
+    let mut threshold = random_ratio() * total;
+
+    for (c, weight) in weights.iter() {
+      if threshold < *weight {
+        return Some(*c);
+      }
+      threshold -= weight;
+    }
+
+    weights.last().map(|(c, _)| *c)
+  }
+}
+
+/// Get the bundled corpus used when no word list is provided
+pub fn get_default_corpus() -> Vec<String> {
+  DEFAULT_CORPUS
+      .split_whitespace()
+      .map(String::from)
+      .collect()
+}
+
+pub const DEFAULT_CORPUS: &str = r#"
+  acorn adora alba alora amara amber anima apex aria arlo aroma astra atlas aura aurora avena
+  axel azura balta bambo banjo belia bella bento bera blossa bolo bonita bora bravo brio
+  cadence calla calypso camelia canto capri cara carina casa cello cielo cinder cira clara
+  clio coda cola cora corsa cosmo crema cuba dalia dara delta dina diva dola dora duma
+  echo eden elara elba elio ella elma elora elva ember enna enzo era estra etna evora
+  fable falco fara fauna felix fiora flora forma fresca frida gala gamma gemma gia gila
+  giro gloria goya halo hana hera hola hydra ibis ida ilia ilo indigo iona iris isla
+  jade java jolla juno kala kamo kara kiva koa kora kuma lago lana lava leda lena leo
+  levi lima lina lira livia loma lora luca lumen luna lupa luxe lyra magna maia malta
+  mana mara marina maya melo mesa mila mina mira moda mona mora nava nera nexa nila
+  nima nina noma nora nova nula oasis olia olivia omega onda opal ora orbita orca orion
+  osa palo pampa pana pera perla piana pico pila pina plaza polo prima puma quanta
+  quinta rana rava rema rena rica riva roma rosa rubia rumba sable saga sala salsa
+  samba santa sara seda selva sena sera sierra siena silva sola soma sona sora stella
+  suma tala tamba tara tela terra tesla tiara tika tilda tora tova tulsa ula ultra
+  umbra una vela vena vera verda vesta via vida villa viola vita viva volta xena yara
+  yola yuma zala zara zebra zela zena zeta zinna zola zora zulu
+"#;
@@ -0,0 +1,179 @@
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+const VOWELS: &str = "aeiou";
+const DIGITS: &str = "0123456789";
+
+/// LetterPattern
+/// Sequence of allowed characters for each position of a label
+/// Syntax :
+/// - `C` any consonant, `V` any vowel (a, e, i, o, u), `L` or `?` any letter, `D` any digit
+/// - `[aeiou]` or `[a-f0-9]` any character of the set
+/// - lowercase letters, digits and `-` are literals
+/// - whitespace is ignored (e.g. `C V C V`)
+#[derive(Debug)]
+pub struct LetterPattern {
+  positions: Vec<Vec<char>>,
+}
+
+impl LetterPattern {
+  /// Parse a pattern such as `CVCV`, `??x?` or `[aeiou]zz[a-z]`
+  pub fn parse(pattern: &str) -> Result<Self, String> {
+    let mut positions: Vec<Vec<char>> = vec![];
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+      let position: Vec<char> = match c {
+        ' ' | '\t' => continue,
+        'C' => LETTERS.chars().filter(|l| !VOWELS.contains(*l)).collect(),
+        'V' => VOWELS.chars().collect(),
+        'L' | '?' => LETTERS.chars().collect(),
+        'D' => DIGITS.chars().collect(),
+        '[' => {
+          let mut set = String::new();
+          loop {
+            match chars.next() {
+              Some(']') => break,
+              Some(c) => set.push(c),
+              None => return Err(format!("Missing closing bracket in pattern '{}'", pattern)),
+            }
+          }
+          parse_set(&set)?
+        }
+        c if c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' => vec![c],
+        c => return Err(format!("Unexpected character '{}' in pattern '{}'", c, pattern)),
+      };
+
+      if position.is_empty() {
+        return Err(format!("Empty character set in pattern '{}'", pattern));
+      }
+
+      positions.push(position);
+    }
+
+    if positions.is_empty() {
+      return Err(String::from("Pattern is empty"));
+    }
+
+    Ok(Self { positions })
+  }
+
+  /// Total number of labels matching the pattern
+  pub fn count(&self) -> u128 {
+    self.positions
+        .iter()
+        .fold(1u128, |total, position| total.saturating_mul(position.len() as u128))
+  }
+
+  /// Get the label at a given index, the last position changing fastest
+  pub fn label_at(&self, index: u128) -> String {
+    let mut remainder = index;
+    let mut label: Vec<char> = vec![' '; self.positions.len()];
+
+    for (i, position) in self.positions.iter().enumerate().rev() {
+      let size = position.len() as u128;
+      label[i] = position[(remainder % size) as usize];
+      remainder /= size;
+    }
+
+    label.into_iter().collect()
+  }
+
+  /// Enumerate labels matching the pattern, starting at `start` and returning at most `limit` labels
+  /// Labels starting or ending with a hyphen are skipped
+  pub fn enumerate(&self, start: u128, limit: usize) -> Vec<String> {
+    let end = self.count().min(start.saturating_add(limit as u128));
+
+    (start..end)
+        .map(|index| self.label_at(index))
+        .filter(|label| !label.starts_with('-') && !label.ends_with('-'))
+        .collect()
+  }
+}
+
+/// Parse the content of a character set (e.g. `aeiou` or `a-z0-9`)
+fn parse_set(set: &str) -> Result<Vec<char>, String> {
+  let chars: Vec<char> = set.chars().collect();
+  let mut result: Vec<char> = vec![];
+  let mut i = 0;
+
+  while i < chars.len() {
+    if i + 2 < chars.len() && chars[i + 1] == '-' {
+      let (from, to) = (chars[i], chars[i + 2]);
+      if from > to {
+        return Err(format!("Invalid range '{}-{}'", from, to));
+      }
+      result.extend(from..=to);
+      i += 3;
+    } else {
+      result.push(chars[i]);
+      i += 1;
+    }
+  }
+
+  if let Some(c) = result.iter().find(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || **c == '-')) {
+    return Err(format!("Unexpected character '{}' in set '[{}]'", c, set));
+  }
+
+  result.sort();
+  result.dedup();
+  Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn character_sets() {
+    let pattern = LetterPattern::parse("[aeiou]zz[a-c]").unwrap();
+    assert_eq!(pattern.count(), 15);
+    assert_eq!(pattern.enumerate(0, 4), vec!["azza", "azzb", "azzc", "ezza"]);
+    assert_eq!(pattern.label_at(14), "uzzc");
+
+    // Ranges and single characters are merged without duplicates
+    assert_eq!(LetterPattern::parse("[a-cb0-1]").unwrap().count(), 5);
+  }
+
+  #[test]
+  fn any_letter_and_digit() {
+    let pattern = LetterPattern::parse("??x?").unwrap();
+    assert_eq!(pattern.count(), 26 * 26 * 26);
+    assert_eq!(pattern.label_at(0), "aaxa");
+    assert_eq!(pattern.label_at(pattern.count() - 1), "zzxz");
+
+    let pattern = LetterPattern::parse("app D D").unwrap();
+    assert_eq!(pattern.count(), 100);
+    assert_eq!(pattern.enumerate(98, 10), vec!["app98", "app99"]);
+  }
+
+  #[test]
+  fn consonants_and_vowels() {
+    let pattern = LetterPattern::parse("CV").unwrap();
+    assert_eq!(pattern.count(), 21 * 5);
+    assert_eq!(pattern.label_at(0), "ba");
+    assert_eq!(LetterPattern::parse("L").unwrap().count(), 26);
+  }
+
+  #[test]
+  fn hyphens_at_the_ends_are_skipped() {
+    let pattern = LetterPattern::parse("[-a]b[a-]").unwrap();
+    assert_eq!(pattern.enumerate(0, 10), vec!["aba"]);
+  }
+
+  #[test]
+  fn enumerate_past_the_end() {
+    let pattern = LetterPattern::parse("[ab]").unwrap();
+    assert!(pattern.enumerate(2, 10).is_empty());
+    assert_eq!(pattern.enumerate(0, 0), Vec::<String>::new());
+  }
+
+  #[test]
+  fn invalid_patterns() {
+    assert_eq!(LetterPattern::parse("").unwrap_err(), "Pattern is empty");
+    assert_eq!(LetterPattern::parse("  ").unwrap_err(), "Pattern is empty");
+    assert!(LetterPattern::parse("[abc").unwrap_err().contains("Missing closing bracket"));
+    assert!(LetterPattern::parse("[]x").unwrap_err().contains("Empty character set"));
+    assert!(LetterPattern::parse("[z-a]").unwrap_err().contains("Invalid range 'z-a'"));
+    assert!(LetterPattern::parse("[A-C]").unwrap_err().contains("Unexpected character 'A'"));
+    assert!(LetterPattern::parse("ab*").unwrap_err().contains("Unexpected character '*'"));
+  }
+}
@@ -0,0 +1,210 @@
+use std::collections::HashSet;
+
+/// Rows of a QWERTY keyboard, used to find keyboard-adjacent characters
+const KEYBOARD_ROWS: [&str; 4] = ["1234567890", "qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// Characters or sequences which look alike
+const HOMOGLYPHS: [(&str, &str); 10] = [
+  ("o", "0"),
+  ("l", "1"),
+  ("i", "1"),
+  ("l", "i"),
+  ("m", "rn"),
+  ("w", "vv"),
+  ("d", "cl"),
+  ("e", "3"),
+  ("s", "5"),
+  ("g", "q"),
+];
+
+/// Generate common typosquats of a label, taking one typo of each kind in turn so that a limited number of typos
+/// still covers every kind, most likely typos of each kind first
+/// - omission (e.g. "exmple")
+/// - transposition (e.g. "exmaple")
+/// - doubled letter (e.g. "exxample")
+/// - keyboard-adjacent substitution (e.g. "wxample")
+/// - homoglyph (e.g. "examp1e")
+/// - hyphen insertion (e.g. "exam-ple")
+/// - missing dot (e.g. "wwwexample")
+pub fn generate_typos(name: &str) -> Vec<String> {
+  let name: String = name
+      .to_lowercase()
+      .chars()
+      .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+      .collect();
+  let chars: Vec<char> = name.chars().collect();
+
+  let kinds: Vec<Vec<String>> = vec![
+    get_omissions(&chars),
+    get_transpositions(&chars),
+    get_doubled_letters(&chars),
+    get_adjacent_substitutions(&chars),
+    get_homoglyphs(&name),
+    get_hyphen_insertions(&chars),
+    vec![format!("www{}", name)],
+  ];
+
+  let mut seen: HashSet<String> = HashSet::new();
+  interleave(kinds)
+      .into_iter()
+      .filter(|typo| !typo.is_empty() && *typo != name)
+      .filter(|typo| !typo.starts_with('-') && !typo.ends_with('-'))
+      .filter(|typo| seen.insert(typo.clone()))
+      .collect()
+}
+
+/// Take the first item of each list, then the second one... (e.g. [[a, b], [c]] => [a, c, b])
+fn interleave(lists: Vec<Vec<String>>) -> Vec<String> {
+  let length = lists.iter().map(|list| list.len()).max().unwrap_or(0);
+  let mut iterators: Vec<_> = lists.into_iter().map(|list| list.into_iter()).collect();
+
+  (0..length)
+      .flat_map(|_| iterators.iter_mut().filter_map(|iterator| iterator.next()).collect::<Vec<String>>())
+      .collect()
+}
+
+fn get_omissions(chars: &[char]) -> Vec<String> {
+  (0..chars.len())
+      .map(|i| chars.iter().enumerate().filter(|(j, _)| *j != i).map(|(_, c)| c).collect())
+      .collect()
+}
+
+fn get_transpositions(chars: &[char]) -> Vec<String> {
+  (0..chars.len().saturating_sub(1))
+      .map(|i| {
+        let mut typo = chars.to_vec();
+        typo.swap(i, i + 1);
+        typo.into_iter().collect()
+      })
+      .collect()
+}
+
+fn get_doubled_letters(chars: &[char]) -> Vec<String> {
+  (0..chars.len())
+      .map(|i| {
+        let mut typo = chars.to_vec();
+        typo.insert(i, chars[i]);
+        typo.into_iter().collect()
+      })
+      .collect()
+}
+
+fn get_adjacent_substitutions(chars: &[char]) -> Vec<String> {
+  let mut typos: Vec<String> = vec![];
+
+  for (i, c) in chars.iter().enumerate() {
+    for adjacent in get_adjacent_keys(*c) {
+      let mut typo = chars.to_vec();
+      typo[i] = adjacent;
+      typos.push(typo.into_iter().collect());
+    }
+  }
+
+  typos
+}
+
+fn get_homoglyphs(name: &str) -> Vec<String> {
+  let mut typos: Vec<String> = vec![];
+
+  for (original, lookalike) in HOMOGLYPHS.iter() {
+    for (from, to) in [(original, lookalike), (lookalike, original)] {
+      for (index, _) in name.match_indices(*from) {
+        typos.push(format!("{}{}{}", &name[..index], to, &name[index + from.len()..]));
+      }
+    }
+  }
+
+  typos
+}
+
+fn get_hyphen_insertions(chars: &[char]) -> Vec<String> {
+  (1..chars.len())
+      .filter(|i| chars[*i] != '-' && chars[*i - 1] != '-')
+      .map(|i| {
+        let mut typo = chars.to_vec();
+        typo.insert(i, '-');
+        typo.into_iter().collect()
+      })
+      .collect()
+}
+
+/// Get the keys next to a character on the same row of a QWERTY keyboard
+fn get_adjacent_keys(c: char) -> Vec<char> {
+  for row in KEYBOARD_ROWS.iter() {
+    let keys: Vec<char> = row.chars().collect();
+
+    if let Some(position) = keys.iter().position(|key| *key == c) {
+      let mut adjacent: Vec<char> = vec![];
+      if position > 0 {
+        adjacent.push(keys[position - 1]);
+      }
+      if position + 1 < keys.len() {
+        adjacent.push(keys[position + 1]);
+      }
+      return adjacent;
+    }
+  }
+
+  vec![]
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn every_kind_of_typo() {
+    let typos = generate_typos("example");
+    for typo in ["exmple", "exmaple", "exxample", "wxample", "examp1e", "exam-ple", "wwwexample"] {
+      assert!(typos.contains(&String::from(typo)), "missing {}", typo);
+    }
+  }
+
+  #[test]
+  fn kinds_are_interleaved() {
+    let typos = generate_typos("example");
+    assert_eq!(&typos[..4], ["xample", "xeample", "eexample", "wxample"]);
+    assert_eq!(interleave(vec![vec![String::from("a"), String::from("b")], vec![String::from("c")]]), ["a", "c", "b"]);
+  }
+
+  #[test]
+  fn no_duplicates_original_or_invalid_hyphens() {
+    let typos = generate_typos("my-app");
+    let unique: HashSet<&String> = typos.iter().collect();
+    assert_eq!(unique.len(), typos.len());
+    assert!(!typos.contains(&String::from("my-app")));
+    assert!(typos.iter().all(|typo| !typo.starts_with('-') && !typo.ends_with('-')));
+    assert!(typos.contains(&String::from("myapp")));
+  }
+
+  #[test]
+  fn homoglyphs_in_both_directions() {
+    let typos = generate_typos("modem");
+    assert!(typos.contains(&String::from("m0dem")));
+    assert!(typos.contains(&String::from("rnodem")));
+    assert!(typos.contains(&String::from("moclem")));
+    assert!(generate_typos("g00d").contains(&String::from("go0d")));
+  }
+
+  #[test]
+  fn input_is_normalized() {
+    assert_eq!(generate_typos("Ex.ample"), generate_typos("example"));
+  }
+
+  #[test]
+  fn single_character() {
+    let typos = generate_typos("a");
+    assert!(!typos.contains(&String::new()));
+    assert!(typos.contains(&String::from("aa")));
+    assert!(typos.contains(&String::from("s")));
+    assert!(typos.contains(&String::from("wwwa")));
+  }
+
+  #[test]
+  fn adjacent_keys_on_the_same_row() {
+    assert_eq!(get_adjacent_keys('q'), ['w']);
+    assert_eq!(get_adjacent_keys('g'), ['f', 'h']);
+    assert_eq!(get_adjacent_keys('0'), ['9']);
+    assert!(get_adjacent_keys('-').is_empty());
+  }
+}
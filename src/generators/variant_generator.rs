@@ -0,0 +1,253 @@
+use std::cmp::Reverse;
+use crate::scoring::{dictionary_coverage, score};
+
+/// Common prefixes and suffixes used by brands when the plain name is taken
+const PREFIXES: [&str; 5] = ["get", "try", "my", "the", "go"];
+const SUFFIXES: [&str; 5] = ["app", "hq", "hub", "ly", "io"];
+/// Numbers appended by brands when the plain name is taken (e.g. "example24")
+const NUMBER_SUFFIXES: [&str; 3] = ["1", "24", "360"];
+
+/// Words commonly replaced by numbers (e.g. "to" => "2")
+const NUMERALS: [(&str, &str); 8] = [
+  ("four", "4"),
+  ("for", "4"),
+  ("two", "2"),
+  ("too", "2"),
+  ("to", "2"),
+  ("eight", "8"),
+  ("ate", "8"),
+  ("one", "1"),
+];
+
+/// Above this number of words, only fully joined and fully hyphenated variants are generated
+const MAX_WORDS_FOR_PARTIAL_HYPHENATION: usize = 6;
+
+/// Generate the variants of a multi-word query (e.g. "my cool app")
+/// - joined and hyphenated forms (e.g. "mycoolapp", "my-cool-app", "mycool-app")
+/// - plural or singular form of the last word (e.g. "mycoolapps")
+/// - number substitution of whole words (e.g. "go to market" => "go2market")
+pub fn generate_variants(query: &str) -> Vec<String> {
+  let words: Vec<String> = query
+      .to_lowercase()
+      .split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+      .map(|word| word.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>())
+      .filter(|word| !word.is_empty())
+      .collect();
+
+  if words.is_empty() {
+    return vec![];
+  }
+
+  let mut word_sets: Vec<Vec<String>> = vec![words.clone()];
+
+  if let Some(last) = words.last() {
+    for form in get_plural_forms(last) {
+      let mut plural_words = words.clone();
+      plural_words.pop();
+      plural_words.push(form);
+      word_sets.push(plural_words);
+    }
+  }
+
+  let numeral_words: Vec<String> = words
+      .iter()
+      .map(|word| match NUMERALS.iter().find(|(numeral_word, _)| numeral_word == word) {
+        Some((_, numeral)) => numeral.to_string(),
+        None => word.clone(),
+      })
+      .collect();
+  if numeral_words != words {
+    word_sets.push(numeral_words);
+  }
+
+  let variants: Vec<String> = word_sets.iter().flat_map(|word_set| get_hyphenations(word_set)).collect();
+  dedup(variants, "")
+}
+
+/// Join words with or without hyphens between each pair of words
+fn get_hyphenations(words: &[String]) -> Vec<String> {
+  let gaps = words.len() - 1;
+
+  if words.len() > MAX_WORDS_FOR_PARTIAL_HYPHENATION {
+    return vec![words.join(""), words.join("-")];
+  }
+
+  (0..1usize << gaps)
+      .map(|mask| {
+        let mut variant = words[0].clone();
+        for (i, word) in words.iter().enumerate().skip(1) {
+          if mask & (1 << (i - 1)) != 0 {
+            variant.push('-');
+          }
+          variant.push_str(word);
+        }
+        variant
+      })
+      .collect()
+}
+
+/// Generate alternatives of a name when it is taken everywhere, best alternatives first (ranked by score)
+/// - plural or singular form (e.g. "apps", "app")
+/// - number substitution of whole words (e.g. "pathtogrowth" => "path2growth", "go-to-market" => "go-2-market")
+/// - common affixes, plain or hyphenated (e.g. "getexample", "example-hq")
+/// - common numbers (e.g. "example24")
+pub fn generate_alternatives(name: &str) -> Vec<String> {
+  let name = name.to_lowercase();
+  let mut alternatives: Vec<String> = vec![];
+
+  alternatives.extend(get_plural_forms(&name));
+  alternatives.extend(get_numeral_substitutions(&name));
+
+  for (prefix, suffix) in PREFIXES.iter().zip(SUFFIXES.iter()) {
+    alternatives.push(format!("{}{}", prefix, name));
+    alternatives.push(format!("{}{}", name, suffix));
+    alternatives.push(format!("{}-{}", prefix, name));
+    alternatives.push(format!("{}-{}", name, suffix));
+  }
+
+  alternatives.extend(NUMBER_SUFFIXES.iter().map(|number| format!("{}{}", name, number)));
+
+  let mut alternatives = dedup(alternatives, &name);
+  alternatives.sort_by_key(|alternative| Reverse(score(alternative)));
+  alternatives
+}
+
+/// Get the plural form of a word, or its singular form if it already looks plural
+pub fn get_plural_forms(word: &str) -> Vec<String> {
+  let mut forms: Vec<String> = vec![];
+
+  if let Some(stem) = word.strip_suffix("ies") {
+    forms.push(format!("{}y", stem));
+  } else if let Some(stem) = word.strip_suffix("es").filter(|stem| ends_with_sibilant(stem)) {
+    forms.push(stem.to_string());
+  } else if let Some(stem) = word.strip_suffix('s').filter(|stem| !stem.ends_with('s')) {
+    forms.push(stem.to_string());
+  } else if let Some(stem) = word.strip_suffix('y').filter(|stem| !stem.ends_with(['a', 'e', 'i', 'o', 'u'])) {
+    forms.push(format!("{}ies", stem));
+  } else if ends_with_sibilant(word) {
+    forms.push(format!("{}es", word));
+  } else {
+    forms.push(format!("{}s", word));
+  }
+
+  forms.retain(|form| !form.is_empty());
+  forms
+}
+
+/// Replace whole words with numbers, one at a time (e.g. "go-to-market" => "go-2-market")
+/// Inside a hyphen separated segment, a word is only replaced when the rest of the segment is made of dictionary words
+/// (e.g. "pathtogrowth" => "path2growth"), so parts of words are kept (e.g. "create" does not become "cre8")
+pub fn get_numeral_substitutions(name: &str) -> Vec<String> {
+  let segments: Vec<&str> = name.split('-').collect();
+  let mut substitutions: Vec<String> = vec![];
+
+  for (i, segment) in segments.iter().enumerate() {
+    for (word, numeral) in NUMERALS.iter() {
+      for (start, _) in segment.match_indices(word) {
+        let (before, after) = (&segment[..start], &segment[start + word.len()..]);
+
+        if is_made_of_words(before) && is_made_of_words(after) {
+          let mut substituted: Vec<String> = segments.iter().map(|segment| segment.to_string()).collect();
+          substituted[i] = format!("{}{}{}", before, numeral, after);
+          substitutions.push(substituted.join("-"));
+        }
+      }
+    }
+  }
+
+  substitutions
+}
+
+/// Check if a part of a name is empty or only made of dictionary words
+fn is_made_of_words(part: &str) -> bool {
+  part.is_empty() || dictionary_coverage(part) >= 1.0
+}
+
+fn ends_with_sibilant(word: &str) -> bool {
+  word.ends_with(['s', 'x', 'z']) || word.ends_with("ch") || word.ends_with("sh")
+}
+
+/// Remove duplicates and the original name while keeping the order
+fn dedup(variants: Vec<String>, name: &str) -> Vec<String> {
+  let mut result: Vec<String> = vec![];
+
+  for variant in variants {
+    if variant != name && !variant.is_empty() && !result.contains(&variant) {
+      result.push(variant);
+    }
+  }
+
+  result
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn joined_and_hyphenated_variants() {
+    let variants = generate_variants("my cool app");
+    for variant in ["mycoolapp", "my-cool-app", "mycool-app", "my-coolapp", "mycoolapps", "my-cool-apps"] {
+      assert!(variants.contains(&String::from(variant)), "missing {}", variant);
+    }
+    assert_eq!(variants[0], "mycoolapp");
+  }
+
+  #[test]
+  fn whole_word_numerals() {
+    let variants = generate_variants("go to market");
+    assert!(variants.contains(&String::from("go2market")));
+    assert!(variants.contains(&String::from("go-2-market")));
+
+    // Numeral words inside other words are kept
+    let variants = generate_variants("create often");
+    assert!(variants.iter().all(|variant| !variant.contains('8') && !variant.contains('1')));
+    assert!(generate_variants("tool for you").contains(&String::from("tool4you")));
+    assert!(!generate_variants("fortune cookie").iter().any(|variant| variant.contains('4')));
+  }
+
+  #[test]
+  fn numeral_substitutions() {
+    assert_eq!(get_numeral_substitutions("go-to-market"), ["go-2-market"]);
+    assert_eq!(get_numeral_substitutions("pathtomarket"), ["path2market"]);
+    assert!(get_numeral_substitutions("create").is_empty());
+    assert!(get_numeral_substitutions("tomato").is_empty());
+    assert_eq!(get_numeral_substitutions("four"), ["4"]);
+  }
+
+  #[test]
+  fn plural_and_singular_forms() {
+    assert_eq!(get_plural_forms("app"), ["apps"]);
+    assert_eq!(get_plural_forms("apps"), ["app"]);
+    assert_eq!(get_plural_forms("city"), ["cities"]);
+    assert_eq!(get_plural_forms("cities"), ["city"]);
+    assert_eq!(get_plural_forms("box"), ["boxes"]);
+    assert_eq!(get_plural_forms("boxes"), ["box"]);
+    assert_eq!(get_plural_forms("day"), ["days"]);
+    assert_eq!(get_plural_forms("glass"), ["glasses"]);
+    assert!(get_plural_forms("s").is_empty());
+  }
+
+  #[test]
+  fn alternatives_exclude_the_name() {
+    let alternatives = generate_alternatives("Example");
+    assert!(!alternatives.contains(&String::from("example")));
+    for alternative in ["examples", "getexample", "example-hq", "example24"] {
+      assert!(alternatives.contains(&String::from(alternative)), "missing {}", alternative);
+    }
+    let unique: std::collections::HashSet<&String> = alternatives.iter().collect();
+    assert_eq!(unique.len(), alternatives.len());
+  }
+
+  #[test]
+  fn alternatives_are_ranked_by_score() {
+    let scores: Vec<u8> = generate_alternatives("cloud").iter().map(|alternative| score(alternative)).collect();
+    assert!(scores.windows(2).all(|pair| pair[0] >= pair[1]));
+  }
+
+  #[test]
+  fn empty_query() {
+    assert!(generate_variants("").is_empty());
+    assert!(generate_variants(" - _ ").is_empty());
+  }
+}
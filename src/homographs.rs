@@ -0,0 +1,116 @@
+mod confusables;
+
+use confusables::CONFUSABLES;
+
+/// Scripts which can be mixed in a single label (UTS #39 "highly restrictive" level)
+/// e.g. Japanese names mixing Han, Hiragana and Katakana
+const ALLOWED_SCRIPT_SETS: [&[&str]; 3] = [
+  &["Latin", "Han", "Hiragana", "Katakana"],
+  &["Latin", "Han", "Bopomofo"],
+  &["Latin", "Han", "Hangul"],
+];
+
+/// Get the script of a character, `None` for characters shared by all scripts (digits, hyphen, dot)
+pub fn get_script(c: char) -> Option<&'static str> {
+  let script = match c as u32 {
+    0x30..=0x39 | 0x2D | 0x2E => return None,
+    0x41..=0x5A | 0x61..=0x7A | 0xC0..=0x24F | 0x1E00..=0x1EFF | 0x250..=0x2AF | 0xA720..=0xA7FF => "Latin",
+    0x370..=0x3FF | 0x1F00..=0x1FFF => "Greek",
+    0x400..=0x52F | 0x1C80..=0x1C8F | 0x2DE0..=0x2DFF | 0xA640..=0xA69F => "Cyrillic",
+    0x530..=0x58F => "Armenian",
+    0x590..=0x5FF => "Hebrew",
+    0x600..=0x6FF | 0x750..=0x77F => "Arabic",
+    0x900..=0x97F => "Devanagari",
+    0xE00..=0xE7F => "Thai",
+    0x10A0..=0x10FF => "Georgian",
+    0x3040..=0x309F => "Hiragana",
+    0x30A0..=0x30FF => "Katakana",
+    0x3100..=0x312F => "Bopomofo",
+    0x1100..=0x11FF | 0xAC00..=0xD7AF => "Hangul",
+    0x4E00..=0x9FFF | 0x3400..=0x4DBF => "Han",
+    0x300..=0x36F => return None,
+    _ => "Other",
+  };
+
+  Some(script)
+}
+
+/// Get the scripts used in a name, in order of appearance
+pub fn find_scripts(name: &str) -> Vec<&'static str> {
+  let mut scripts: Vec<&'static str> = vec![];
+
+  for script in name.chars().filter_map(get_script) {
+    if !scripts.contains(&script) {
+      scripts.push(script);
+    }
+  }
+
+  scripts
+}
+
+/// Check if a name mixes scripts which are not usually used together (e.g. Latin and Cyrillic)
+pub fn is_mixed_script(scripts: &[&str]) -> bool {
+  scripts.len() > 1
+      && !ALLOWED_SCRIPT_SETS
+      .iter()
+      .any(|allowed| scripts.iter().all(|script| allowed.contains(script)))
+}
+
+/// Get the prototype of a confusable character from the Unicode confusables data (e.g. Cyrillic 'а' => "a")
+pub fn get_prototype(c: char) -> Option<&'static str> {
+  CONFUSABLES
+      .binary_search_by_key(&c, |(confusable, _)| *confusable)
+      .ok()
+      .map(|index| CONFUSABLES[index].1)
+}
+
+/// Replace every confusable character with its prototype (e.g. "раураl" => "paypal")
+/// Prototypes are lowercased since domain names are case-insensitive
+pub fn get_skeleton(name: &str) -> String {
+  name
+      .chars()
+      .map(|c| match get_prototype(c) {
+        Some(prototype) => prototype.to_lowercase(),
+        None => c.to_string(),
+      })
+      .collect()
+}
+
+/// Describe the homograph risks of an internationalized name, `None` for ASCII names or safe names
+/// (e.g. "Mixed scripts: Latin, Cyrillic | Looks like paypal.com")
+pub fn check_homograph(name: &str) -> Option<String> {
+  if name.is_ascii() {
+    return None;
+  }
+
+  let mut warnings: Vec<String> = vec![];
+
+  let scripts = find_scripts(name);
+  let is_mixed = is_mixed_script(&scripts);
+  if is_mixed {
+    warnings.push(format!("Mixed scripts: {}", scripts.join(", ")));
+  }
+
+  let confusable_chars: Vec<String> = name
+      .chars()
+      .filter(|c| get_prototype(*c).is_some())
+      .map(|c| c.to_string())
+      .collect();
+
+  if !confusable_chars.is_empty() {
+    let skeleton = get_skeleton(name);
+
+    if skeleton.is_ascii() {
+      warnings.push(format!("Looks like {}", skeleton));
+    } else if is_mixed {
+      // Single script names (e.g. Greek "ελλάδα") only contain lookalikes by nature
+      warnings.push(format!("Confusable characters: {}", confusable_chars.join(", ")));
+    }
+  }
+
+  if warnings.is_empty() {
+    None
+  } else {
+    Some(warnings.join(" | "))
+  }
+}
@@ -0,0 +1,1066 @@
+// Generated from the Unicode confusables data, do not edit directly
+// https://www.unicode.org/Public/security/16.0.0/confusables.txt
+// Unicode data is distributed under the Unicode License (https://www.unicode.org/license.txt).
+
+/// Prototypes of the confusable characters (e.g. Cyrillic 'а' => "a"), sorted by character.
+/// ASCII characters are left out: they are never replaced in domain names (e.g. "m" => "rn")
+pub static CONFUSABLES: [(char, &str); 6347] = [
+  ('\u{a0}', " "), ('\u{a2}', "c\u{338}"), ('\u{a5}', "Y\u{335}"), ('\u{af}', "\u{2c9}"), ('\u{b4}', "'"), ('\u{b5}', "\u{3bc}"),
+  ('\u{b8}', ","), ('\u{c6}', "AE"), ('\u{c7}', "C\u{326}"), ('\u{d0}', "D\u{335}"), ('\u{d7}', "x"), ('\u{d8}', "O\u{338}"),
+  ('\u{e6}', "ae"), ('\u{e7}', "c\u{326}"), ('\u{f0}', "\u{2202}\u{335}"), ('\u{f6}', "\u{629}"), ('\u{f8}', "o\u{338}"), ('\u{110}', "D\u{335}"),
+  ('\u{111}', "d\u{335}"), ('\u{11a}', "\u{114}"), ('\u{11b}', "\u{115}"), ('\u{126}', "H\u{335}"), ('\u{127}', "h\u{335}"), ('\u{131}', "i"),
+  ('\u{132}', "lJ"), ('\u{133}', "ij"), ('\u{13f}', "l\u{b7}"), ('\u{140}', "l\u{b7}"), ('\u{141}', "L\u{338}"), ('\u{142}', "l\u{338}"),
+  ('\u{146}', "\u{272}"), ('\u{149}', "'n"), ('\u{150}', "\u{d6}"), ('\u{152}', "OE"), ('\u{153}', "oe"), ('\u{163}', "\u{1ab}"),
+  ('\u{166}', "T\u{335}"), ('\u{167}', "t\u{335}"), ('\u{17f}', "f"), ('\u{180}', "b\u{335}"), ('\u{181}', "'B"), ('\u{182}', "b\u{304}"),
+  ('\u{183}', "b\u{304}"), ('\u{184}', "b"), ('\u{187}', "C'"), ('\u{189}', "D\u{335}"), ('\u{18a}', "'D"), ('\u{18c}', "d\u{304}"),
+  ('\u{18d}', "g"), ('\u{191}', "F\u{326}"), ('\u{192}', "f\u{326}"), ('\u{193}', "G'"), ('\u{196}', "l"), ('\u{197}', "l\u{335}"),
+  ('\u{198}', "K'"), ('\u{199}', "k\u{314}"), ('\u{19a}', "l\u{335}"), ('\u{19b}', "\u{3bb}\u{338}"), ('\u{19d}', "N\u{326}"), ('\u{19e}', "n\u{329}"),
+  ('\u{19f}', "O\u{335}"), ('\u{1a0}', "O'"), ('\u{1a1}', "o'"), ('\u{1a4}', "'P"), ('\u{1a5}', "p\u{314}"), ('\u{1a6}', "R"),
+  ('\u{1a7}', "2"), ('\u{1ac}', "'T"), ('\u{1ad}', "t\u{314}"), ('\u{1ae}', "T\u{328}"), ('\u{1b3}', "'Y"), ('\u{1b4}', "y\u{314}"),
+  ('\u{1b5}', "Z\u{335}"), ('\u{1b6}', "z\u{335}"), ('\u{1b7}', "3"), ('\u{1bb}', "2\u{335}"), ('\u{1bc}', "5"), ('\u{1bd}', "s"),
+  ('\u{1bf}', "\u{fe}"), ('\u{1c0}', "l"), ('\u{1c1}', "ll"), ('\u{1c3}', "!"), ('\u{1c4}', "D\u{17d}"), ('\u{1c5}', "D\u{17e}"),
+  ('\u{1c6}', "d\u{17e}"), ('\u{1c7}', "LJ"), ('\u{1c8}', "Lj"), ('\u{1c9}', "lj"), ('\u{1ca}', "NJ"), ('\u{1cb}', "Nj"),
+  ('\u{1cc}', "nj"), ('\u{1cd}', "\u{102}"), ('\u{1ce}', "\u{103}"), ('\u{1cf}', "\u{12c}"), ('\u{1d0}', "\u{12d}"), ('\u{1d1}', "\u{14e}"),
+  ('\u{1d2}', "\u{14f}"), ('\u{1d3}', "\u{16c}"), ('\u{1d4}', "\u{16d}"), ('\u{1e4}', "G\u{335}"), ('\u{1e5}', "g\u{335}"), ('\u{1e6}', "\u{11e}"),
+  ('\u{1e7}', "\u{11f}"), ('\u{1f1}', "DZ"), ('\u{1f2}', "Dz"), ('\u{1f3}', "dz"), ('\u{1f5}', "\u{123}"), ('\u{1fe}', "O\u{338}\u{301}"),
+  ('\u{21a}', "\u{162}"), ('\u{21b}', "\u{1ab}"), ('\u{21c}', "3"), ('\u{222}', "8"), ('\u{223}', "8"), ('\u{224}', "Z\u{326}"),
+  ('\u{225}', "z\u{326}"), ('\u{226}', "\u{c5}"), ('\u{227}', "\u{e5}"), ('\u{23c}', "c\u{338}"), ('\u{23e}', "T\u{338}"), ('\u{241}', "?"),
+  ('\u{244}', "U\u{335}"), ('\u{246}', "E\u{338}"), ('\u{247}', "e\u{338}"), ('\u{248}', "J\u{335}"), ('\u{249}', "j\u{335}"), ('\u{24d}', "r\u{335}"),
+  ('\u{24e}', "Y\u{335}"), ('\u{24f}', "y\u{335}"), ('\u{251}', "a"), ('\u{253}', "b\u{314}"), ('\u{256}', "d\u{328}"), ('\u{257}', "d\u{314}"),
+  ('\u{259}', "\u{1dd}"), ('\u{25a}', "\u{1dd}\u{2de}"), ('\u{25b}', "\u{a793}"), ('\u{260}', "g\u{314}"), ('\u{261}', "g"), ('\u{263}', "y"),
+  ('\u{266}', "h\u{314}"), ('\u{268}', "i\u{335}"), ('\u{269}', "i"), ('\u{26a}', "i"), ('\u{26b}', "l\u{334}"), ('\u{26d}', "l\u{328}"),
+  ('\u{26e}', "l\u{21d}"), ('\u{26f}', "w"), ('\u{271}', "rn\u{326}"), ('\u{273}', "n\u{328}"), ('\u{275}', "o\u{335}"), ('\u{276}', "o\u{1d07}"),
+  ('\u{27c}', "r\u{329}"), ('\u{27d}', "r\u{328}"), ('\u{282}', "s\u{328}"), ('\u{28b}', "u"), ('\u{28f}', "y"), ('\u{290}', "z\u{328}"),
+  ('\u{292}', "\u{21d}"), ('\u{294}', "?"), ('\u{2a0}', "q\u{314}"), ('\u{2a3}', "dz"), ('\u{2a4}', "d\u{21d}"), ('\u{2a5}', "d\u{291}"),
+  ('\u{2a6}', "ts"), ('\u{2a7}', "t\u{283}"), ('\u{2a8}', "t\u{255}"), ('\u{2a9}', "f\u{14b}"), ('\u{2aa}', "ls"), ('\u{2ab}', "lz"),
+  ('\u{2b3}', "\u{18f4}"), ('\u{2b9}', "'"), ('\u{2ba}', "''"), ('\u{2bb}', "'"), ('\u{2bc}', "'"), ('\u{2bd}', "'"),
+  ('\u{2be}', "'"), ('\u{2bf}', "\u{559}"), ('\u{2c2}', "<"), ('\u{2c3}', ">"), ('\u{2c4}', "^"), ('\u{2c6}', "^"),
+  ('\u{2c8}', "'"), ('\u{2ca}', "'"), ('\u{2cb}', "'"), ('\u{2d0}', ":"), ('\u{2d3}', "\u{559}"), ('\u{2d7}', "-"),
+  ('\u{2d8}', "\u{2c7}"), ('\u{2d9}', "\u{971}"), ('\u{2da}', "\u{b0}"), ('\u{2db}', "i"), ('\u{2dc}', "~"), ('\u{2dd}', "''"),
+  ('\u{2e1}', "\u{18f3}"), ('\u{2e2}', "\u{18f5}"), ('\u{2e4}', "\u{2c1}"), ('\u{2ee}', "''"), ('\u{2f4}', "'"), ('\u{2f6}', "''"),
+  ('\u{2f8}', ":"), ('\u{2fb}', "\u{2ea}"), ('\u{305}', "\u{304}"), ('\u{30c}', "\u{306}"), ('\u{30d}', "\u{670}"), ('\u{310}', "\u{306}\u{307}"),
+  ('\u{311}', "\u{302}"), ('\u{315}', "\u{313}"), ('\u{317}', "\u{650}"), ('\u{320}', "\u{331}"), ('\u{321}', "\u{326}"), ('\u{322}', "\u{328}"),
+  ('\u{327}', "\u{326}"), ('\u{336}', "\u{335}"), ('\u{337}', "\u{338}"), ('\u{339}', "\u{326}"), ('\u{340}', "\u{300}"), ('\u{341}', "\u{301}"),
+  ('\u{342}', "\u{303}"), ('\u{343}', "\u{313}"), ('\u{345}', "\u{328}"), ('\u{347}', "\u{333}"), ('\u{357}', "\u{350}"), ('\u{358}', "\u{307}"),
+  ('\u{366}', "\u{30a}"), ('\u{36e}', "\u{306}"), ('\u{370}', "\u{2c75}"), ('\u{374}', "'"), ('\u{375}', "\u{2cf}"), ('\u{376}', "\u{418}"),
+  ('\u{377}', "\u{1d0e}"), ('\u{37a}', "i"), ('\u{37b}', "\u{254}"), ('\u{37d}', "\u{a73f}"), ('\u{37e}', ";"), ('\u{37f}', "J"),
+  ('\u{384}', "'"), ('\u{387}', "\u{b7}"), ('\u{391}', "A"), ('\u{392}', "B"), ('\u{395}', "E"), ('\u{396}', "Z"),
+  ('\u{397}', "H"), ('\u{398}', "O\u{335}"), ('\u{399}', "l"), ('\u{39a}', "K"), ('\u{39b}', "\u{245}"), ('\u{39c}', "M"),
+  ('\u{39d}', "N"), ('\u{39f}', "O"), ('\u{3a1}', "P"), ('\u{3a3}', "\u{1a9}"), ('\u{3a4}', "T"), ('\u{3a5}', "Y"),
+  ('\u{3a7}', "X"), ('\u{3b1}', "a"), ('\u{3b2}', "\u{df}"), ('\u{3b3}', "y"), ('\u{3b4}', "\u{1e9f}"), ('\u{3b5}', "\u{a793}"),
+  ('\u{3b7}', "n\u{329}"), ('\u{3b8}', "O\u{335}"), ('\u{3b9}', "i"), ('\u{3ba}', "\u{138}"), ('\u{3bd}', "v"), ('\u{3bf}', "o"),
+  ('\u{3c1}', "p"), ('\u{3c3}', "o"), ('\u{3c4}', "\u{1d1b}"), ('\u{3c5}', "u"), ('\u{3c6}', "\u{278}"), ('\u{3d0}', "\u{df}"),
+  ('\u{3d1}', "O\u{335}"), ('\u{3d2}', "Y"), ('\u{3d5}', "\u{278}"), ('\u{3d6}', "\u{3c0}"), ('\u{3db}', "\u{3c2}"), ('\u{3dc}', "F"),
+  ('\u{3e8}', "2"), ('\u{3e9}', "\u{1a8}"), ('\u{3f0}', "\u{138}"), ('\u{3f1}', "p"), ('\u{3f2}', "c"), ('\u{3f3}', "j"),
+  ('\u{3f4}', "O\u{335}"), ('\u{3f5}', "\u{a793}"), ('\u{3f7}', "\u{de}"), ('\u{3f8}', "\u{fe}"), ('\u{3f9}', "C"), ('\u{3fa}', "M"),
+  ('\u{3fd}', "\u{186}"), ('\u{3ff}', "\u{a73e}"), ('\u{404}', "\u{a792}"), ('\u{405}', "S"), ('\u{406}', "l"), ('\u{408}', "J"),
+  ('\u{410}', "A"), ('\u{411}', "b\u{304}"), ('\u{412}', "B"), ('\u{413}', "\u{393}"), ('\u{415}', "E"), ('\u{417}', "3"),
+  ('\u{419}', "\u{40d}"), ('\u{41a}', "K"), ('\u{41b}', "\u{245}"), ('\u{41c}', "M"), ('\u{41d}', "H"), ('\u{41e}', "O"),
+  ('\u{41f}', "\u{3a0}"), ('\u{420}', "P"), ('\u{421}', "C"), ('\u{422}', "T"), ('\u{423}', "Y"), ('\u{424}', "\u{3a6}"),
+  ('\u{425}', "X"), ('\u{42b}', "bl"), ('\u{42c}', "b"), ('\u{42e}', "lO"), ('\u{430}', "a"), ('\u{431}', "6"),
+  ('\u{432}', "\u{299}"), ('\u{433}', "r"), ('\u{435}', "e"), ('\u{437}', "\u{25c}"), ('\u{438}', "\u{1d0e}"), ('\u{43a}', "\u{138}"),
+  ('\u{43c}', "\u{28d}"), ('\u{43d}', "\u{29c}"), ('\u{43e}', "o"), ('\u{43f}', "\u{3c0}"), ('\u{440}', "p"), ('\u{441}', "c"),
+  ('\u{442}', "\u{1d1b}"), ('\u{443}', "y"), ('\u{444}', "\u{278}"), ('\u{445}', "x"), ('\u{44a}', "\u{2c9}b"), ('\u{44b}', "\u{185}i"),
+  ('\u{44c}', "\u{185}"), ('\u{44f}', "\u{1d19}"), ('\u{454}', "\u{a793}"), ('\u{455}', "s"), ('\u{456}', "i"), ('\u{458}', "j"),
+  ('\u{45b}', "h\u{335}"), ('\u{45d}', "\u{439}"), ('\u{461}', "w"), ('\u{462}', "b\u{335}"), ('\u{463}', "b\u{335}"), ('\u{470}', "\u{3a8}"),
+  ('\u{471}', "\u{3c8}"), ('\u{472}', "O\u{335}"), ('\u{473}', "o\u{335}"), ('\u{474}', "V"), ('\u{475}', "v"), ('\u{47c}', "\u{460}\u{486}\u{487}"),
+  ('\u{47d}', "w\u{486}\u{487}"), ('\u{48a}', "\u{40d}\u{326}"), ('\u{48b}', "\u{439}\u{326}"), ('\u{48c}', "b\u{335}"), ('\u{48d}', "b\u{335}"), ('\u{490}', "\u{393}'"),
+  ('\u{491}', "r'"), ('\u{492}', "\u{393}\u{335}"), ('\u{493}', "r\u{335}"), ('\u{496}', "\u{416}\u{329}"), ('\u{497}', "\u{436}\u{329}"), ('\u{498}', "3\u{326}"),
+  ('\u{499}', "\u{25c}\u{326}"), ('\u{49a}', "K\u{329}"), ('\u{49b}', "\u{138}\u{329}"), ('\u{49e}', "K\u{335}"), ('\u{49f}', "\u{138}\u{335}"), ('\u{4a2}', "H\u{329}"),
+  ('\u{4a3}', "\u{29c}\u{329}"), ('\u{4aa}', "C\u{326}"), ('\u{4ab}', "c\u{326}"), ('\u{4ac}', "T\u{329}"), ('\u{4ad}', "\u{1d1b}\u{329}"), ('\u{4ae}', "Y"),
+  ('\u{4af}', "y"), ('\u{4b0}', "Y\u{335}"), ('\u{4b1}', "y\u{335}"), ('\u{4b2}', "X\u{329}"), ('\u{4bb}', "h"), ('\u{4bd}', "e"),
+  ('\u{4be}', "\u{4bc}\u{328}"), ('\u{4bf}', "e\u{328}"), ('\u{4c0}', "l"), ('\u{4c5}', "\u{245}\u{326}"), ('\u{4c6}', "\u{43b}\u{326}"), ('\u{4c7}', "H\u{326}"),
+  ('\u{4c8}', "\u{29c}\u{326}"), ('\u{4c9}', "H\u{326}"), ('\u{4ca}', "\u{29c}\u{326}"), ('\u{4cb}', "\u{4b6}"), ('\u{4cc}', "\u{4b7}"), ('\u{4cd}', "M\u{326}"),
+  ('\u{4ce}', "\u{28d}\u{326}"), ('\u{4cf}', "i"), ('\u{4d4}', "AE"), ('\u{4d5}', "ae"), ('\u{4d8}', "\u{18f}"), ('\u{4d9}', "\u{1dd}"),
+  ('\u{4e0}', "3"), ('\u{4e1}', "\u{21d}"), ('\u{4e8}', "O\u{335}"), ('\u{4e9}', "o\u{335}"), ('\u{501}', "d"), ('\u{50a}', "\u{1f6}"),
+  ('\u{50c}', "G"), ('\u{50d}', "\u{262}"), ('\u{510}', "\u{190}"), ('\u{511}', "\u{a793}"), ('\u{51b}', "q"), ('\u{51c}', "W"),
+  ('\u{51d}', "w"), ('\u{53b}', "\u{12ae}"), ('\u{544}', "\u{1206}"), ('\u{54a}', "\u{1323}"), ('\u{54c}', "\u{1261}"), ('\u{54d}', "U"),
+  ('\u{54f}', "S"), ('\u{553}', "\u{3a6}"), ('\u{555}', "O"), ('\u{55a}', "'"), ('\u{55d}', "'"), ('\u{561}', "w"),
+  ('\u{563}', "q"), ('\u{566}', "q"), ('\u{56e}', "\u{1e9f}"), ('\u{570}', "h"), ('\u{575}', "\u{237}"), ('\u{578}', "n"),
+  ('\u{57a}', "\u{270}"), ('\u{57c}', "n"), ('\u{57d}', "u"), ('\u{581}', "g"), ('\u{584}', "f"), ('\u{585}', "o"),
+  ('\u{587}', "\u{565}\u{582}"), ('\u{589}', ":"), ('\u{59c}', "\u{301}"), ('\u{59d}', "\u{301}"), ('\u{5a4}', "\u{59a}"), ('\u{5a8}', "\u{599}"),
+  ('\u{5ad}', "\u{596}"), ('\u{5ae}', "\u{598}"), ('\u{5af}', "\u{30a}"), ('\u{5b4}', "\u{323}"), ('\u{5b9}', "\u{307}"), ('\u{5ba}', "\u{307}"),
+  ('\u{5c0}', "l"), ('\u{5c1}', "\u{307}"), ('\u{5c2}', "\u{307}"), ('\u{5c3}', ":"), ('\u{5c4}', "\u{307}"), ('\u{5c5}', "\u{323}"),
+  ('\u{5d5}', "l"), ('\u{5d8}', "v"), ('\u{5d9}', "'"), ('\u{5df}', "l"), ('\u{5e1}', "o"), ('\u{5f0}', "ll"),
+  ('\u{5f1}', "l'"), ('\u{5f2}', "''"), ('\u{5f3}', "'"), ('\u{5f4}', "''"), ('\u{609}', "\u{ba}/\u{2080}\u{2080}"), ('\u{60a}', "\u{ba}/\u{2080}\u{2080}\u{2080}"),
+  ('\u{60d}', ","), ('\u{60f}', "\u{639}"), ('\u{618}', "\u{301}"), ('\u{619}', "\u{313}"), ('\u{61a}', "\u{650}"), ('\u{623}', "l\u{674}"),
+  ('\u{624}', "\u{648}\u{674}"), ('\u{625}', "l\u{655}"), ('\u{626}', "\u{649}\u{674}"), ('\u{627}', "l"), ('\u{62b}', "\u{649}\u{6db}"), ('\u{634}', "\u{633}\u{6db}"),
+  ('\u{63d}', "\u{649}\u{302}"), ('\u{63f}', "\u{649}\u{6db}"), ('\u{647}', "o"), ('\u{64a}', "\u{649}"), ('\u{64b}', "\u{30b}"), ('\u{64e}', "\u{301}"),
+  ('\u{64f}', "\u{313}"), ('\u{652}', "\u{30a}"), ('\u{653}', "\u{303}"), ('\u{656}', "\u{329}"), ('\u{657}', "\u{312}"), ('\u{658}', "\u{306}"),
+  ('\u{659}', "\u{304}"), ('\u{65a}', "\u{306}"), ('\u{65b}', "\u{302}"), ('\u{65c}', "\u{323}"), ('\u{65d}', "\u{314}"), ('\u{65f}', "\u{655}"),
+  ('\u{660}', "."), ('\u{661}', "l"), ('\u{665}', "o"), ('\u{667}', "V"), ('\u{668}', "\u{245}"), ('\u{66a}', "\u{ba}/\u{2080}"),
+  ('\u{66b}', ","), ('\u{66c}', "\u{60c}"), ('\u{66d}', "*"), ('\u{66e}', "\u{649}"), ('\u{66f}', "\u{6a1}"), ('\u{672}', "l\u{674}"),
+  ('\u{673}', "l\u{655}"), ('\u{675}', "l\u{674}"), ('\u{676}', "\u{648}\u{674}"), ('\u{677}', "\u{648}\u{313}\u{674}"), ('\u{678}', "\u{649}\u{674}"), ('\u{679}', "\u{649}\u{615}"),
+  ('\u{67e}', "\u{649}\u{6db}"), ('\u{681}', "\u{62d}\u{654}"), ('\u{685}', "\u{62d}\u{6db}"), ('\u{688}', "\u{62f}\u{615}"), ('\u{68b}', "\u{68a}\u{615}"), ('\u{68e}', "\u{62f}\u{6db}"),
+  ('\u{691}', "\u{631}\u{615}"), ('\u{692}', "\u{631}\u{306}"), ('\u{698}', "\u{631}\u{6db}"), ('\u{69e}', "\u{635}\u{6db}"), ('\u{69f}', "\u{637}\u{6db}"), ('\u{6a4}', "\u{6a1}\u{6db}"),
+  ('\u{6a7}', "\u{641}"), ('\u{6a8}', "\u{6a1}\u{6db}"), ('\u{6a9}', "\u{643}"), ('\u{6aa}', "\u{643}"), ('\u{6ad}', "\u{643}\u{6db}"), ('\u{6b4}', "\u{6af}\u{6db}"),
+  ('\u{6b5}', "\u{644}\u{306}"), ('\u{6b7}', "\u{644}\u{6db}"), ('\u{6ba}', "\u{649}"), ('\u{6bb}', "\u{649}\u{615}"), ('\u{6bd}', "\u{649}\u{6db}"), ('\u{6be}', "o"),
+  ('\u{6c1}', "o"), ('\u{6c2}', "\u{6c0}"), ('\u{6c3}', "\u{629}"), ('\u{6c6}', "\u{648}\u{306}"), ('\u{6c7}', "\u{648}\u{313}"), ('\u{6c8}', "\u{648}\u{670}"),
+  ('\u{6c9}', "\u{648}\u{302}"), ('\u{6cb}', "\u{648}\u{6db}"), ('\u{6cc}', "\u{649}"), ('\u{6ce}', "\u{649}\u{306}"), ('\u{6d0}', "\u{67b}"), ('\u{6d1}', "\u{649}\u{6db}"),
+  ('\u{6d2}', "\u{649}"), ('\u{6d4}', "-"), ('\u{6d5}', "o"), ('\u{6df}', "\u{30a}"), ('\u{6e8}', "\u{306}\u{307}"), ('\u{6ec}', "\u{307}"),
+  ('\u{6ee}', "\u{62f}\u{302}"), ('\u{6ef}', "\u{631}\u{302}"), ('\u{6f0}', "."), ('\u{6f1}', "l"), ('\u{6f2}', "\u{662}"), ('\u{6f3}', "\u{663}"),
+  ('\u{6f4}', "\u{664}"), ('\u{6f5}', "o"), ('\u{6f6}', "\u{666}"), ('\u{6f7}', "V"), ('\u{6f8}', "\u{245}"), ('\u{6f9}', "\u{669}"),
+  ('\u{6fd}', "\u{621}\u{348}"), ('\u{6fe}', "\u{645}\u{348}"), ('\u{6ff}', "o\u{302}"), ('\u{701}', "."), ('\u{702}', "."), ('\u{703}', ":"),
+  ('\u{704}', ":"), ('\u{740}', "\u{307}"), ('\u{741}', "\u{307}"), ('\u{742}', "\u{73c}"), ('\u{747}', "\u{301}"), ('\u{751}', "\u{628}\u{6db}"),
+  ('\u{756}', "\u{649}\u{306}"), ('\u{762}', "\u{6ac}"), ('\u{763}', "\u{643}\u{6db}"), ('\u{767}', "\u{754}"), ('\u{768}', "\u{646}\u{615}"), ('\u{769}', "\u{646}\u{306}"),
+  ('\u{76c}', "\u{631}\u{654}"), ('\u{771}', "\u{697}\u{615}"), ('\u{772}', "\u{62d}\u{654}"), ('\u{77e}', "\u{633}\u{302}"), ('\u{7c0}', "O"), ('\u{7ca}', "l"),
+  ('\u{7eb}', "\u{304}"), ('\u{7ed}', "\u{307}"), ('\u{7ee}', "\u{302}"), ('\u{7f3}', "\u{308}"), ('\u{7f4}', "'"), ('\u{7f5}', "'"),
+  ('\u{7fa}', "_"), ('\u{8a1}', "\u{628}\u{654}"), ('\u{8a4}', "\u{6a2}\u{6db}"), ('\u{8a7}', "\u{645}\u{6db}"), ('\u{8a8}', "\u{649}\u{654}"), ('\u{8a9}', "\u{754}"),
+  ('\u{8ae}', "\u{62f}\u{324}\u{323}"), ('\u{8af}', "\u{635}\u{324}\u{323}"), ('\u{8b0}', "\u{6af}"), ('\u{8b1}', "\u{648}"), ('\u{8b2}', "\u{632}\u{302}"), ('\u{8b6}', "\u{628}\u{6e2}"),
+  ('\u{8b7}', "\u{649}\u{6db}\u{6e2}"), ('\u{8b9}', "\u{631}\u{306}\u{307}"), ('\u{8ba}', "\u{649}\u{306}\u{307}"), ('\u{8bb}', "\u{6a1}"), ('\u{8bc}', "\u{6a1}"), ('\u{8bd}', "\u{649}"),
+  ('\u{8e5}', "\u{64c}"), ('\u{8e8}', "\u{64c}"), ('\u{8ea}', "\u{307}"), ('\u{8eb}', "\u{308}"), ('\u{8ed}', "\u{323}"), ('\u{8ee}', "\u{324}"),
+  ('\u{8f0}', "\u{30b}"), ('\u{8f1}', "\u{64c}"), ('\u{8f2}', "\u{64d}"), ('\u{8f3}', "\u{313}"), ('\u{8f8}', "\u{350}"), ('\u{8f9}', "\u{354}"),
+  ('\u{8fa}', "\u{355}"), ('\u{8ff}', "\u{350}"), ('\u{900}', "\u{352}"), ('\u{901}', "\u{306}\u{307}"), ('\u{902}', "\u{307}"), ('\u{903}', ":"),
+  ('\u{904}', "\u{905}\u{946}"), ('\u{906}', "\u{905}\u{93e}"), ('\u{908}', "\u{930}\u{94d}\u{907}"), ('\u{90d}', "\u{90f}\u{945}"), ('\u{90e}', "\u{90f}\u{946}"), ('\u{910}', "\u{90f}\u{947}"),
+  ('\u{911}', "\u{905}\u{949}"), ('\u{912}', "\u{905}\u{93e}\u{946}"), ('\u{913}', "\u{905}\u{93e}\u{947}"), ('\u{914}', "\u{905}\u{93e}\u{948}"), ('\u{93c}', "\u{323}"), ('\u{952}', "\u{331}"),
+  ('\u{953}', "\u{300}"), ('\u{954}', "\u{301}"), ('\u{965}', "\u{964}\u{964}"), ('\u{966}', "o"), ('\u{967}', "\u{669}"), ('\u{97d}', "?"),
+  ('\u{981}', "\u{306}\u{307}"), ('\u{986}', "\u{985}\u{9be}"), ('\u{9bc}', "\u{323}"), ('\u{9e0}', "\u{98b}\u{9c3}"), ('\u{9e1}', "\u{98b}\u{9c3}"), ('\u{9e6}', "O"),
+  ('\u{9ea}', "8"), ('\u{9ed}', "9"), ('\u{a02}', "\u{307}"), ('\u{a03}', "\u{983}"), ('\u{a06}', "\u{a05}\u{a3e}"), ('\u{a07}', "\u{a72}\u{a3f}"),
+  ('\u{a08}', "\u{a72}\u{a40}"), ('\u{a09}', "\u{a73}\u{a41}"), ('\u{a0a}', "\u{a73}\u{a42}"), ('\u{a0f}', "\u{a72}\u{a47}"), ('\u{a10}', "\u{a05}\u{a48}"), ('\u{a14}', "\u{a05}\u{a4c}"),
+  ('\u{a3c}', "\u{323}"), ('\u{a4b}', "\u{946}"), ('\u{a4d}', "\u{94d}"), ('\u{a66}', "o"), ('\u{a67}', "9"), ('\u{a6a}', "8"),
+  ('\u{a81}', "\u{306}\u{307}"), ('\u{a82}', "\u{307}"), ('\u{a83}', ":"), ('\u{a86}', "\u{a85}\u{abe}"), ('\u{a8d}', "\u{a85}\u{ac5}"), ('\u{a8f}', "\u{a85}\u{ac7}"),
+  ('\u{a90}', "\u{a85}\u{ac8}"), ('\u{a91}', "\u{a85}\u{abe}\u{ac5}"), ('\u{a93}', "\u{a85}\u{abe}\u{ac7}"), ('\u{a94}', "\u{a85}\u{abe}\u{ac8}"), ('\u{abc}', "\u{323}"), ('\u{abd}', "\u{93d}"),
+  ('\u{ac1}', "\u{941}"), ('\u{ac2}', "\u{942}"), ('\u{acd}', "\u{94d}"), ('\u{ae6}', "o"), ('\u{ae8}', "\u{968}"), ('\u{ae9}', "\u{969}"),
+  ('\u{aea}', "\u{96a}"), ('\u{aee}', "\u{96e}"), ('\u{af0}', "\u{970}"), ('\u{b01}', "\u{306}\u{307}"), ('\u{b03}', "8"), ('\u{b06}', "\u{b05}\u{b3e}"),
+  ('\u{b20}', "O"), ('\u{b3c}', "\u{323}"), ('\u{b66}', "O"), ('\u{b68}', "9"), ('\u{b82}', "\u{30a}"), ('\u{b8a}', "\u{b89}\u{bb3}"),
+  ('\u{b9c}', "\u{b90}"), ('\u{bb0}', "\u{b88}"), ('\u{bbe}', "\u{b88}"), ('\u{bc8}', "\u{ba9}"), ('\u{bca}', "\u{bc6}\u{b88}"), ('\u{bcb}', "\u{bc7}\u{b88}"),
+  ('\u{bcc}', "\u{bc6}\u{bb3}"), ('\u{bcd}', "\u{307}"), ('\u{bd7}', "\u{bb3}"), ('\u{be6}', "o"), ('\u{be7}', "\u{b95}"), ('\u{be8}', "\u{b89}"),
+  ('\u{bea}', "\u{b9a}"), ('\u{beb}', "\u{b88}\u{bc1}"), ('\u{bec}', "\u{b9a}\u{bc1}"), ('\u{bed}', "\u{b8e}"), ('\u{bee}', "\u{b85}"), ('\u{bf0}', "\u{baf}"),
+  ('\u{bf2}', "\u{b9a}\u{bc2}"), ('\u{bf4}', "\u{bae}\u{bc0}"), ('\u{bf5}', "\u{bf3}"), ('\u{bf7}', "\u{b8e}\u{bb5}"), ('\u{bf8}', "\u{bb7}"), ('\u{bfa}', "\u{ba8}\u{bc0}"),
+  ('\u{c00}', "\u{306}\u{307}"), ('\u{c02}', "o"), ('\u{c03}', "\u{983}"), ('\u{c13}', "\u{c12}\u{c55}"), ('\u{c14}', "\u{c12}\u{c4c}"), ('\u{c20}', "\u{c30}\u{5bc}"),
+  ('\u{c22}', "\u{c21}\u{323}"), ('\u{c25}', "\u{c27}\u{5bc}"), ('\u{c2d}', "\u{c2c}\u{323}"), ('\u{c2e}', "\u{c35}\u{c41}"), ('\u{c37}', "\u{c35}\u{323}"), ('\u{c39}', "\u{c35}\u{c3e}"),
+  ('\u{c42}', "\u{c41}\u{c3e}"), ('\u{c44}', "\u{c43}\u{c3e}"), ('\u{c60}', "\u{c0b}\u{c3e}"), ('\u{c61}', "\u{c0c}\u{c3e}"), ('\u{c66}', "o"), ('\u{c81}', "\u{306}\u{307}"),
+  ('\u{c82}', "o"), ('\u{c83}', "\u{983}"), ('\u{c85}', "\u{c05}"), ('\u{c86}', "\u{c06}"), ('\u{c87}', "\u{c07}"), ('\u{c92}', "\u{c12}"),
+  ('\u{c93}', "\u{c12}\u{c55}"), ('\u{c94}', "\u{c12}\u{c4c}"), ('\u{c9c}', "\u{c1c}"), ('\u{c9e}', "\u{c1e}"), ('\u{ca3}', "\u{c23}"), ('\u{caf}', "\u{c2f}"),
+  ('\u{cb1}', "\u{c31}"), ('\u{cb2}', "\u{c32}"), ('\u{ce1}', "\u{c8c}\u{cbe}"), ('\u{ce6}', "o"), ('\u{ce7}', "\u{c67}"), ('\u{ce8}', "\u{c68}"),
+  ('\u{cef}', "\u{c6f}"), ('\u{d01}', "\u{306}\u{307}"), ('\u{d02}', "o"), ('\u{d03}', "\u{983}"), ('\u{d08}', "\u{d07}\u{d57}"), ('\u{d09}', "\u{b89}"),
+  ('\u{d0a}', "\u{b89}\u{d57}"), ('\u{d0c}', "\u{d28}\u{d41}"), ('\u{d10}', "\u{d0e}\u{d46}"), ('\u{d13}', "\u{d12}\u{d3e}"), ('\u{d14}', "\u{d12}\u{d57}"), ('\u{d19}', "\u{d28}\u{d41}"),
+  ('\u{d1c}', "\u{b90}"), ('\u{d20}', "o"), ('\u{d23}', "\u{ba3}"), ('\u{d31}', "\u{d30}"), ('\u{d34}', "\u{bb4}"), ('\u{d36}', "\u{bb6}"),
+  ('\u{d3a}', "\u{b9f}\u{bbf}"), ('\u{d3f}', "\u{bbf}"), ('\u{d40}', "\u{bbf}"), ('\u{d42}', "\u{d41}"), ('\u{d43}', "\u{d41}"), ('\u{d48}', "\u{d46}\u{d46}"),
+  ('\u{d4e}', "\u{971}"), ('\u{d5a}', "\u{d28}\u{d4d}\u{d2e}"), ('\u{d5f}', "o\u{d30}o"), ('\u{d61}', "\u{d1e}"), ('\u{d66}', "o"), ('\u{d6a}', "\u{d30}\u{d4d}"),
+  ('\u{d6b}', "\u{d26}\u{d4d}\u{d30}"), ('\u{d6c}', "\u{d28}\u{d4d}\u{d28}"), ('\u{d6d}', "9"), ('\u{d6e}', "\u{d35}\u{d4d}\u{d30}"), ('\u{d6f}', "\u{d28}\u{d4d}"), ('\u{d76}', "\u{d39}\u{d4d}\u{d2e}"),
+  ('\u{d79}', "\u{d28}\u{d41}"), ('\u{d7b}', "\u{d28}\u{d4d}"), ('\u{d7c}', "\u{d30}\u{d4d}"), ('\u{d82}', "o"), ('\u{d83}', "\u{983}"), ('\u{de9}', "\u{de8}\u{dcf}"),
+  ('\u{dea}', "\u{da2}"), ('\u{deb}', "\u{daf}"), ('\u{def}', "\u{de8}\u{dd3}"), ('\u{e03}', "\u{e02}"), ('\u{e0b}', "\u{e0a}"), ('\u{e0f}', "\u{e0e}"),
+  ('\u{e14}', "\u{e04}"), ('\u{e15}', "\u{e04}"), ('\u{e17}', "\u{e11}"), ('\u{e21}', "\u{e06}"), ('\u{e26}', "\u{e20}"), ('\u{e33}', "\u{30a}\u{e32}"),
+  ('\u{e41}', "\u{e40}\u{e40}"), ('\u{e45}', "\u{e32}"), ('\u{e4d}', "\u{30a}"), ('\u{e50}', "o"), ('\u{e88}', "\u{e08}"), ('\u{e8d}', "\u{e22}"),
+  ('\u{e9a}', "\u{e1a}"), ('\u{e9b}', "\u{e1b}"), ('\u{e9d}', "\u{e1d}"), ('\u{e9e}', "\u{e1e}"), ('\u{e9f}', "\u{e1f}"), ('\u{eb3}', "\u{30a}\u{eb2}"),
+  ('\u{eb8}', "\u{e38}"), ('\u{eb9}', "\u{e39}"), ('\u{ec8}', "\u{e48}"), ('\u{ec9}', "\u{e49}"), ('\u{eca}', "\u{e4a}"), ('\u{ecb}', "\u{e4b}"),
+  ('\u{ecd}', "\u{30a}"), ('\u{ed0}', "o"), ('\u{edc}', "\u{eab}\u{e99}"), ('\u{edd}', "\u{eab}\u{ea1}"), ('\u{f00}', "\u{f68}\u{f7c}\u{f7e}"), ('\u{f02}', "\u{f60}\u{f74}\u{f82}\u{f7f}"),
+  ('\u{f03}', "\u{f60}\u{f74}\u{f82}\u{f14}"), ('\u{f0c}', "\u{f0b}"), ('\u{f0e}', "\u{f0d}\u{f0d}"), ('\u{f1b}', "\u{f1a}\u{f1a}"), ('\u{f1e}', "\u{f1d}\u{f1d}"), ('\u{f1f}', "\u{f1a}\u{f1d}"),
+  ('\u{f37}', "\u{325}"), ('\u{f6a}', "\u{f62}"), ('\u{f77}', "\u{fb2}\u{f71}\u{f80}"), ('\u{f79}', "\u{fb3}\u{f71}\u{f80}"), ('\u{fce}', "\u{f1d}\u{f1a}"), ('\u{fd5}', "\u{5350}"),
+  ('\u{fd6}', "\u{534d}"), ('\u{1000}', "\u{1002}\u{102c}"), ('\u{1010}', "o\u{102c}"), ('\u{101d}', "o"), ('\u{101f}', "\u{1015}\u{102c}"), ('\u{1029}', "\u{101e}\u{103c}"),
+  ('\u{102a}', "\u{101e}\u{103c}\u{1031}\u{102c}\u{103a}"), ('\u{1036}', "\u{30a}"), ('\u{1038}', "\u{983}"), ('\u{1040}', "o"), ('\u{104b}', "\u{104a}\u{104a}"), ('\u{1065}', "\u{1041}"),
+  ('\u{1066}', "\u{1015}\u{103e}"), ('\u{106f}', "\u{1015}\u{102c}\u{103e}"), ('\u{1070}', "\u{1003}\u{103e}"), ('\u{107e}', "\u{107d}\u{103e}"), ('\u{1081}', "\u{1002}\u{103e}"), ('\u{109e}', "\u{1083}\u{30a}"),
+  ('\u{10a0}', "\u{a786}"), ('\u{10e7}', "y"), ('\u{10f3}', "\u{21d}"), ('\u{10ff}', "o"), ('\u{1101}', "\u{1100}\u{1100}"), ('\u{1104}', "\u{1103}\u{1103}"),
+  ('\u{1108}', "\u{1107}\u{1107}"), ('\u{110a}', "\u{1109}\u{1109}"), ('\u{110d}', "\u{110c}\u{110c}"), ('\u{1113}', "\u{1102}\u{1100}"), ('\u{1114}', "\u{1102}\u{1102}"), ('\u{1115}', "\u{1102}\u{1103}"),
+  ('\u{1116}', "\u{1102}\u{1107}"), ('\u{1117}', "\u{1103}\u{1100}"), ('\u{1118}', "\u{1105}\u{1102}"), ('\u{1119}', "\u{1105}\u{1105}"), ('\u{111a}', "\u{1105}\u{1112}"), ('\u{111b}', "\u{1105}\u{110b}"),
+  ('\u{111c}', "\u{1106}\u{1107}"), ('\u{111d}', "\u{1106}\u{110b}"), ('\u{111e}', "\u{1107}\u{1100}"), ('\u{111f}', "\u{1107}\u{1102}"), ('\u{1120}', "\u{1107}\u{1103}"), ('\u{1121}', "\u{1107}\u{1109}"),
+  ('\u{1122}', "\u{1107}\u{1109}\u{1100}"), ('\u{1123}', "\u{1107}\u{1109}\u{1103}"), ('\u{1124}', "\u{1107}\u{1109}\u{1107}"), ('\u{1125}', "\u{1107}\u{1109}\u{1109}"), ('\u{1126}', "\u{1107}\u{1109}\u{110c}"), ('\u{1127}', "\u{1107}\u{110c}"),
+  ('\u{1128}', "\u{1107}\u{110e}"), ('\u{1129}', "\u{1107}\u{1110}"), ('\u{112a}', "\u{1107}\u{1111}"), ('\u{112b}', "\u{1107}\u{110b}"), ('\u{112c}', "\u{1107}\u{1107}\u{110b}"), ('\u{112d}', "\u{1109}\u{1100}"),
+  ('\u{112e}', "\u{1109}\u{1102}"), ('\u{112f}', "\u{1109}\u{1103}"), ('\u{1130}', "\u{1109}\u{1105}"), ('\u{1131}', "\u{1109}\u{1106}"), ('\u{1132}', "\u{1109}\u{1107}"), ('\u{1133}', "\u{1109}\u{1107}\u{1100}"),
+  ('\u{1134}', "\u{1109}\u{1109}\u{1109}"), ('\u{1135}', "\u{1109}\u{110b}"), ('\u{1136}', "\u{1109}\u{110c}"), ('\u{1137}', "\u{1109}\u{110e}"), ('\u{1138}', "\u{1109}\u{110f}"), ('\u{1139}', "\u{1109}\u{1110}"),
+  ('\u{113a}', "\u{1109}\u{1111}"), ('\u{113b}', "\u{1105}\u{1112}"), ('\u{113d}', "\u{113c}\u{113c}"), ('\u{113f}', "\u{113e}\u{113e}"), ('\u{1141}', "\u{110b}\u{1100}"), ('\u{1142}', "\u{110b}\u{1103}"),
+  ('\u{1143}', "\u{110b}\u{1106}"), ('\u{1144}', "\u{110b}\u{1107}"), ('\u{1145}', "\u{110b}\u{1109}"), ('\u{1146}', "\u{110b}\u{1140}"), ('\u{1147}', "\u{110b}\u{110b}"), ('\u{1148}', "\u{110b}\u{110c}"),
+  ('\u{1149}', "\u{110b}\u{110e}"), ('\u{114a}', "\u{110b}\u{1110}"), ('\u{114b}', "\u{110b}\u{1111}"), ('\u{114d}', "\u{110c}\u{110b}"), ('\u{114f}', "\u{114e}\u{114e}"), ('\u{1151}', "\u{1150}\u{1150}"),
+  ('\u{1152}', "\u{110e}\u{110f}"), ('\u{1153}', "\u{110e}\u{1112}"), ('\u{1156}', "\u{1111}\u{1107}"), ('\u{1157}', "\u{1111}\u{110b}"), ('\u{1158}', "\u{1112}\u{1112}"), ('\u{115a}', "\u{1100}\u{1103}"),
+  ('\u{115b}', "\u{1102}\u{1109}"), ('\u{115c}', "\u{1102}\u{110c}"), ('\u{115d}', "\u{1102}\u{1112}"), ('\u{115e}', "\u{1103}\u{1105}"), ('\u{1162}', "\u{1161}\u{4e28}"), ('\u{1164}', "\u{1163}\u{4e28}"),
+  ('\u{1166}', "\u{1165}\u{4e28}"), ('\u{1168}', "\u{1167}\u{4e28}"), ('\u{116a}', "\u{1169}\u{1161}"), ('\u{116b}', "\u{1169}\u{1161}\u{4e28}"), ('\u{116c}', "\u{1169}\u{4e28}"), ('\u{116f}', "\u{116e}\u{1165}"),
+  ('\u{1170}', "\u{116e}\u{1165}\u{4e28}"), ('\u{1171}', "\u{116e}\u{4e28}"), ('\u{1173}', "\u{30fc}"), ('\u{1174}', "\u{30fc}\u{4e28}"), ('\u{1175}', "\u{4e28}"), ('\u{1176}', "\u{1161}\u{1169}"),
+  ('\u{1177}', "\u{1161}\u{116e}"), ('\u{1178}', "\u{1163}\u{1169}"), ('\u{1179}', "\u{1163}\u{116d}"), ('\u{117a}', "\u{1165}\u{1169}"), ('\u{117b}', "\u{1165}\u{116e}"), ('\u{117c}', "\u{1165}\u{30fc}"),
+  ('\u{117d}', "\u{1167}\u{1169}"), ('\u{117e}', "\u{1167}\u{116e}"), ('\u{117f}', "\u{1169}\u{1165}"), ('\u{1180}', "\u{1169}\u{1165}\u{4e28}"), ('\u{1181}', "\u{1169}\u{1167}\u{4e28}"), ('\u{1182}', "\u{1169}\u{1169}"),
+  ('\u{1183}', "\u{1169}\u{116e}"), ('\u{1184}', "\u{116d}\u{1163}"), ('\u{1185}', "\u{116d}\u{1163}\u{4e28}"), ('\u{1186}', "\u{116d}\u{1163}"), ('\u{1187}', "\u{116d}\u{1169}"), ('\u{1188}', "\u{116d}\u{4e28}"),
+  ('\u{1189}', "\u{116e}\u{1161}"), ('\u{118a}', "\u{116e}\u{1161}\u{4e28}"), ('\u{118b}', "\u{116e}\u{1165}\u{30fc}"), ('\u{118c}', "\u{116e}\u{1167}\u{4e28}"), ('\u{118d}', "\u{116e}\u{116e}"), ('\u{118e}', "\u{1172}\u{1161}"),
+  ('\u{118f}', "\u{1172}\u{1165}"), ('\u{1190}', "\u{1172}\u{1165}\u{4e28}"), ('\u{1191}', "\u{1172}\u{1167}"), ('\u{1192}', "\u{1172}\u{1167}\u{4e28}"), ('\u{1193}', "\u{1172}\u{116e}"), ('\u{1194}', "\u{1172}\u{4e28}"),
+  ('\u{1195}', "\u{30fc}\u{116e}"), ('\u{1196}', "\u{30fc}\u{30fc}"), ('\u{1197}', "\u{30fc}\u{4e28}\u{116e}"), ('\u{1198}', "\u{4e28}\u{1161}"), ('\u{1199}', "\u{4e28}\u{1163}"), ('\u{119a}', "\u{4e28}\u{1169}"),
+  ('\u{119b}', "\u{4e28}\u{116e}"), ('\u{119c}', "\u{4e28}\u{30fc}"), ('\u{119d}', "\u{4e28}\u{119e}"), ('\u{119f}', "\u{119e}\u{1165}"), ('\u{11a0}', "\u{119e}\u{116e}"), ('\u{11a1}', "\u{119e}\u{4e28}"),
+  ('\u{11a2}', "\u{119e}\u{119e}"), ('\u{11a3}', "\u{1161}\u{30fc}"), ('\u{11a4}', "\u{1163}\u{116e}"), ('\u{11a5}', "\u{1167}\u{1163}"), ('\u{11a6}', "\u{1169}\u{1163}"), ('\u{11a7}', "\u{1169}\u{1163}\u{4e28}"),
+  ('\u{11a8}', "\u{1100}"), ('\u{11a9}', "\u{1100}\u{1100}"), ('\u{11aa}', "\u{1100}\u{1109}"), ('\u{11ab}', "\u{1102}"), ('\u{11ac}', "\u{1102}\u{110c}"), ('\u{11ad}', "\u{1102}\u{1112}"),
+  ('\u{11ae}', "\u{1103}"), ('\u{11af}', "\u{1105}"), ('\u{11b0}', "\u{1105}\u{1100}"), ('\u{11b1}', "\u{1105}\u{1106}"), ('\u{11b2}', "\u{1105}\u{1107}"), ('\u{11b3}', "\u{1105}\u{1109}"),
+  ('\u{11b4}', "\u{1105}\u{1110}"), ('\u{11b5}', "\u{1105}\u{1111}"), ('\u{11b6}', "\u{1105}\u{1112}"), ('\u{11b7}', "\u{1106}"), ('\u{11b8}', "\u{1107}"), ('\u{11b9}', "\u{1107}\u{1109}"),
+  ('\u{11ba}', "\u{1109}"), ('\u{11bb}', "\u{1109}\u{1109}"), ('\u{11bc}', "\u{110b}"), ('\u{11bd}', "\u{110c}"), ('\u{11be}', "\u{110e}"), ('\u{11bf}', "\u{110f}"),
+  ('\u{11c0}', "\u{1110}"), ('\u{11c1}', "\u{1111}"), ('\u{11c2}', "\u{1112}"), ('\u{11c3}', "\u{1100}\u{1105}"), ('\u{11c4}', "\u{1100}\u{1109}\u{1100}"), ('\u{11c5}', "\u{1102}\u{1100}"),
+  ('\u{11c6}', "\u{1102}\u{1103}"), ('\u{11c7}', "\u{1102}\u{1109}"), ('\u{11c8}', "\u{1102}\u{1140}"), ('\u{11c9}', "\u{1102}\u{1110}"), ('\u{11ca}', "\u{1103}\u{1100}"), ('\u{11cb}', "\u{1103}\u{1105}"),
+  ('\u{11cc}', "\u{1105}\u{1100}\u{1109}"), ('\u{11cd}', "\u{1105}\u{1102}"), ('\u{11ce}', "\u{1105}\u{1103}"), ('\u{11cf}', "\u{1105}\u{1103}\u{1112}"), ('\u{11d0}', "\u{1105}\u{1105}"), ('\u{11d1}', "\u{1105}\u{1106}\u{1100}"),
+  ('\u{11d2}', "\u{1105}\u{1106}\u{1109}"), ('\u{11d3}', "\u{1105}\u{1107}\u{1109}"), ('\u{11d4}', "\u{1105}\u{1107}\u{1112}"), ('\u{11d5}', "\u{1105}\u{1107}\u{110b}"), ('\u{11d6}', "\u{1105}\u{1109}\u{1109}"), ('\u{11d7}', "\u{1105}\u{1140}"),
+  ('\u{11d8}', "\u{1105}\u{110f}"), ('\u{11d9}', "\u{1105}\u{1159}"), ('\u{11da}', "\u{1106}\u{1100}"), ('\u{11db}', "\u{1106}\u{1105}"), ('\u{11dc}', "\u{1106}\u{1107}"), ('\u{11dd}', "\u{1106}\u{1109}"),
+  ('\u{11de}', "\u{1106}\u{1109}\u{1109}"), ('\u{11df}', "\u{1106}\u{1140}"), ('\u{11e0}', "\u{1106}\u{110e}"), ('\u{11e1}', "\u{1106}\u{1112}"), ('\u{11e2}', "\u{1106}\u{110b}"), ('\u{11e3}', "\u{1107}\u{1105}"),
+  ('\u{11e4}', "\u{1107}\u{1111}"), ('\u{11e5}', "\u{1107}\u{1112}"), ('\u{11e6}', "\u{1107}\u{110b}"), ('\u{11e7}', "\u{1109}\u{1100}"), ('\u{11e8}', "\u{1109}\u{1103}"), ('\u{11e9}', "\u{1109}\u{1105}"),
+  ('\u{11ea}', "\u{1109}\u{1107}"), ('\u{11eb}', "\u{1140}"), ('\u{11ec}', "\u{110b}\u{1100}"), ('\u{11ed}', "\u{110b}\u{1100}\u{1100}"), ('\u{11ee}', "\u{110b}\u{110b}"), ('\u{11ef}', "\u{110b}\u{110f}"),
+  ('\u{11f0}', "\u{114c}"), ('\u{11f1}', "\u{110b}\u{1109}"), ('\u{11f2}', "\u{110b}\u{1140}"), ('\u{11f3}', "\u{1111}\u{1107}"), ('\u{11f4}', "\u{1111}\u{110b}"), ('\u{11f5}', "\u{1112}\u{1102}"),
+  ('\u{11f6}', "\u{1112}\u{1105}"), ('\u{11f7}', "\u{1112}\u{1106}"), ('\u{11f8}', "\u{1112}\u{1107}"), ('\u{11f9}', "\u{1159}"), ('\u{11fa}', "\u{1100}\u{1102}"), ('\u{11fb}', "\u{1100}\u{1107}"),
+  ('\u{11fc}', "\u{1100}\u{110e}"), ('\u{11fd}', "\u{1100}\u{110f}"), ('\u{11fe}', "\u{1100}\u{1112}"), ('\u{11ff}', "\u{1102}\u{1102}"), ('\u{1200}', "U"), ('\u{1223}', "\u{270}"),
+  ('\u{1240}', "\u{3a6}"), ('\u{1260}', "\u{548}"), ('\u{1294}', "\u{571}"), ('\u{12d0}', "O"), ('\u{13a0}', "D"), ('\u{13a1}', "R"),
+  ('\u{13a2}', "T"), ('\u{13a4}', "O'"), ('\u{13a5}', "i"), ('\u{13a8}', "\u{2c75}"), ('\u{13a9}', "Y"), ('\u{13aa}', "A"),
+  ('\u{13ab}', "J"), ('\u{13ac}', "E"), ('\u{13ae}', "?"), ('\u{13b0}', "\u{2c75}"), ('\u{13b1}', "\u{393}"), ('\u{13b3}', "W"),
+  ('\u{13b7}', "M"), ('\u{13bb}', "H"), ('\u{13bd}', "Y"), ('\u{13be}', "O\u{335}"), ('\u{13bf}', "\u{1ab}"), ('\u{13c0}', "G"),
+  ('\u{13c2}', "h"), ('\u{13c3}', "Z"), ('\u{13c7}', "\u{460}"), ('\u{13cb}', "\u{190}"), ('\u{13cc}', "U\u{335}"), ('\u{13ce}', "4"),
+  ('\u{13cf}', "b"), ('\u{13d2}', "R"), ('\u{13d4}', "W"), ('\u{13d5}', "S"), ('\u{13d9}', "V"), ('\u{13da}', "S"),
+  ('\u{13de}', "L"), ('\u{13df}', "C"), ('\u{13e2}', "P"), ('\u{13e6}', "K"), ('\u{13e7}', "d"), ('\u{13eb}', "O\u{335}"),
+  ('\u{13ee}', "6"), ('\u{13f0}', "\u{df}"), ('\u{13f2}', "h\u{314}"), ('\u{13f3}', "G"), ('\u{13f4}', "B"), ('\u{13fb}', "\u{262}"),
+  ('\u{13fc}', "\u{299}"), ('\u{1400}', "="), ('\u{1403}', "\u{394}"), ('\u{140c}', "\u{b7}\u{1401}"), ('\u{140d}', "\u{1401}\u{b7}"), ('\u{140e}', "\u{b7}\u{394}"),
+  ('\u{140f}', "\u{394}\u{b7}"), ('\u{1410}', "\u{b7}\u{1404}"), ('\u{1411}', "\u{1404}\u{b7}"), ('\u{1412}', "\u{b7}\u{1405}"), ('\u{1413}', "\u{1405}\u{b7}"), ('\u{1414}', "\u{b7}\u{1406}"),
+  ('\u{1415}', "\u{1406}\u{b7}"), ('\u{1417}', "\u{b7}\u{140a}"), ('\u{1418}', "\u{140a}\u{b7}"), ('\u{1419}', "\u{b7}\u{140b}"), ('\u{141a}', "\u{140b}\u{b7}"), ('\u{1427}', "\u{b7}"),
+  ('\u{142b}', "\u{1401}\u{1420}"), ('\u{142c}', "\u{394}\u{1420}"), ('\u{142d}', "\u{1405}\u{1420}"), ('\u{142e}', "\u{140a}\u{1420}"), ('\u{142f}', "V"), ('\u{1431}', "\u{245}"),
+  ('\u{1433}', ">"), ('\u{1437}', "\u{b7}>"), ('\u{1438}', "<"), ('\u{143a}', "\u{b7}V"), ('\u{143b}', "V\u{b7}"), ('\u{143c}', "\u{b7}\u{245}"),
+  ('\u{143d}', "\u{245}\u{b7}"), ('\u{143e}', "\u{b7}\u{1432}"), ('\u{143f}', "\u{1432}\u{b7}"), ('\u{1440}', "\u{b7}>"), ('\u{1441}', ">\u{b7}"), ('\u{1442}', "\u{b7}\u{1434}"),
+  ('\u{1443}', "\u{1434}\u{b7}"), ('\u{1444}', "\u{b7}<"), ('\u{1445}', "<\u{b7}"), ('\u{1446}', "\u{b7}\u{1439}"), ('\u{1447}', "\u{1439}\u{b7}"), ('\u{144a}', "'"),
+  ('\u{144c}', "U"), ('\u{144e}', "\u{548}"), ('\u{1454}', "\u{b7}\u{1450}"), ('\u{1457}', "\u{b7}U"), ('\u{1458}', "U\u{b7}"), ('\u{1459}', "\u{b7}\u{548}"),
+  ('\u{145a}', "\u{548}\u{b7}"), ('\u{145b}', "\u{b7}\u{144f}"), ('\u{145c}', "\u{144f}\u{b7}"), ('\u{145d}', "\u{b7}\u{1450}"), ('\u{145e}', "\u{1450}\u{b7}"), ('\u{145f}', "\u{b7}\u{1451}"),
+  ('\u{1460}', "\u{1451}\u{b7}"), ('\u{1461}', "\u{b7}\u{1455}"), ('\u{1462}', "\u{1455}\u{b7}"), ('\u{1463}', "\u{b7}\u{1456}"), ('\u{1464}', "\u{1456}\u{b7}"), ('\u{1467}', "U'"),
+  ('\u{1468}', "\u{548}'"), ('\u{1469}', "\u{1450}'"), ('\u{146a}', "\u{1455}'"), ('\u{146d}', "P"), ('\u{146f}', "d"), ('\u{1472}', "b"),
+  ('\u{1473}', "b\u{307}"), ('\u{1474}', "\u{b7}\u{146b}"), ('\u{1475}', "\u{146b}\u{b7}"), ('\u{1476}', "\u{b7}P"), ('\u{1477}', "p\u{b7}"), ('\u{1478}', "\u{b7}\u{146e}"),
+  ('\u{1479}', "\u{146e}\u{b7}"), ('\u{147a}', "\u{b7}d"), ('\u{147b}', "d\u{b7}"), ('\u{147c}', "\u{b7}\u{1470}"), ('\u{147d}', "\u{1470}\u{b7}"), ('\u{147e}', "\u{b7}b"),
+  ('\u{147f}', "b\u{b7}"), ('\u{1480}', "\u{b7}b\u{307}"), ('\u{1481}', "b\u{307}\u{b7}"), ('\u{1485}', "\u{146b}'"), ('\u{1486}', "P'"), ('\u{1487}', "d'"),
+  ('\u{1488}', "b'"), ('\u{148d}', "J"), ('\u{1492}', "\u{b7}\u{1489}"), ('\u{1493}', "\u{1489}\u{b7}"), ('\u{1494}', "\u{b7}\u{148b}"), ('\u{1495}', "\u{148b}\u{b7}"),
+  ('\u{1496}', "\u{b7}\u{148c}"), ('\u{1497}', "\u{148c}\u{b7}"), ('\u{1498}', "\u{b7}J"), ('\u{1499}', "J\u{b7}"), ('\u{149a}', "\u{b7}\u{148e}"), ('\u{149b}', "\u{148e}\u{b7}"),
+  ('\u{149c}', "\u{b7}\u{1490}"), ('\u{149d}', "\u{1490}\u{b7}"), ('\u{149e}', "\u{b7}\u{1491}"), ('\u{149f}', "\u{1491}\u{b7}"), ('\u{14a5}', "\u{393}"), ('\u{14aa}', "L"),
+  ('\u{14ac}', "\u{b7}\u{14a3}"), ('\u{14ad}', "\u{14a3}\u{b7}"), ('\u{14ae}', "\u{b7}\u{393}"), ('\u{14af}', "\u{393}\u{b7}"), ('\u{14b0}', "\u{b7}\u{14a6}"), ('\u{14b1}', "\u{14a6}\u{b7}"),
+  ('\u{14b2}', "\u{b7}\u{14a7}"), ('\u{14b3}', "\u{14a7}\u{b7}"), ('\u{14b4}', "\u{b7}\u{14a8}"), ('\u{14b5}', "\u{14a8}\u{b7}"), ('\u{14b6}', "\u{b7}L"), ('\u{14b7}', "l\u{b7}"),
+  ('\u{14b8}', "\u{b7}\u{14ab}"), ('\u{14b9}', "\u{14ab}\u{b7}"), ('\u{14bf}', "2"), ('\u{14c9}', "\u{b7}\u{14c0}"), ('\u{14ca}', "\u{14c0}\u{b7}"), ('\u{14cb}', "\u{b7}\u{14c7}"),
+  ('\u{14cc}', "\u{14c7}\u{b7}"), ('\u{14cd}', "\u{b7}\u{14c8}"), ('\u{14ce}', "\u{14c8}\u{b7}"), ('\u{14d1}', "\u{1421}"), ('\u{14dc}', "\u{b7}\u{14d3}"), ('\u{14dd}', "\u{14d3}\u{b7}"),
+  ('\u{14de}', "\u{b7}\u{14d5}"), ('\u{14df}', "\u{14d5}\u{b7}"), ('\u{14e0}', "\u{b7}\u{14d6}"), ('\u{14e1}', "\u{14d6}\u{b7}"), ('\u{14e2}', "\u{b7}\u{14d7}"), ('\u{14e3}', "\u{14d7}\u{b7}"),
+  ('\u{14e4}', "\u{b7}\u{14d8}"), ('\u{14e5}', "\u{14d8}\u{b7}"), ('\u{14e6}', "\u{b7}\u{14da}"), ('\u{14e7}', "\u{14da}\u{b7}"), ('\u{14e8}', "\u{b7}\u{14db}"), ('\u{14e9}', "\u{14db}\u{b7}"),
+  ('\u{14f6}', "\u{b7}\u{14ed}"), ('\u{14f7}', "\u{14ed}\u{b7}"), ('\u{14f8}', "\u{b7}\u{14ef}"), ('\u{14f9}', "\u{14ef}\u{b7}"), ('\u{14fa}', "\u{b7}\u{14f0}"), ('\u{14fb}', "\u{14f0}\u{b7}"),
+  ('\u{14fc}', "\u{b7}\u{14f1}"), ('\u{14fd}', "\u{14f1}\u{b7}"), ('\u{14fe}', "\u{b7}\u{14f2}"), ('\u{14ff}', "\u{14f2}\u{b7}"), ('\u{1500}', "\u{b7}\u{14f4}"), ('\u{1501}', "\u{14f4}\u{b7}"),
+  ('\u{1502}', "\u{b7}\u{14f5}"), ('\u{1503}', "\u{14f5}\u{b7}"), ('\u{150c}', "\u{150b}<"), ('\u{150d}', "\u{150b}\u{1455}"), ('\u{150e}', "\u{150b}b"), ('\u{150f}', "\u{150b}\u{1490}"),
+  ('\u{1517}', "\u{b7}\u{1510}"), ('\u{1518}', "\u{1510}\u{b7}"), ('\u{1519}', "\u{b7}\u{1511}"), ('\u{151a}', "\u{1511}\u{b7}"), ('\u{151b}', "\u{b7}\u{1512}"), ('\u{151c}', "\u{1512}\u{b7}"),
+  ('\u{151d}', "\u{b7}\u{1513}"), ('\u{151e}', "\u{1513}\u{b7}"), ('\u{151f}', "\u{b7}\u{1514}"), ('\u{1520}', "\u{1514}\u{b7}"), ('\u{1521}', "\u{b7}\u{1515}"), ('\u{1522}', "\u{1515}\u{b7}"),
+  ('\u{1523}', "\u{b7}\u{1516}"), ('\u{1524}', "\u{1516}\u{b7}"), ('\u{152f}', "\u{b7}4"), ('\u{1530}', "4\u{b7}"), ('\u{1531}', "\u{b7}\u{1528}"), ('\u{1532}', "\u{1528}\u{b7}"),
+  ('\u{1533}', "\u{b7}\u{1529}"), ('\u{1534}', "\u{1529}\u{b7}"), ('\u{1535}', "\u{b7}\u{152a}"), ('\u{1536}', "\u{152a}\u{b7}"), ('\u{1537}', "\u{b7}\u{152b}"), ('\u{1538}', "\u{152b}\u{b7}"),
+  ('\u{1539}', "\u{b7}\u{152d}"), ('\u{153a}', "\u{152d}\u{b7}"), ('\u{153b}', "\u{b7}\u{152e}"), ('\u{153c}', "\u{152e}\u{b7}"), ('\u{1540}', "\u{1429}"), ('\u{1541}', "x"),
+  ('\u{154e}', "\u{b7}\u{154c}"), ('\u{154f}', "\u{154c}\u{b7}"), ('\u{155b}', "\u{b7}\u{155a}"), ('\u{155c}', "\u{155a}\u{b7}"), ('\u{1568}', "\u{b7}\u{1567}"), ('\u{1569}', "\u{1567}\u{b7}"),
+  ('\u{1577}', "\u{1e9f}"), ('\u{157c}', "H"), ('\u{157d}', "x"), ('\u{157e}', "\u{1550}\u{146c}"), ('\u{157f}', "\u{1550}P"), ('\u{1580}', "\u{1550}\u{146e}"),
+  ('\u{1581}', "\u{1550}d"), ('\u{1582}', "\u{1550}\u{1470}"), ('\u{1583}', "\u{1550}b"), ('\u{1584}', "\u{1550}b\u{307}"), ('\u{1585}', "\u{1550}\u{1483}"), ('\u{1587}', "R"),
+  ('\u{158e}', "\u{1595}\u{148a}"), ('\u{158f}', "\u{1595}\u{148b}"), ('\u{1590}', "\u{1595}\u{148c}"), ('\u{1591}', "\u{1595}J"), ('\u{1592}', "\u{1595}\u{148e}"), ('\u{1593}', "\u{1595}\u{1490}"),
+  ('\u{1594}', "\u{1595}\u{1491}"), ('\u{15af}', "b"), ('\u{15b4}', "F"), ('\u{15b5}', "\u{2132}"), ('\u{15b7}', "\u{a7fb}"), ('\u{15c4}', "\u{2c6f}"),
+  ('\u{15c5}', "A"), ('\u{15de}', "D"), ('\u{15ea}', "D"), ('\u{15ef}', "\u{460}"), ('\u{15f0}', "M"), ('\u{15f7}', "B"),
+  ('\u{1602}', "\u{1490}"), ('\u{1603}', "\u{1489}"), ('\u{1604}', "\u{14d3}"), ('\u{1607}', "\u{14da}"), ('\u{1622}', "\u{1543}"), ('\u{1623}', "\u{1546}"),
+  ('\u{1624}', "\u{154a}"), ('\u{162e}', "\u{1b1}"), ('\u{162f}', "\u{3a9}"), ('\u{1634}', "\u{1b1}"), ('\u{1635}', "\u{3a9}"), ('\u{166d}', "X"),
+  ('\u{166e}', "x"), ('\u{166f}', "\u{1550}\u{146b}"), ('\u{1670}', "\u{1595}\u{1489}"), ('\u{1671}', "\u{1596}\u{148b}"), ('\u{1672}', "\u{1596}\u{148c}"), ('\u{1673}', "\u{1596}J"),
+  ('\u{1674}', "\u{1596}\u{148e}"), ('\u{1675}', "\u{1596}\u{1490}"), ('\u{1676}', "\u{1596}\u{1491}"), ('\u{1677}', "\u{15a7}\u{b7}"), ('\u{1678}', "\u{15a8}\u{b7}"), ('\u{1679}', "\u{15a9}\u{b7}"),
+  ('\u{167a}', "\u{15aa}\u{b7}"), ('\u{167b}', "\u{15ab}\u{b7}"), ('\u{167c}', "\u{15ac}\u{b7}"), ('\u{167d}', "\u{15ad}\u{b7}"), ('\u{1680}', " "), ('\u{16b2}', "<"),
+  ('\u{16b7}', "X"), ('\u{16c1}', "l"), ('\u{16c2}', "\u{16bd}"), ('\u{16cc}', "'"), ('\u{16d5}', "K"), ('\u{16d6}', "M"),
+  ('\u{16d8}', "\u{3a8}"), ('\u{16e1}', "\u{16bc}"), ('\u{16eb}', "\u{b7}"), ('\u{16ec}', ":"), ('\u{16ed}', "+"), ('\u{16f0}', "\u{3a6}"),
+  ('\u{1734}', "\u{1715}"), ('\u{1735}', "/"), ('\u{17a3}', "\u{17a2}"), ('\u{17b7}', "\u{e34}"), ('\u{17b8}', "\u{e35}"), ('\u{17b9}', "\u{e36}"),
+  ('\u{17ba}', "\u{e37}"), ('\u{17c6}', "\u{30a}"), ('\u{17cb}', "\u{e48}"), ('\u{17d3}', "\u{30a}"), ('\u{17d4}', "\u{e2f}"), ('\u{17d5}', "\u{e5a}"),
+  ('\u{17d9}', "\u{e4f}"), ('\u{17da}', "\u{e5b}"), ('\u{1803}', ":"), ('\u{1809}', ":"), ('\u{1855}', "\u{1835}"), ('\u{1896}', "\u{185c}"),
+  ('\u{18b3}', "\u{b7}\u{18b1}"), ('\u{18b6}', "\u{b7}\u{18b4}"), ('\u{18b9}', "\u{b7}\u{18b8}"), ('\u{18c2}', "\u{b7}\u{18c0}"), ('\u{18c6}', "\u{b7}\u{14c2}"), ('\u{18c7}', "\u{14c2}\u{b7}"),
+  ('\u{18c8}', "\u{b7}\u{14c3}"), ('\u{18c9}', "\u{14c3}\u{b7}"), ('\u{18ca}', "\u{b7}\u{14c4}"), ('\u{18cb}', "\u{14c4}\u{b7}"), ('\u{18cc}', "\u{b7}\u{14c5}"), ('\u{18cd}', "\u{14c5}\u{b7}"),
+  ('\u{18ce}', "\u{b7}\u{1543}"), ('\u{18cf}', "\u{b7}\u{1546}"), ('\u{18d0}', "\u{b7}\u{1547}"), ('\u{18d1}', "\u{b7}\u{1548}"), ('\u{18d2}', "\u{b7}\u{1549}"), ('\u{18d3}', "\u{b7}\u{154b}"),
+  ('\u{18db}', "\u{18f5}"), ('\u{18dc}', "\u{18df}\u{141e}"), ('\u{18dd}', "\u{141e}\u{18df}"), ('\u{18e0}', "\u{1543}\u{b7}"), ('\u{18e3}', "\u{155e}\u{b7}"), ('\u{18e4}', "\u{1566}\u{b7}"),
+  ('\u{18e5}', "\u{156b}\u{b7}"), ('\u{18e8}', "\u{1586}\u{b7}"), ('\u{18ea}', "\u{1597}\u{b7}"), ('\u{18ed}', "\u{460}\u{b7}"), ('\u{18f0}', "\u{15f4}\u{b7}"), ('\u{18f2}', "\u{161b}\u{b7}"),
+  ('\u{19d0}', "\u{199e}"), ('\u{19d1}', "\u{19b1}"), ('\u{1a80}', "\u{1a45}"), ('\u{1a90}', "\u{1a45}"), ('\u{1aa9}', "\u{1aa8}\u{1aa8}"), ('\u{1aab}', "\u{1aaa}\u{1aa8}"),
+  ('\u{1ab4}', "\u{6db}"), ('\u{1ab7}', "\u{328}"), ('\u{1b52}', "\u{1b0d}"), ('\u{1b53}', "\u{1b11}"), ('\u{1b58}', "\u{1b28}"), ('\u{1b5c}', "\u{1b50}"),
+  ('\u{1b5f}', "\u{1b5e}\u{1b5e}"), ('\u{1c3c}', "\u{1c3b}\u{1c3b}"), ('\u{1c7f}', "\u{1c7e}\u{1c7e}"), ('\u{1cd0}', "\u{302}"), ('\u{1cd2}', "\u{304}"), ('\u{1cd3}', "''"),
+  ('\u{1cd5}', "\u{32b}"), ('\u{1cd8}', "\u{32e}"), ('\u{1cd9}', "\u{32d}"), ('\u{1cda}', "\u{30e}"), ('\u{1cdc}', "\u{329}"), ('\u{1cdd}', "\u{323}"),
+  ('\u{1cde}', "\u{324}"), ('\u{1ced}', "\u{316}"), ('\u{1d04}', "c"), ('\u{1d08}', "\u{25c}"), ('\u{1d0b}', "\u{138}"), ('\u{1d0d}', "\u{28d}"),
+  ('\u{1d0f}', "o"), ('\u{1d10}', "\u{254}"), ('\u{1d11}', "o"), ('\u{1d14}', "\u{1dd}o"), ('\u{1d1c}', "u"), ('\u{1d20}', "v"),
+  ('\u{1d21}', "w"), ('\u{1d22}', "z"), ('\u{1d24}', "\u{1a8}"), ('\u{1d26}', "r"), ('\u{1d27}', "\u{28c}"), ('\u{1d28}', "\u{3c0}"),
+  ('\u{1d29}', "\u{1d18}"), ('\u{1d2b}', "\u{43b}"), ('\u{1d3e}', "\u{18d6}"), ('\u{1d52}', "\u{ba}"), ('\u{1d6b}', "ue"), ('\u{1d6e}', "f\u{334}"),
+  ('\u{1d6f}', "rn\u{334}"), ('\u{1d70}', "n\u{334}"), ('\u{1d72}', "r\u{334}"), ('\u{1d73}', "\u{27e}\u{334}"), ('\u{1d74}', "s\u{334}"), ('\u{1d75}', "t\u{334}"),
+  ('\u{1d76}', "z\u{334}"), ('\u{1d78}', "\u{1d34}"), ('\u{1d7b}', "i\u{335}"), ('\u{1d7c}', "i\u{335}"), ('\u{1d7d}', "p\u{335}"), ('\u{1d7e}', "u\u{335}"),
+  ('\u{1d7f}', "\u{28a}\u{335}"), ('\u{1d83}', "g"), ('\u{1d8c}', "y"), ('\u{1d90}', "\u{24b}"), ('\u{1d9f}', "\u{1d4b}"), ('\u{1da2}', "\u{1d4d}"),
+  ('\u{1dba}', "\u{18d4}"), ('\u{1dbb}', "\u{1646}"), ('\u{1dee}', "\u{2dec}"), ('\u{1e43}', "\u{ab51}"), ('\u{1e9a}', "\u{1ea3}"), ('\u{1e9d}', "f"),
+  ('\u{1e9e}', "\u{df}"), ('\u{1eff}', "y"), ('\u{1f7d}', "\u{1ff4}"), ('\u{1fbd}', "'"), ('\u{1fbe}', "i"), ('\u{1fbf}', "'"),
+  ('\u{1fc0}', "~"), ('\u{1fef}', "'"), ('\u{1ff6}', "\u{13ef}"), ('\u{1ffd}', "'"), ('\u{1ffe}', "'"), ('\u{2000}', " "),
+  ('\u{2001}', " "), ('\u{2002}', " "), ('\u{2003}', " "), ('\u{2004}', " "), ('\u{2005}', " "), ('\u{2006}', " "),
+  ('\u{2007}', " "), ('\u{2008}', " "), ('\u{2009}', " "), ('\u{200a}', " "), ('\u{2010}', "-"), ('\u{2011}', "-"),
+  ('\u{2012}', "-"), ('\u{2013}', "-"), ('\u{2014}', "\u{30fc}"), ('\u{2015}', "\u{30fc}"), ('\u{2016}', "ll"), ('\u{2018}', "'"),
+  ('\u{2019}', "'"), ('\u{201a}', ","), ('\u{201b}', "'"), ('\u{201c}', "''"), ('\u{201d}', "''"), ('\u{201f}', "''"),
+  ('\u{2022}', "\u{b7}"), ('\u{2024}', "."), ('\u{2025}', ".."), ('\u{2026}', "..."), ('\u{2027}', "\u{b7}"), ('\u{2028}', " "),
+  ('\u{2029}', " "), ('\u{202f}', " "), ('\u{2030}', "\u{ba}/\u{2080}\u{2080}"), ('\u{2031}', "\u{ba}/\u{2080}\u{2080}\u{2080}"), ('\u{2032}', "'"), ('\u{2033}', "''"),
+  ('\u{2034}', "'''"), ('\u{2035}', "'"), ('\u{2036}', "''"), ('\u{2037}', "'''"), ('\u{2039}', "<"), ('\u{203a}', ">"),
+  ('\u{203c}', "!!"), ('\u{203e}', "\u{2c9}"), ('\u{2041}', "/"), ('\u{2043}', "-"), ('\u{2044}', "/"), ('\u{2047}', "??"),
+  ('\u{2048}', "?!"), ('\u{2049}', "!?"), ('\u{204e}', "*"), ('\u{2052}', "\u{ba}/\u{2080}"), ('\u{2053}', "~"), ('\u{2057}', "''''"),
+  ('\u{205a}', ":"), ('\u{205d}', "\u{2d57}"), ('\u{205e}', "\u{2d42}"), ('\u{205f}', " "), ('\u{2070}', "\u{ba}"), ('\u{2079}', "\u{a770}"),
+  ('\u{20a1}', "C\u{20eb}"), ('\u{20a4}', "\u{a3}"), ('\u{20a5}', "rn\u{338}"), ('\u{20a8}', "Rs"), ('\u{20a9}', "W\u{335}"), ('\u{20ab}', "d\u{335}\u{331}"),
+  ('\u{20ac}', "\u{a792}"), ('\u{20ad}', "K\u{335}"), ('\u{20ae}', "T\u{20eb}"), ('\u{20b6}', "lt"), ('\u{20bd}', "\u{554}"), ('\u{20db}', "\u{6db}"),
+  ('\u{2100}', "a/c"), ('\u{2101}', "a/s"), ('\u{2102}', "C"), ('\u{2103}', "\u{b0}C"), ('\u{2105}', "c/o"), ('\u{2106}', "c/u"),
+  ('\u{2107}', "\u{190}"), ('\u{2108}', "\u{42d}"), ('\u{2109}', "\u{b0}F"), ('\u{210a}', "g"), ('\u{210b}', "H"), ('\u{210c}', "H"),
+  ('\u{210d}', "H"), ('\u{210e}', "h"), ('\u{210f}', "h\u{335}"), ('\u{2110}', "l"), ('\u{2111}', "l"), ('\u{2112}', "L"),
+  ('\u{2113}', "l"), ('\u{2115}', "N"), ('\u{2116}', "No"), ('\u{2119}', "P"), ('\u{211a}', "Q"), ('\u{211b}', "R"),
+  ('\u{211c}', "R"), ('\u{211d}', "R"), ('\u{2121}', "TEL"), ('\u{2124}', "Z"), ('\u{2126}', "\u{3a9}"), ('\u{2127}', "\u{1b1}"),
+  ('\u{2128}', "Z"), ('\u{2129}', "\u{27f}"), ('\u{212a}', "K"), ('\u{212c}', "B"), ('\u{212d}', "C"), ('\u{212e}', "e"),
+  ('\u{212f}', "e"), ('\u{2130}', "E"), ('\u{2131}', "F"), ('\u{2133}', "M"), ('\u{2134}', "o"), ('\u{2135}', "\u{5d0}"),
+  ('\u{2136}', "\u{5d1}"), ('\u{2137}', "\u{5d2}"), ('\u{2138}', "\u{5d3}"), ('\u{2139}', "i"), ('\u{213b}', "FAX"), ('\u{213c}', "\u{3c0}"),
+  ('\u{213d}', "y"), ('\u{213e}', "\u{393}"), ('\u{213f}', "\u{3a0}"), ('\u{2140}', "\u{1a9}"), ('\u{2141}', "\u{a4e8}"), ('\u{2142}', "\u{a4f6}"),
+  ('\u{2143}', "\u{16f00}"), ('\u{2145}', "D"), ('\u{2146}', "d"), ('\u{2147}', "e"), ('\u{2148}', "i"), ('\u{2149}', "j"),
+  ('\u{2160}', "l"), ('\u{2161}', "ll"), ('\u{2162}', "lll"), ('\u{2163}', "lV"), ('\u{2164}', "V"), ('\u{2165}', "Vl"),
+  ('\u{2166}', "Vll"), ('\u{2167}', "Vlll"), ('\u{2168}', "lX"), ('\u{2169}', "X"), ('\u{216a}', "Xl"), ('\u{216b}', "Xll"),
+  ('\u{216c}', "L"), ('\u{216d}', "C"), ('\u{216e}', "D"), ('\u{216f}', "M"), ('\u{2170}', "i"), ('\u{2171}', "ii"),
+  ('\u{2172}', "iii"), ('\u{2173}', "iv"), ('\u{2174}', "v"), ('\u{2175}', "vi"), ('\u{2176}', "vii"), ('\u{2177}', "viii"),
+  ('\u{2178}', "ix"), ('\u{2179}', "x"), ('\u{217a}', "xi"), ('\u{217b}', "xii"), ('\u{217c}', "l"), ('\u{217d}', "c"),
+  ('\u{217e}', "d"), ('\u{217f}', "rn"), ('\u{2183}', "\u{186}"), ('\u{2184}', "\u{254}"), ('\u{2191}', "\u{16cf}"), ('\u{2195}', "\u{16e8}"),
+  ('\u{21b5}', "\u{21b2}"), ('\u{21ba}', "\u{1f10e}"), ('\u{21be}', "\u{16da}"), ('\u{21bf}', "\u{16d0}"), ('\u{2200}', "\u{2c6f}"), ('\u{2203}', "\u{18e}"),
+  ('\u{2206}', "\u{394}"), ('\u{220f}', "\u{3a0}"), ('\u{2211}', "\u{1a9}"), ('\u{2212}', "-"), ('\u{2214}', "+\u{307}"), ('\u{2215}', "/"),
+  ('\u{2216}', "\u{5c}"), ('\u{2217}', "*"), ('\u{2218}', "\u{b0}"), ('\u{2219}', "\u{b7}"), ('\u{221e}', "oo"), ('\u{2223}', "l"),
+  ('\u{2225}', "ll"), ('\u{2228}', "v"), ('\u{2229}', "\u{548}"), ('\u{222a}', "U"), ('\u{222b}', "\u{283}"), ('\u{222c}', "\u{283}\u{283}"),
+  ('\u{222d}', "\u{283}\u{283}\u{283}"), ('\u{222f}', "\u{222e}\u{222e}"), ('\u{2230}', "\u{222e}\u{222e}\u{222e}"), ('\u{2236}', ":"), ('\u{2238}', "-\u{307}"), ('\u{223c}', "~"),
+  ('\u{2250}', "=\u{307}"), ('\u{2251}', "=\u{307}\u{323}"), ('\u{2257}', "=\u{30a}"), ('\u{2259}', "=\u{302}"), ('\u{225a}', "=\u{306}"), ('\u{225e}', "=\u{36b}"),
+  ('\u{2263}', "\u{2261}"), ('\u{226a}', "<<"), ('\u{226b}', ">>"), ('\u{2282}', "\u{1455}"), ('\u{2283}', "\u{1450}"), ('\u{2295}', "\u{102a8}"),
+  ('\u{2296}', "O\u{335}"), ('\u{2299}', "\u{298}"), ('\u{229d}', "O\u{335}"), ('\u{22a4}', "T"), ('\u{22a5}', "\u{a4d5}"), ('\u{22c0}', "\u{2227}"),
+  ('\u{22c1}', "v"), ('\u{22c2}', "\u{548}"), ('\u{22c3}', "U"), ('\u{22c4}', "\u{16dc}"), ('\u{22c5}', "\u{b7}"), ('\u{22c8}', "\u{16de}"),
+  ('\u{22d6}', "<\u{b7}"), ('\u{22d7}', "\u{b7}>"), ('\u{22d8}', "<<<"), ('\u{22d9}', ">>>"), ('\u{22ee}', "\u{2d57}"), ('\u{22ef}', "\u{b7}\u{b7}\u{b7}"),
+  ('\u{22f4}', "\u{a793}"), ('\u{22ff}', "E"), ('\u{2300}', "\u{2205}"), ('\u{2325}', "\u{2324}"), ('\u{2329}', "\u{276c}"), ('\u{232a}', "\u{276d}"),
+  ('\u{2341}', "\u{303c}"), ('\u{2359}', "\u{394}\u{332}"), ('\u{235a}', "\u{16dc}\u{332}"), ('\u{235c}', "\u{b0}\u{332}"), ('\u{235f}', "\u{229b}"), ('\u{2361}', "T\u{308}"),
+  ('\u{2362}', "\u{2207}\u{308}"), ('\u{2363}', "\u{22c6}\u{308}"), ('\u{2364}', "\u{b0}\u{308}"), ('\u{2365}', "\u{629}"), ('\u{2368}', "~\u{308}"), ('\u{2369}', "\u{1435}"),
+  ('\u{236b}', "\u{2207}\u{334}"), ('\u{236c}', "O\u{335}"), ('\u{2373}', "i"), ('\u{2374}', "p"), ('\u{2375}', "\u{3c9}"), ('\u{2376}', "a\u{332}"),
+  ('\u{2377}', "\u{a793}\u{332}"), ('\u{2378}', "i\u{332}"), ('\u{2379}', "\u{3c9}\u{332}"), ('\u{237a}', "a"), ('\u{237f}', "\u{16bd}"), ('\u{239c}', "\u{4e28}"),
+  ('\u{239f}', "\u{4e28}"), ('\u{23a2}', "\u{4e28}"), ('\u{23a5}', "\u{4e28}"), ('\u{23aa}', "\u{4e28}"), ('\u{23ae}', "\u{4e28}"), ('\u{23c1}', "\u{2355}"),
+  ('\u{23c2}', "\u{234e}"), ('\u{23c3}', "\u{234b}"), ('\u{23c6}', "\u{236d}"), ('\u{23e8}', "\u{2081}\u{2080}"), ('\u{23fc}', "\u{23fb}"), ('\u{23fd}', "l"),
+  ('\u{23fe}', "\u{263e}"), ('\u{244a}', "\u{5c}\u{5c}"), ('\u{2460}', "\u{2780}"), ('\u{2461}', "\u{2781}"), ('\u{2462}', "\u{2782}"), ('\u{2463}', "\u{2783}"),
+  ('\u{2464}', "\u{2784}"), ('\u{2465}', "\u{2785}"), ('\u{2466}', "\u{2786}"), ('\u{2467}', "\u{2787}"), ('\u{2468}', "\u{2788}"), ('\u{2469}', "\u{2789}"),
+  ('\u{2474}', "(l)"), ('\u{2475}', "(2)"), ('\u{2476}', "(3)"), ('\u{2477}', "(4)"), ('\u{2478}', "(5)"), ('\u{2479}', "(6)"),
+  ('\u{247a}', "(7)"), ('\u{247b}', "(8)"), ('\u{247c}', "(9)"), ('\u{247d}', "(lO)"), ('\u{247e}', "(ll)"), ('\u{247f}', "(l2)"),
+  ('\u{2480}', "(l3)"), ('\u{2481}', "(l4)"), ('\u{2482}', "(l5)"), ('\u{2483}', "(l6)"), ('\u{2484}', "(l7)"), ('\u{2485}', "(l8)"),
+  ('\u{2486}', "(l9)"), ('\u{2487}', "(2O)"), ('\u{2488}', "l."), ('\u{2489}', "2."), ('\u{248a}', "3."), ('\u{248b}', "4."),
+  ('\u{248c}', "5."), ('\u{248d}', "6."), ('\u{248e}', "7."), ('\u{248f}', "8."), ('\u{2490}', "9."), ('\u{2491}', "lO."),
+  ('\u{2492}', "ll."), ('\u{2493}', "l2."), ('\u{2494}', "l3."), ('\u{2495}', "l4."), ('\u{2496}', "l5."), ('\u{2497}', "l6."),
+  ('\u{2498}', "l7."), ('\u{2499}', "l8."), ('\u{249a}', "l9."), ('\u{249b}', "2O."), ('\u{249c}', "(a)"), ('\u{249d}', "(b)"),
+  ('\u{249e}', "(c)"), ('\u{249f}', "(d)"), ('\u{24a0}', "(e)"), ('\u{24a1}', "(f)"), ('\u{24a2}', "(g)"), ('\u{24a3}', "(h)"),
+  ('\u{24a4}', "(i)"), ('\u{24a5}', "(j)"), ('\u{24a6}', "(k)"), ('\u{24a7}', "(l)"), ('\u{24a8}', "(rn)"), ('\u{24a9}', "(n)"),
+  ('\u{24aa}', "(o)"), ('\u{24ab}', "(p)"), ('\u{24ac}', "(q)"), ('\u{24ad}', "(r)"), ('\u{24ae}', "(s)"), ('\u{24af}', "(t)"),
+  ('\u{24b0}', "(u)"), ('\u{24b1}', "(v)"), ('\u{24b2}', "(w)"), ('\u{24b3}', "(x)"), ('\u{24b4}', "(y)"), ('\u{24b5}', "(z)"),
+  ('\u{24b8}', "\u{a9}"), ('\u{24c5}', "\u{2117}"), ('\u{24c7}', "\u{ae}"), ('\u{24db}', "\u{24be}"), ('\u{24ea}', "\u{1f10d}"), ('\u{2500}', "\u{30fc}"),
+  ('\u{2501}', "\u{30fc}"), ('\u{2503}', "\u{2502}"), ('\u{250f}', "\u{250c}"), ('\u{2523}', "\u{251c}"), ('\u{2571}', "/"), ('\u{2573}', "X"),
+  ('\u{2588}', "\u{220e}"), ('\u{2590}', "\u{258c}"), ('\u{2594}', "\u{2c9}"), ('\u{2597}', "\u{2596}"), ('\u{259d}', "\u{2598}"), ('\u{25a0}', "\u{220e}"),
+  ('\u{25b1}', "\u{23e5}"), ('\u{25b3}', "\u{394}"), ('\u{25b7}', "\u{22b3}"), ('\u{25b8}', "\u{25b6}"), ('\u{25ba}', "\u{25b6}"), ('\u{25bd}', "\u{102bc}"),
+  ('\u{25c1}', "\u{22b2}"), ('\u{25c7}', "\u{16dc}"), ('\u{25ca}', "\u{16dc}"), ('\u{25cb}', "\u{b0}"), ('\u{25ce}', "\u{233e}"), ('\u{25e0}', "\u{2312}"),
+  ('\u{25e6}', "\u{b0}"), ('\u{2609}', "\u{298}"), ('\u{2610}', "\u{25a1}"), ('\u{2625}', "\u{1099e}"), ('\u{2630}', "\u{2cb6}"), ('\u{2638}', "\u{2388}"),
+  ('\u{264e}', "\u{224f}"), ('\u{2662}', "\u{16dc}"), ('\u{2669}', "\u{1d158}\u{1d165}"), ('\u{266a}', "\u{1d158}\u{1d165}\u{1d16e}"), ('\u{26ac}', "\u{970}"), ('\u{2768}', "("),
+  ('\u{2769}', ")"), ('\u{276e}', "<"), ('\u{276f}', ">"), ('\u{2772}', "("), ('\u{2773}', ")"), ('\u{2774}', "{"),
+  ('\u{2775}', "}"), ('\u{2795}', "+"), ('\u{2796}', "-"), ('\u{2797}', "\u{f7}"), ('\u{27c2}', "\u{a4d5}"), ('\u{27c8}', "\u{5c}\u{1455}"),
+  ('\u{27c9}', "\u{1450}/"), ('\u{27cb}', "/"), ('\u{27cd}', "\u{5c}"), ('\u{27d9}', "T"), ('\u{27e8}', "\u{276c}"), ('\u{27e9}', "\u{276d}"),
+  ('\u{292b}', "x"), ('\u{292c}', "x"), ('\u{2963}', "\u{16d0}\u{16da}"), ('\u{2965}', "\u{21c3}\u{21c2}"), ('\u{296e}', "\u{16d0}\u{21c2}"), ('\u{296f}', "\u{21c3}\u{16da}"),
+  ('\u{2999}', "\u{2d42}"), ('\u{29b0}', "\u{2349}"), ('\u{29be}', "\u{233e}"), ('\u{29c4}', "\u{303c}"), ('\u{29c5}', "\u{2342}"), ('\u{29c7}', "\u{233b}"),
+  ('\u{29d6}', "\u{102c0}"), ('\u{29d9}', "\u{299a}"), ('\u{29f4}', ":\u{2192}"), ('\u{29f5}', "\u{5c}"), ('\u{29f6}', "/\u{304}"), ('\u{29f8}', "/"),
+  ('\u{29f9}', "\u{5c}"), ('\u{2a00}', "\u{298}"), ('\u{2a01}', "\u{102a8}"), ('\u{2a02}', "\u{2297}"), ('\u{2a03}', "\u{228d}"), ('\u{2a04}', "\u{228e}"),
+  ('\u{2a05}', "\u{2293}"), ('\u{2a06}', "\u{2294}"), ('\u{2a0c}', "\u{283}\u{283}\u{283}\u{283}"), ('\u{2a1d}', "\u{16de}"), ('\u{2a20}', ">>"), ('\u{2a21}', "\u{16da}"),
+  ('\u{2a22}', "+\u{30a}"), ('\u{2a23}', "+\u{302}"), ('\u{2a24}', "+\u{303}"), ('\u{2a25}', "+\u{323}"), ('\u{2a26}', "+\u{330}"), ('\u{2a27}', "+\u{2082}"),
+  ('\u{2a29}', "-\u{313}"), ('\u{2a2a}', "-\u{323}"), ('\u{2a2f}', "x"), ('\u{2a30}', "x\u{307}"), ('\u{2a3d}', "\u{2319}"), ('\u{2a3e}', "\u{2a1f}"),
+  ('\u{2a3f}', "\u{2210}"), ('\u{2a6a}', "~\u{307}"), ('\u{2a6e}', "=\u{20f0}"), ('\u{2a74}', "::="), ('\u{2a75}', "=="), ('\u{2a76}', "==="),
+  ('\u{2aa5}', "><"), ('\u{2aaa}', "\u{15d5}"), ('\u{2aab}', "\u{15d2}"), ('\u{2ad7}', "\u{1450}\u{1455}"), ('\u{2afb}', "///"), ('\u{2afd}', "//"),
+  ('\u{2bec}', "\u{219e}"), ('\u{2bed}', "\u{219f}"), ('\u{2bee}', "\u{21a0}"), ('\u{2bef}', "\u{21a1}"), ('\u{2c67}', "H\u{329}"), ('\u{2c69}', "K\u{329}"),
+  ('\u{2c84}', "\u{393}"), ('\u{2c85}', "r"), ('\u{2c86}', "\u{394}"), ('\u{2c88}', "\u{a792}"), ('\u{2c89}', "\u{a793}"), ('\u{2c8e}', "H"),
+  ('\u{2c92}', "l"), ('\u{2c94}', "K"), ('\u{2c95}', "\u{138}"), ('\u{2c96}', "\u{3bb}"), ('\u{2c98}', "M"), ('\u{2c9a}', "N"),
+  ('\u{2c9e}', "O"), ('\u{2c9f}', "o"), ('\u{2ca0}', "\u{3a0}"), ('\u{2ca2}', "P"), ('\u{2ca3}', "p"), ('\u{2ca4}', "C"),
+  ('\u{2ca5}', "c"), ('\u{2ca6}', "T"), ('\u{2ca8}', "Y"), ('\u{2caa}', "\u{3a6}"), ('\u{2cab}', "\u{278}"), ('\u{2cac}', "X"),
+  ('\u{2cad}', "\u{3c7}"), ('\u{2cae}', "\u{3a8}"), ('\u{2cb1}', "\u{3c9}"), ('\u{2cb4}', "<\u{b7}"), ('\u{2cba}', "-"), ('\u{2cbc}', "\u{428}"),
+  ('\u{2cbd}', "\u{448}"), ('\u{2cc6}', "/"), ('\u{2cca}', "9"), ('\u{2ccc}', "3"), ('\u{2ccd}', "\u{21d}"), ('\u{2cd0}', "L"),
+  ('\u{2cd1}', "\u{29f}"), ('\u{2cd2}', "6"), ('\u{2cdc}', "\u{3ec}"), ('\u{2ce4}', "\u{3d7}"), ('\u{2ce9}', "\u{2627}"), ('\u{2cf9}', "\u{5c}\u{5c}"),
+  ('\u{2d31}', "O\u{335}"), ('\u{2d37}', "\u{245}"), ('\u{2d38}', "V"), ('\u{2d39}', "E"), ('\u{2d3a}', "\u{18e}"), ('\u{2d41}', "O\u{338}"),
+  ('\u{2d48}', "\u{b7}\u{b7}\u{b7}"), ('\u{2d49}', "\u{1a9}"), ('\u{2d4f}', "l"), ('\u{2d51}', "!"), ('\u{2d54}', "O"), ('\u{2d55}', "Q"),
+  ('\u{2d59}', "\u{298}"), ('\u{2d5d}', "X"), ('\u{2d60}', "\u{394}"), ('\u{2d63}', "\u{16ef}"), ('\u{2de8}', "\u{1ddf}"), ('\u{2dea}', "\u{30a}"),
+  ('\u{2ded}', "\u{368}"), ('\u{2def}', "\u{36f}"), ('\u{2df6}', "\u{363}"), ('\u{2df7}', "\u{364}"), ('\u{2e1a}', "-\u{308}"), ('\u{2e1e}', "~\u{307}"),
+  ('\u{2e1f}', "~\u{323}"), ('\u{2e26}', "\u{1455}"), ('\u{2e27}', "\u{1450}"), ('\u{2e28}', "(("), ('\u{2e29}', "))"), ('\u{2e2a}', "\u{2235}"),
+  ('\u{2e2b}', "\u{2234}"), ('\u{2e2c}', "\u{2237}"), ('\u{2e2e}', "\u{61f}"), ('\u{2e30}', "\u{b0}"), ('\u{2e31}', "\u{b7}"), ('\u{2e32}', "\u{60c}"),
+  ('\u{2e35}', "\u{61b}"), ('\u{2e39}', "\u{1e9f}"), ('\u{2e3d}', "\u{2d42}"), ('\u{2e3f}', "\u{b6}"), ('\u{2e40}', "="), ('\u{2e82}', "\u{4e5b}"),
+  ('\u{2e83}', "\u{4e5a}"), ('\u{2e85}', "\u{4ebb}"), ('\u{2e89}', "\u{5202}"), ('\u{2e8b}', "\u{353e}"), ('\u{2e8e}', "\u{5140}"), ('\u{2e8f}', "\u{5c23}"),
+  ('\u{2e90}', "\u{5c22}"), ('\u{2e92}', "\u{5df3}"), ('\u{2e93}', "\u{5e7a}"), ('\u{2e94}', "\u{5f51}"), ('\u{2e96}', "\u{5fc4}"), ('\u{2e97}', "\u{38fa}"),
+  ('\u{2e98}', "\u{624c}"), ('\u{2e99}', "\u{6535}"), ('\u{2e9b}', "\u{65e1}"), ('\u{2e9e}', "\u{6b7a}"), ('\u{2e9f}', "\u{6bcd}"), ('\u{2ea0}', "\u{6c11}"),
+  ('\u{2ea1}', "\u{6c35}"), ('\u{2ea2}', "\u{6c3a}"), ('\u{2ea3}', "\u{706c}"), ('\u{2ea4}', "\u{722b}"), ('\u{2ea6}', "\u{4e2c}"), ('\u{2ea8}', "\u{72ad}"),
+  ('\u{2eab}', "\u{7f52}"), ('\u{2ead}', "\u{793b}"), ('\u{2eaf}', "\u{7cf9}"), ('\u{2eb1}', "\u{7f53}"), ('\u{2eb2}', "\u{7f52}"), ('\u{2eb9}', "\u{8002}"),
+  ('\u{2eba}', "\u{8080}"), ('\u{2ebe}', "\u{8279}"), ('\u{2ebf}', "\u{8279}"), ('\u{2ec0}', "\u{8279}"), ('\u{2ec1}', "\u{864e}"), ('\u{2ec2}', "\u{8864}"),
+  ('\u{2ec3}', "\u{8980}"), ('\u{2ec4}', "\u{897f}"), ('\u{2ec5}', "\u{89c1}"), ('\u{2ec8}', "\u{8ba0}"), ('\u{2ec9}', "\u{8d1d}"), ('\u{2ecb}', "\u{8f66}"),
+  ('\u{2ecc}', "\u{8fb6}"), ('\u{2ecd}', "\u{8fb6}"), ('\u{2ecf}', "\u{961d}"), ('\u{2ed0}', "\u{9485}"), ('\u{2ed1}', "\u{9577}"), ('\u{2ed2}', "\u{9578}"),
+  ('\u{2ed3}', "\u{957f}"), ('\u{2ed4}', "\u{95e8}"), ('\u{2ed6}', "\u{961d}"), ('\u{2ed8}', "\u{9752}"), ('\u{2ed9}', "\u{97e6}"), ('\u{2eda}', "\u{9875}"),
+  ('\u{2edb}', "\u{98ce}"), ('\u{2edc}', "\u{98de}"), ('\u{2edd}', "\u{98df}"), ('\u{2edf}', "\u{98e0}"), ('\u{2ee0}', "\u{9963}"), ('\u{2ee2}', "\u{9a6c}"),
+  ('\u{2ee4}', "\u{9b3c}"), ('\u{2ee5}', "\u{9c7c}"), ('\u{2ee8}', "\u{9ea6}"), ('\u{2ee9}', "\u{9ec4}"), ('\u{2eeb}', "\u{6589}"), ('\u{2eec}', "\u{9f50}"),
+  ('\u{2eed}', "\u{6b6f}"), ('\u{2eee}', "\u{9f7f}"), ('\u{2eef}', "\u{7adc}"), ('\u{2ef0}', "\u{9f99}"), ('\u{2ef2}', "\u{4e80}"), ('\u{2ef3}', "\u{9f9f}"),
+  ('\u{2f00}', "\u{30fc}"), ('\u{2f01}', "\u{4e28}"), ('\u{2f02}', "\u{5c}"), ('\u{2f03}', "/"), ('\u{2f04}', "\u{4e59}"), ('\u{2f05}', "\u{4e85}"),
+  ('\u{2f06}', "\u{4e8c}"), ('\u{2f07}', "\u{4ea0}"), ('\u{2f08}', "\u{4eba}"), ('\u{2f09}', "\u{513f}"), ('\u{2f0a}', "\u{5165}"), ('\u{2f0b}', "\u{516b}"),
+  ('\u{2f0c}', "\u{5182}"), ('\u{2f0d}', "\u{5196}"), ('\u{2f0e}', "\u{51ab}"), ('\u{2f0f}', "\u{51e0}"), ('\u{2f10}', "\u{51f5}"), ('\u{2f11}', "\u{5200}"),
+  ('\u{2f12}', "\u{529b}"), ('\u{2f13}', "\u{52f9}"), ('\u{2f14}', "\u{5315}"), ('\u{2f15}', "\u{531a}"), ('\u{2f16}', "\u{5338}"), ('\u{2f17}', "\u{5341}"),
+  ('\u{2f18}', "\u{535c}"), ('\u{2f19}', "\u{5369}"), ('\u{2f1a}', "\u{5382}"), ('\u{2f1b}', "\u{53b6}"), ('\u{2f1c}', "\u{53c8}"), ('\u{2f1d}', "\u{53e3}"),
+  ('\u{2f1e}', "\u{53e3}"), ('\u{2f1f}', "\u{571f}"), ('\u{2f20}', "\u{571f}"), ('\u{2f21}', "\u{5902}"), ('\u{2f22}', "\u{590a}"), ('\u{2f23}', "\u{5915}"),
+  ('\u{2f24}', "\u{5927}"), ('\u{2f25}', "\u{5973}"), ('\u{2f26}', "\u{5b50}"), ('\u{2f27}', "\u{5b80}"), ('\u{2f28}', "\u{5bf8}"), ('\u{2f29}', "\u{5c0f}"),
+  ('\u{2f2a}', "\u{5c22}"), ('\u{2f2b}', "\u{5c38}"), ('\u{2f2c}', "\u{5c6e}"), ('\u{2f2d}', "\u{5c71}"), ('\u{2f2e}', "\u{5ddb}"), ('\u{2f2f}', "\u{5de5}"),
+  ('\u{2f30}', "\u{5df1}"), ('\u{2f31}', "\u{5dfe}"), ('\u{2f32}', "\u{5e72}"), ('\u{2f33}', "\u{5e7a}"), ('\u{2f34}', "\u{5e7f}"), ('\u{2f35}', "\u{5ef4}"),
+  ('\u{2f36}', "\u{5efe}"), ('\u{2f37}', "\u{5f0b}"), ('\u{2f38}', "\u{5f13}"), ('\u{2f39}', "\u{5f50}"), ('\u{2f3a}', "\u{5f61}"), ('\u{2f3b}', "\u{5f73}"),
+  ('\u{2f3c}', "\u{5fc3}"), ('\u{2f3d}', "\u{6208}"), ('\u{2f3e}', "\u{6236}"), ('\u{2f3f}', "\u{624b}"), ('\u{2f40}', "\u{652f}"), ('\u{2f41}', "\u{6534}"),
+  ('\u{2f42}', "\u{6587}"), ('\u{2f43}', "\u{6597}"), ('\u{2f44}', "\u{65a4}"), ('\u{2f45}', "\u{65b9}"), ('\u{2f46}', "\u{65e0}"), ('\u{2f47}', "\u{65e5}"),
+  ('\u{2f48}', "\u{66f0}"), ('\u{2f49}', "\u{6708}"), ('\u{2f4a}', "\u{6728}"), ('\u{2f4b}', "\u{6b20}"), ('\u{2f4c}', "\u{6b62}"), ('\u{2f4d}', "\u{6b79}"),
+  ('\u{2f4e}', "\u{6bb3}"), ('\u{2f4f}', "\u{6bcb}"), ('\u{2f50}', "\u{6bd4}"), ('\u{2f51}', "\u{6bdb}"), ('\u{2f52}', "\u{6c0f}"), ('\u{2f53}', "\u{6c14}"),
+  ('\u{2f54}', "\u{6c34}"), ('\u{2f55}', "\u{706b}"), ('\u{2f56}', "\u{722a}"), ('\u{2f57}', "\u{7236}"), ('\u{2f58}', "\u{723b}"), ('\u{2f59}', "\u{723f}"),
+  ('\u{2f5a}', "\u{7247}"), ('\u{2f5b}', "\u{7259}"), ('\u{2f5c}', "\u{725b}"), ('\u{2f5d}', "\u{72ac}"), ('\u{2f5e}', "\u{7384}"), ('\u{2f5f}', "\u{7389}"),
+  ('\u{2f60}', "\u{74dc}"), ('\u{2f61}', "\u{74e6}"), ('\u{2f62}', "\u{7518}"), ('\u{2f63}', "\u{751f}"), ('\u{2f64}', "\u{7528}"), ('\u{2f65}', "\u{7530}"),
+  ('\u{2f66}', "\u{758b}"), ('\u{2f67}', "\u{7592}"), ('\u{2f68}', "\u{7676}"), ('\u{2f69}', "\u{767d}"), ('\u{2f6a}', "\u{76ae}"), ('\u{2f6b}', "\u{76bf}"),
+  ('\u{2f6c}', "\u{76ee}"), ('\u{2f6d}', "\u{77db}"), ('\u{2f6e}', "\u{77e2}"), ('\u{2f6f}', "\u{77f3}"), ('\u{2f70}', "\u{793a}"), ('\u{2f71}', "\u{79b8}"),
+  ('\u{2f72}', "\u{79be}"), ('\u{2f73}', "\u{7a74}"), ('\u{2f74}', "\u{7acb}"), ('\u{2f75}', "\u{7af9}"), ('\u{2f76}', "\u{7c73}"), ('\u{2f77}', "\u{7cf8}"),
+  ('\u{2f78}', "\u{7f36}"), ('\u{2f79}', "\u{7f51}"), ('\u{2f7a}', "\u{7f8a}"), ('\u{2f7b}', "\u{7fbd}"), ('\u{2f7c}', "\u{8001}"), ('\u{2f7d}', "\u{800c}"),
+  ('\u{2f7e}', "\u{8012}"), ('\u{2f7f}', "\u{8033}"), ('\u{2f80}', "\u{807f}"), ('\u{2f81}', "\u{8089}"), ('\u{2f82}', "\u{81e3}"), ('\u{2f83}', "\u{81ea}"),
+  ('\u{2f84}', "\u{81f3}"), ('\u{2f85}', "\u{81fc}"), ('\u{2f86}', "\u{820c}"), ('\u{2f87}', "\u{821b}"), ('\u{2f88}', "\u{821f}"), ('\u{2f89}', "\u{826e}"),
+  ('\u{2f8a}', "\u{8272}"), ('\u{2f8b}', "\u{8278}"), ('\u{2f8c}', "\u{864d}"), ('\u{2f8d}', "\u{866b}"), ('\u{2f8e}', "\u{8840}"), ('\u{2f8f}', "\u{884c}"),
+  ('\u{2f90}', "\u{8863}"), ('\u{2f91}', "\u{897e}"), ('\u{2f92}', "\u{898b}"), ('\u{2f93}', "\u{89d2}"), ('\u{2f94}', "\u{8a00}"), ('\u{2f95}', "\u{8c37}"),
+  ('\u{2f96}', "\u{8c46}"), ('\u{2f97}', "\u{8c55}"), ('\u{2f98}', "\u{8c78}"), ('\u{2f99}', "\u{8c9d}"), ('\u{2f9a}', "\u{8d64}"), ('\u{2f9b}', "\u{8d70}"),
+  ('\u{2f9c}', "\u{8db3}"), ('\u{2f9d}', "\u{8eab}"), ('\u{2f9e}', "\u{8eca}"), ('\u{2f9f}', "\u{8f9b}"), ('\u{2fa0}', "\u{8fb0}"), ('\u{2fa1}', "\u{8fb5}"),
+  ('\u{2fa2}', "\u{9091}"), ('\u{2fa3}', "\u{9149}"), ('\u{2fa4}', "\u{91c6}"), ('\u{2fa5}', "\u{91cc}"), ('\u{2fa6}', "\u{91d1}"), ('\u{2fa7}', "\u{9577}"),
+  ('\u{2fa8}', "\u{9580}"), ('\u{2fa9}', "\u{961c}"), ('\u{2faa}', "\u{96b6}"), ('\u{2fab}', "\u{96b9}"), ('\u{2fac}', "\u{96e8}"), ('\u{2fad}', "\u{9751}"),
+  ('\u{2fae}', "\u{975e}"), ('\u{2faf}', "\u{9762}"), ('\u{2fb0}', "\u{9769}"), ('\u{2fb1}', "\u{97cb}"), ('\u{2fb2}', "\u{97ed}"), ('\u{2fb3}', "\u{97f3}"),
+  ('\u{2fb4}', "\u{9801}"), ('\u{2fb5}', "\u{98a8}"), ('\u{2fb6}', "\u{98db}"), ('\u{2fb7}', "\u{98df}"), ('\u{2fb8}', "\u{9996}"), ('\u{2fb9}', "\u{9999}"),
+  ('\u{2fba}', "\u{99ac}"), ('\u{2fbb}', "\u{9aa8}"), ('\u{2fbc}', "\u{9ad8}"), ('\u{2fbd}', "\u{9adf}"), ('\u{2fbe}', "\u{9b25}"), ('\u{2fbf}', "\u{9b2f}"),
+  ('\u{2fc0}', "\u{9b32}"), ('\u{2fc1}', "\u{9b3c}"), ('\u{2fc2}', "\u{9b5a}"), ('\u{2fc3}', "\u{9ce5}"), ('\u{2fc4}', "\u{9e75}"), ('\u{2fc5}', "\u{9e7f}"),
+  ('\u{2fc6}', "\u{9ea5}"), ('\u{2fc7}', "\u{9ebb}"), ('\u{2fc8}', "\u{9ec3}"), ('\u{2fc9}', "\u{9ecd}"), ('\u{2fca}', "\u{9ed1}"), ('\u{2fcb}', "\u{9ef9}"),
+  ('\u{2fcc}', "\u{9efd}"), ('\u{2fcd}', "\u{9f0e}"), ('\u{2fce}', "\u{9f13}"), ('\u{2fcf}', "\u{9f20}"), ('\u{2fd0}', "\u{9f3b}"), ('\u{2fd1}', "\u{9f4a}"),
+  ('\u{2fd2}', "\u{9f52}"), ('\u{2fd3}', "\u{9f8d}"), ('\u{2fd4}', "\u{9f9c}"), ('\u{2fd5}', "\u{9fa0}"), ('\u{3002}', "\u{2f3}"), ('\u{3003}', "''"),
+  ('\u{3007}', "O"), ('\u{3008}', "\u{276c}"), ('\u{3009}', "\u{276d}"), ('\u{3012}', "\u{20b8}"), ('\u{3014}', "("), ('\u{3015}', ")"),
+  ('\u{301a}', "\u{27e6}"), ('\u{301b}', "\u{27e7}"), ('\u{302c}', "\u{309}"), ('\u{302d}', "\u{325}"), ('\u{3033}', "/"), ('\u{3036}', "\u{20b8}"),
+  ('\u{3038}', "\u{5341}"), ('\u{3039}', "\u{5344}"), ('\u{303a}', "\u{5345}"), ('\u{304f}', "\u{276c}"), ('\u{309a}', "\u{30a}"), ('\u{309b}', "\u{ff9e}"),
+  ('\u{309c}', "\u{ff9f}"), ('\u{30a0}', "="), ('\u{30a4}', "\u{4ebb}"), ('\u{30a8}', "\u{5de5}"), ('\u{30ab}', "\u{529b}"), ('\u{30bf}', "\u{5915}"),
+  ('\u{30c8}', "\u{535c}"), ('\u{30cb}', "\u{4e8c}"), ('\u{30ce}', "/"), ('\u{30cf}', "\u{516b}"), ('\u{30d8}', "\u{3078}"), ('\u{30ed}', "\u{53e3}"),
+  ('\u{30fb}', "\u{b7}"), ('\u{3126}', "\u{513f}"), ('\u{3131}', "\u{1100}"), ('\u{3132}', "\u{1100}\u{1100}"), ('\u{3133}', "\u{1100}\u{1109}"), ('\u{3134}', "\u{1102}"),
+  ('\u{3135}', "\u{1102}\u{110c}"), ('\u{3136}', "\u{1102}\u{1112}"), ('\u{3137}', "\u{1103}"), ('\u{3138}', "\u{1103}\u{1103}"), ('\u{3139}', "\u{1105}"), ('\u{313a}', "\u{1105}\u{1100}"),
+  ('\u{313b}', "\u{1105}\u{1106}"), ('\u{313c}', "\u{1105}\u{1107}"), ('\u{313d}', "\u{1105}\u{1109}"), ('\u{313e}', "\u{1105}\u{1110}"), ('\u{313f}', "\u{1105}\u{1111}"), ('\u{3140}', "\u{1105}\u{1112}"),
+  ('\u{3141}', "\u{1106}"), ('\u{3142}', "\u{1107}"), ('\u{3143}', "\u{1107}\u{1107}"), ('\u{3144}', "\u{1107}\u{1109}"), ('\u{3145}', "\u{1109}"), ('\u{3146}', "\u{1109}\u{1109}"),
+  ('\u{3147}', "\u{110b}"), ('\u{3148}', "\u{110c}"), ('\u{3149}', "\u{110c}\u{110c}"), ('\u{314a}', "\u{110e}"), ('\u{314b}', "\u{110f}"), ('\u{314c}', "\u{1110}"),
+  ('\u{314d}', "\u{1111}"), ('\u{314e}', "\u{1112}"), ('\u{314f}', "\u{1161}"), ('\u{3150}', "\u{1161}\u{4e28}"), ('\u{3151}', "\u{1163}"), ('\u{3152}', "\u{1163}\u{4e28}"),
+  ('\u{3153}', "\u{1165}"), ('\u{3154}', "\u{1165}\u{4e28}"), ('\u{3155}', "\u{1167}"), ('\u{3156}', "\u{1167}\u{4e28}"), ('\u{3157}', "\u{1169}"), ('\u{3158}', "\u{1169}\u{1161}"),
+  ('\u{3159}', "\u{1169}\u{1161}\u{4e28}"), ('\u{315a}', "\u{1169}\u{4e28}"), ('\u{315b}', "\u{116d}"), ('\u{315c}', "\u{116e}"), ('\u{315d}', "\u{116e}\u{1165}"), ('\u{315e}', "\u{116e}\u{1165}\u{4e28}"),
+  ('\u{315f}', "\u{116e}\u{4e28}"), ('\u{3160}', "\u{1172}"), ('\u{3161}', "\u{30fc}"), ('\u{3162}', "\u{30fc}\u{4e28}"), ('\u{3163}', "\u{4e28}"), ('\u{3164}', "\u{1160}"),
+  ('\u{3165}', "\u{1102}\u{1102}"), ('\u{3166}', "\u{1102}\u{1103}"), ('\u{3167}', "\u{1102}\u{1109}"), ('\u{3168}', "\u{1102}\u{1140}"), ('\u{3169}', "\u{1105}\u{1100}\u{1109}"), ('\u{316a}', "\u{1105}\u{1103}"),
+  ('\u{316b}', "\u{1105}\u{1107}\u{1109}"), ('\u{316c}', "\u{1105}\u{1140}"), ('\u{316d}', "\u{1105}\u{1159}"), ('\u{316e}', "\u{1106}\u{1107}"), ('\u{316f}', "\u{1106}\u{1109}"), ('\u{3170}', "\u{1106}\u{1140}"),
+  ('\u{3171}', "\u{1106}\u{110b}"), ('\u{3172}', "\u{1107}\u{1100}"), ('\u{3173}', "\u{1107}\u{1103}"), ('\u{3174}', "\u{1107}\u{1109}\u{1100}"), ('\u{3175}', "\u{1107}\u{1109}\u{1103}"), ('\u{3176}', "\u{1107}\u{110c}"),
+  ('\u{3177}', "\u{1107}\u{1110}"), ('\u{3178}', "\u{1107}\u{110b}"), ('\u{3179}', "\u{1107}\u{1107}\u{110b}"), ('\u{317a}', "\u{1109}\u{1100}"), ('\u{317b}', "\u{1109}\u{1102}"), ('\u{317c}', "\u{1109}\u{1103}"),
+  ('\u{317d}', "\u{1109}\u{1107}"), ('\u{317e}', "\u{1109}\u{110c}"), ('\u{317f}', "\u{1140}"), ('\u{3180}', "\u{110b}\u{110b}"), ('\u{3181}', "\u{114c}"), ('\u{3182}', "\u{110b}\u{1109}"),
+  ('\u{3183}', "\u{110b}\u{1140}"), ('\u{3184}', "\u{1111}\u{110b}"), ('\u{3185}', "\u{1112}\u{1112}"), ('\u{3186}', "\u{1159}"), ('\u{3187}', "\u{116d}\u{1163}"), ('\u{3188}', "\u{116d}\u{1163}\u{4e28}"),
+  ('\u{3189}', "\u{116d}\u{4e28}"), ('\u{318a}', "\u{1172}\u{1167}"), ('\u{318b}', "\u{1172}\u{1167}\u{4e28}"), ('\u{318c}', "\u{1172}\u{4e28}"), ('\u{318d}', "\u{119e}"), ('\u{318e}', "\u{119e}\u{4e28}"),
+  ('\u{31d0}', "\u{30fc}"), ('\u{31d1}', "\u{4e28}"), ('\u{31d3}', "/"), ('\u{31d4}', "\u{5c}"), ('\u{31d6}', "\u{4e5b}"), ('\u{31da}', "\u{4e85}"),
+  ('\u{31db}', "\u{276c}"), ('\u{31df}', "\u{4e5a}"), ('\u{31e0}', "\u{4e59}"), ('\u{3200}', "(\u{1100})"), ('\u{3201}', "(\u{1102})"), ('\u{3202}', "(\u{1103})"),
+  ('\u{3203}', "(\u{1105})"), ('\u{3204}', "(\u{1106})"), ('\u{3205}', "(\u{1107})"), ('\u{3206}', "(\u{1109})"), ('\u{3207}', "(\u{110b})"), ('\u{3208}', "(\u{110c})"),
+  ('\u{3209}', "(\u{110e})"), ('\u{320a}', "(\u{110f})"), ('\u{320b}', "(\u{1110})"), ('\u{320c}', "(\u{1111})"), ('\u{320d}', "(\u{1112})"), ('\u{320e}', "(\u{ac00})"),
+  ('\u{320f}', "(\u{b098})"), ('\u{3210}', "(\u{b2e4})"), ('\u{3211}', "(\u{b77c})"), ('\u{3212}', "(\u{b9c8})"), ('\u{3213}', "(\u{bc14})"), ('\u{3214}', "(\u{c0ac})"),
+  ('\u{3215}', "(\u{c544})"), ('\u{3216}', "(\u{c790})"), ('\u{3217}', "(\u{cc28})"), ('\u{3218}', "(\u{ce74})"), ('\u{3219}', "(\u{d0c0})"), ('\u{321a}', "(\u{d30c})"),
+  ('\u{321b}', "(\u{d558})"), ('\u{321c}', "(\u{c8fc})"), ('\u{321d}', "(\u{c624}\u{c804})"), ('\u{321e}', "(\u{c624}\u{d6c4})"), ('\u{3220}', "(\u{30fc})"), ('\u{3221}', "(\u{4e8c})"),
+  ('\u{3222}', "(\u{4e09})"), ('\u{3223}', "(\u{56db})"), ('\u{3224}', "(\u{4e94})"), ('\u{3225}', "(\u{516d})"), ('\u{3226}', "(\u{4e03})"), ('\u{3227}', "(\u{516b})"),
+  ('\u{3228}', "(\u{4e5d})"), ('\u{3229}', "(\u{5341})"), ('\u{322a}', "(\u{6708})"), ('\u{322b}', "(\u{706b})"), ('\u{322c}', "(\u{6c34})"), ('\u{322d}', "(\u{6728})"),
+  ('\u{322e}', "(\u{91d1})"), ('\u{322f}', "(\u{571f})"), ('\u{3230}', "(\u{65e5})"), ('\u{3231}', "(\u{682a})"), ('\u{3232}', "(\u{6709})"), ('\u{3233}', "(\u{793e})"),
+  ('\u{3234}', "(\u{540d})"), ('\u{3235}', "(\u{7279})"), ('\u{3236}', "(\u{8ca1})"), ('\u{3237}', "(\u{795d})"), ('\u{3238}', "(\u{52b4})"), ('\u{3239}', "(\u{4ee3})"),
+  ('\u{323a}', "(\u{547c})"), ('\u{323b}', "(\u{5b66})"), ('\u{323c}', "(\u{76e3})"), ('\u{323d}', "(\u{4f01})"), ('\u{323e}', "(\u{8cc7})"), ('\u{323f}', "(\u{5354})"),
+  ('\u{3240}', "(\u{796d})"), ('\u{3241}', "(\u{4f11})"), ('\u{3242}', "(\u{81ea})"), ('\u{3243}', "(\u{81f3})"), ('\u{32c0}', "l\u{6708}"), ('\u{32c1}', "2\u{6708}"),
+  ('\u{32c2}', "3\u{6708}"), ('\u{32c3}', "4\u{6708}"), ('\u{32c4}', "5\u{6708}"), ('\u{32c5}', "6\u{6708}"), ('\u{32c6}', "7\u{6708}"), ('\u{32c7}', "8\u{6708}"),
+  ('\u{32c8}', "9\u{6708}"), ('\u{32c9}', "lO\u{6708}"), ('\u{32ca}', "ll\u{6708}"), ('\u{32cb}', "l2\u{6708}"), ('\u{3358}', "O\u{70b9}"), ('\u{3359}', "l\u{70b9}"),
+  ('\u{335a}', "2\u{70b9}"), ('\u{335b}', "3\u{70b9}"), ('\u{335c}', "4\u{70b9}"), ('\u{335d}', "5\u{70b9}"), ('\u{335e}', "6\u{70b9}"), ('\u{335f}', "7\u{70b9}"),
+  ('\u{3360}', "8\u{70b9}"), ('\u{3361}', "9\u{70b9}"), ('\u{3362}', "lO\u{70b9}"), ('\u{3363}', "ll\u{70b9}"), ('\u{3364}', "l2\u{70b9}"), ('\u{3365}', "l3\u{70b9}"),
+  ('\u{3366}', "l4\u{70b9}"), ('\u{3367}', "l5\u{70b9}"), ('\u{3368}', "l6\u{70b9}"), ('\u{3369}', "l7\u{70b9}"), ('\u{336a}', "l8\u{70b9}"), ('\u{336b}', "l9\u{70b9}"),
+  ('\u{336c}', "2O\u{70b9}"), ('\u{336d}', "2l\u{70b9}"), ('\u{336e}', "22\u{70b9}"), ('\u{336f}', "23\u{70b9}"), ('\u{3370}', "24\u{70b9}"), ('\u{33e0}', "l\u{65e5}"),
+  ('\u{33e1}', "2\u{65e5}"), ('\u{33e2}', "3\u{65e5}"), ('\u{33e3}', "4\u{65e5}"), ('\u{33e4}', "5\u{65e5}"), ('\u{33e5}', "6\u{65e5}"), ('\u{33e6}', "7\u{65e5}"),
+  ('\u{33e7}', "8\u{65e5}"), ('\u{33e8}', "9\u{65e5}"), ('\u{33e9}', "lO\u{65e5}"), ('\u{33ea}', "ll\u{65e5}"), ('\u{33eb}', "l2\u{65e5}"), ('\u{33ec}', "l3\u{65e5}"),
+  ('\u{33ed}', "l4\u{65e5}"), ('\u{33ee}', "l5\u{65e5}"), ('\u{33ef}', "l6\u{65e5}"), ('\u{33f0}', "l7\u{65e5}"), ('\u{33f1}', "l8\u{65e5}"), ('\u{33f2}', "l9\u{65e5}"),
+  ('\u{33f3}', "2O\u{65e5}"), ('\u{33f4}', "2l\u{65e5}"), ('\u{33f5}', "22\u{65e5}"), ('\u{33f6}', "23\u{65e5}"), ('\u{33f7}', "24\u{65e5}"), ('\u{33f8}', "25\u{65e5}"),
+  ('\u{33f9}', "26\u{65e5}"), ('\u{33fa}', "27\u{65e5}"), ('\u{33fb}', "28\u{65e5}"), ('\u{33fc}', "29\u{65e5}"), ('\u{33fd}', "3O\u{65e5}"), ('\u{33fe}', "3l\u{65e5}"),
+  ('\u{39b3}', "\u{363d}"), ('\u{439b}', "\u{3588}"), ('\u{4420}', "\u{3b3b}"), ('\u{4e00}', "\u{30fc}"), ('\u{4e36}', "\u{5c}"), ('\u{4e3f}', "/"),
+  ('\u{5002}', "\u{4f75}"), ('\u{503c}', "\u{5024}"), ('\u{555f}', "\u{5553}"), ('\u{56d7}', "\u{53e3}"), ('\u{586b}', "\u{5861}"), ('\u{58eb}', "\u{571f}"),
+  ('\u{58ff}', "\u{58ab}"), ('\u{5b00}', "\u{5aaf}"), ('\u{5e32}', "\u{5e21}"), ('\u{5e50}', "\u{3b3a}"), ('\u{6238}', "\u{6236}"), ('\u{6409}', "\u{3a41}"),
+  ('\u{6663}', "\u{403f}"), ('\u{6669}', "\u{665a}"), ('\u{66f6}', "\u{3ada}"), ('\u{6726}', "\u{4443}"), ('\u{67ff}', "\u{676e}"), ('\u{69e9}', "\u{3ba3}"),
+  ('\u{6a27}', "\u{699d}"), ('\u{6f59}', "\u{6e88}"), ('\u{784f}', "\u{7814}"), ('\u{7d76}', "\u{7d55}"), ('\u{80a6}', "\u{670c}"), ('\u{80ca}', "\u{6710}"),
+  ('\u{80d0}', "\u{670f}"), ('\u{80f6}', "\u{3b35}"), ('\u{8101}', "\u{6713}"), ('\u{8127}', "\u{6718}"), ('\u{8141}', "\u{80fc}"), ('\u{81a7}', "\u{6723}"),
+  ('\u{853f}', "\u{848d}"), ('\u{8641}', "\u{8637}"), ('\u{8a1e}', "\u{46b6}"), ('\u{8a7d}', "\u{8a2e}"), ('\u{8b8f}', "\u{8b86}"), ('\u{8c63}', "\u{8c5c}"),
+  ('\u{8d86}', "\u{8d7f}"), ('\u{8dfa}', "\u{8de5}"), ('\u{8e9b}', "\u{8e97}"), ('\u{8f27}', "\u{8eff}"), ('\u{90de}', "\u{90ce}"), ('\u{93ae}', "\u{93ad}"),
+  ('\u{96b8}', "\u{96b7}"), ('\u{9e43}', "\u{9e42}"), ('\u{9ed2}', "\u{9ed1}"), ('\u{9fc3}', "\u{4039}"), ('\u{a494}', "\u{a2cd}"), ('\u{a49c}', "\u{a0c0}"),
+  ('\u{a49e}', "\u{a04a}"), ('\u{a4a7}', "\u{a458}"), ('\u{a4a8}', "\u{a132}"), ('\u{a4ac}', "\u{a050}"), ('\u{a4b0}', "\u{a3c2}"), ('\u{a4ba}', "\u{a3bf}"),
+  ('\u{a4be}', "\u{a2b1}"), ('\u{a4bf}', "\u{a259}"), ('\u{a4c0}', "\u{a3ab}"), ('\u{a4c2}', "\u{a3b5}"), ('\u{a4d0}', "B"), ('\u{a4d1}', "P"),
+  ('\u{a4d2}', "d"), ('\u{a4d3}', "D"), ('\u{a4d4}', "T"), ('\u{a4d6}', "G"), ('\u{a4d7}', "K"), ('\u{a4d9}', "J"),
+  ('\u{a4da}', "C"), ('\u{a4db}', "\u{186}"), ('\u{a4dc}', "Z"), ('\u{a4dd}', "F"), ('\u{a4de}', "\u{2132}"), ('\u{a4df}', "M"),
+  ('\u{a4e0}', "N"), ('\u{a4e1}', "L"), ('\u{a4e2}', "S"), ('\u{a4e3}', "R"), ('\u{a4e5}', "\u{245}"), ('\u{a4e6}', "V"),
+  ('\u{a4e7}', "H"), ('\u{a4ea}', "W"), ('\u{a4eb}', "X"), ('\u{a4ec}', "Y"), ('\u{a4ed}', "\u{1660}"), ('\u{a4ee}', "A"),
+  ('\u{a4ef}', "\u{2c6f}"), ('\u{a4f0}', "E"), ('\u{a4f1}', "\u{18e}"), ('\u{a4f2}', "l"), ('\u{a4f3}', "O"), ('\u{a4f4}', "U"),
+  ('\u{a4f5}', "\u{548}"), ('\u{a4f7}', "\u{15e1}"), ('\u{a4f8}', "."), ('\u{a4f9}', ","), ('\u{a4fa}', ".."), ('\u{a4fb}', ".,"),
+  ('\u{a4fd}', ":"), ('\u{a4fe}', "-."), ('\u{a4ff}', "="), ('\u{a60e}', "."), ('\u{a644}', "2"), ('\u{a645}', "\u{1a8}"),
+  ('\u{a647}', "i"), ('\u{a64d}', "\u{3c9}"), ('\u{a650}', "\u{42a}l"), ('\u{a651}', "\u{2c9}bi"), ('\u{a668}', "\u{298}"), ('\u{a66f}', "\u{20e9}"),
+  ('\u{a67c}', "\u{306}"), ('\u{a67e}', "\u{2c7}"), ('\u{a695}', "h\u{314}"), ('\u{a698}', "OO"), ('\u{a699}', "oo"), ('\u{a69a}', "\u{102a8}"),
+  ('\u{a6a1}', "\u{418}"), ('\u{a6b0}', "\u{16b9}"), ('\u{a6b1}', "\u{2c75}"), ('\u{a6cd}', "\u{2a1}"), ('\u{a6ce}', "\u{245}"), ('\u{a6db}', "\u{3a0}"),
+  ('\u{a6df}', "V"), ('\u{a6eb}', "?"), ('\u{a6ef}', "2"), ('\u{a6f0}', "\u{302}"), ('\u{a6f1}', "\u{304}"), ('\u{a6f4}', "\u{a6f3}\u{a6f3}"),
+  ('\u{a714}', "\u{2eb}"), ('\u{a716}', "\u{2ea}"), ('\u{a728}', "T3"), ('\u{a729}', "t\u{21d}"), ('\u{a731}', "s"), ('\u{a732}', "AA"),
+  ('\u{a733}', "aa"), ('\u{a734}', "AO"), ('\u{a735}', "ao"), ('\u{a736}', "AU"), ('\u{a737}', "au"), ('\u{a738}', "AV"),
+  ('\u{a739}', "av"), ('\u{a73a}', "AV"), ('\u{a73b}', "av"), ('\u{a73c}', "AY"), ('\u{a73d}', "ay"), ('\u{a740}', "K\u{335}"),
+  ('\u{a74a}', "O\u{335}"), ('\u{a74b}', "o\u{335}"), ('\u{a74e}', "OO"), ('\u{a74f}', "oo"), ('\u{a75a}', "2"), ('\u{a761}', "w\u{326}"),
+  ('\u{a76a}', "3"), ('\u{a76b}', "\u{21d}"), ('\u{a76e}', "9"), ('\u{a777}', "tf"), ('\u{a778}', "&"), ('\u{a77a}', "\u{a779}"),
+  ('\u{a789}', ":"), ('\u{a78c}', "'"), ('\u{a78f}', "\u{b7}"), ('\u{a795}', "\u{a727}"), ('\u{a798}', "F"), ('\u{a799}', "f"),
+  ('\u{a79a}', "\u{10412}"), ('\u{a79b}', "\u{1043a}"), ('\u{a79d}', "\u{29a}"), ('\u{a79e}', "\u{a4e4}"), ('\u{a79f}', "u"), ('\u{a7ab}', "3"),
+  ('\u{a7b1}', "\u{a4d5}"), ('\u{a7b2}', "J"), ('\u{a7b3}', "X"), ('\u{a7b4}', "B"), ('\u{a7b5}', "\u{df}"), ('\u{a7b6}', "\u{a64c}"),
+  ('\u{a7b7}', "\u{3c9}"), ('\u{a7d6}', "\u{df}"), ('\u{a7da}', "\u{245}"), ('\u{a7db}', "\u{3bb}"), ('\u{a7dc}', "\u{245}\u{338}"), ('\u{a7f7}', "\u{30fc}"),
+  ('\u{a830}', "\u{964}"), ('\u{a960}', "\u{1103}\u{1106}"), ('\u{a961}', "\u{1103}\u{1107}"), ('\u{a962}', "\u{1103}\u{1109}"), ('\u{a963}', "\u{1103}\u{110c}"), ('\u{a964}', "\u{1105}\u{1100}"),
+  ('\u{a965}', "\u{1105}\u{1100}\u{1100}"), ('\u{a966}', "\u{1105}\u{1103}"), ('\u{a967}', "\u{1105}\u{1103}\u{1103}"), ('\u{a968}', "\u{1105}\u{1106}"), ('\u{a969}', "\u{1105}\u{1107}"), ('\u{a96a}', "\u{1105}\u{1107}\u{1107}"),
+  ('\u{a96b}', "\u{1105}\u{1107}\u{110b}"), ('\u{a96c}', "\u{1105}\u{1109}"), ('\u{a96d}', "\u{1105}\u{110c}"), ('\u{a96e}', "\u{1105}\u{110f}"), ('\u{a96f}', "\u{1106}\u{1100}"), ('\u{a970}', "\u{1106}\u{1103}"),
+  ('\u{a971}', "\u{1106}\u{1109}"), ('\u{a972}', "\u{1107}\u{1109}\u{1110}"), ('\u{a973}', "\u{1107}\u{110f}"), ('\u{a974}', "\u{1107}\u{1112}"), ('\u{a975}', "\u{1109}\u{1109}\u{1107}"), ('\u{a976}', "\u{110b}\u{1105}"),
+  ('\u{a977}', "\u{110b}\u{1112}"), ('\u{a978}', "\u{110c}\u{110c}\u{1112}"), ('\u{a979}', "\u{1110}\u{1110}"), ('\u{a97a}', "\u{1111}\u{1112}"), ('\u{a97b}', "\u{1112}\u{1109}"), ('\u{a97c}', "\u{1159}\u{1159}"),
+  ('\u{a992}', "\u{2c3f}"), ('\u{a9a3}', "\u{a99d}"), ('\u{a9c6}', "\u{a9d0}"), ('\u{a9cf}', "\u{662}"), ('\u{aa53}', "\u{aa01}"), ('\u{aa56}', "\u{aa23}"),
+  ('\u{ab32}', "e"), ('\u{ab35}', "f"), ('\u{ab3d}', "o"), ('\u{ab3e}', "o\u{338}"), ('\u{ab3f}', "\u{254}\u{338}"), ('\u{ab41}', "\u{1dd}o\u{338}"),
+  ('\u{ab42}', "\u{1dd}o\u{335}"), ('\u{ab47}', "r"), ('\u{ab48}', "r"), ('\u{ab4d}', "\u{283}"), ('\u{ab4e}', "u"), ('\u{ab52}', "u"),
+  ('\u{ab53}', "\u{3c7}"), ('\u{ab55}', "\u{3c7}"), ('\u{ab5a}', "y"), ('\u{ab60}', "\u{459}"), ('\u{ab62}', "\u{254}e"), ('\u{ab63}', "uo"),
+  ('\u{ab70}', "\u{1d05}"), ('\u{ab71}', "\u{280}"), ('\u{ab72}', "\u{1d1b}"), ('\u{ab74}', "o\u{31b}"), ('\u{ab75}', "i"), ('\u{ab7a}', "\u{1d00}"),
+  ('\u{ab7b}', "\u{1d0a}"), ('\u{ab7c}', "\u{1d07}"), ('\u{ab7e}', "\u{242}"), ('\u{ab80}', "\u{2c76}"), ('\u{ab81}', "r"), ('\u{ab83}', "w"),
+  ('\u{ab87}', "\u{28d}"), ('\u{ab8b}', "\u{29c}"), ('\u{ab8e}', "o\u{335}"), ('\u{ab90}', "\u{262}"), ('\u{ab93}', "z"), ('\u{ab9b}', "\u{a793}"),
+  ('\u{ab9c}', "u\u{335}"), ('\u{ab9f}', "\u{185}"), ('\u{aba2}', "\u{280}"), ('\u{aba9}', "v"), ('\u{abaa}', "s"), ('\u{abae}', "\u{29f}"),
+  ('\u{abaf}', "c"), ('\u{abb2}', "\u{1d18}"), ('\u{abb6}', "\u{138}"), ('\u{abbb}', "o\u{335}"), ('\u{d7b0}', "\u{1169}\u{1167}"), ('\u{d7b1}', "\u{1169}\u{1169}\u{4e28}"),
+  ('\u{d7b2}', "\u{116d}\u{1161}"), ('\u{d7b3}', "\u{116d}\u{1161}\u{4e28}"), ('\u{d7b4}', "\u{116d}\u{1165}"), ('\u{d7b5}', "\u{116e}\u{1167}"), ('\u{d7b6}', "\u{116e}\u{4e28}\u{4e28}"), ('\u{d7b7}', "\u{1172}\u{1161}\u{4e28}"),
+  ('\u{d7b8}', "\u{1172}\u{1169}"), ('\u{d7b9}', "\u{30fc}\u{1161}"), ('\u{d7ba}', "\u{30fc}\u{1165}"), ('\u{d7bb}', "\u{30fc}\u{1165}\u{4e28}"), ('\u{d7bc}', "\u{30fc}\u{1169}"), ('\u{d7bd}', "\u{4e28}\u{1163}\u{1169}"),
+  ('\u{d7be}', "\u{4e28}\u{1163}\u{4e28}"), ('\u{d7bf}', "\u{4e28}\u{1167}"), ('\u{d7c0}', "\u{4e28}\u{1167}\u{4e28}"), ('\u{d7c1}', "\u{4e28}\u{1169}\u{4e28}"), ('\u{d7c2}', "\u{4e28}\u{116d}"), ('\u{d7c3}', "\u{4e28}\u{1172}"),
+  ('\u{d7c4}', "\u{4e28}\u{4e28}"), ('\u{d7c5}', "\u{119e}\u{1161}"), ('\u{d7c6}', "\u{119e}\u{1165}\u{4e28}"), ('\u{d7cb}', "\u{1102}\u{1105}"), ('\u{d7cc}', "\u{1102}\u{110e}"), ('\u{d7cd}', "\u{1103}\u{1103}"),
+  ('\u{d7ce}', "\u{1103}\u{1103}\u{1107}"), ('\u{d7cf}', "\u{1103}\u{1107}"), ('\u{d7d0}', "\u{1103}\u{1109}"), ('\u{d7d1}', "\u{1103}\u{1109}\u{1100}"), ('\u{d7d2}', "\u{1103}\u{110c}"), ('\u{d7d3}', "\u{1103}\u{110e}"),
+  ('\u{d7d4}', "\u{1103}\u{1110}"), ('\u{d7d5}', "\u{1105}\u{1100}\u{1100}"), ('\u{d7d6}', "\u{1105}\u{1100}\u{1112}"), ('\u{d7d7}', "\u{1105}\u{1105}\u{110f}"), ('\u{d7d8}', "\u{1105}\u{1106}\u{1112}"), ('\u{d7d9}', "\u{1105}\u{1107}\u{1103}"),
+  ('\u{d7da}', "\u{1105}\u{1107}\u{1111}"), ('\u{d7db}', "\u{1105}\u{114c}"), ('\u{d7dc}', "\u{1105}\u{1159}\u{1112}"), ('\u{d7dd}', "\u{1105}\u{110b}"), ('\u{d7de}', "\u{1106}\u{1102}"), ('\u{d7df}', "\u{1106}\u{1102}\u{1102}"),
+  ('\u{d7e0}', "\u{1106}\u{1106}"), ('\u{d7e1}', "\u{1106}\u{1107}\u{1109}"), ('\u{d7e2}', "\u{1106}\u{110c}"), ('\u{d7e3}', "\u{1107}\u{1103}"), ('\u{d7e4}', "\u{1107}\u{1105}\u{1111}"), ('\u{d7e5}', "\u{1107}\u{1106}"),
+  ('\u{d7e6}', "\u{1107}\u{1107}"), ('\u{d7e7}', "\u{1107}\u{1109}\u{1103}"), ('\u{d7e8}', "\u{1107}\u{110c}"), ('\u{d7e9}', "\u{1107}\u{110e}"), ('\u{d7ea}', "\u{1109}\u{1106}"), ('\u{d7eb}', "\u{1109}\u{1107}\u{110b}"),
+  ('\u{d7ec}', "\u{1109}\u{1109}\u{1100}"), ('\u{d7ed}', "\u{1109}\u{1109}\u{1103}"), ('\u{d7ee}', "\u{1109}\u{1140}"), ('\u{d7ef}', "\u{1109}\u{110c}"), ('\u{d7f0}', "\u{1109}\u{110e}"), ('\u{d7f1}', "\u{1109}\u{1110}"),
+  ('\u{d7f2}', "\u{1105}\u{1112}"), ('\u{d7f3}', "\u{1140}\u{1107}"), ('\u{d7f4}', "\u{1140}\u{1107}\u{110b}"), ('\u{d7f5}', "\u{114c}\u{1106}"), ('\u{d7f6}', "\u{114c}\u{1112}"), ('\u{d7f7}', "\u{110c}\u{1107}"),
+  ('\u{d7f8}', "\u{110c}\u{1107}\u{1107}"), ('\u{d7f9}', "\u{110c}\u{110c}"), ('\u{d7fa}', "\u{1111}\u{1109}"), ('\u{d7fb}', "\u{1111}\u{1110}"), ('\u{f900}', "\u{8c48}"), ('\u{f901}', "\u{66f4}"),
+  ('\u{f902}', "\u{8eca}"), ('\u{f903}', "\u{8cc8}"), ('\u{f904}', "\u{6ed1}"), ('\u{f905}', "\u{4e32}"), ('\u{f906}', "\u{53e5}"), ('\u{f907}', "\u{9f9c}"),
+  ('\u{f908}', "\u{9f9c}"), ('\u{f909}', "\u{5951}"), ('\u{f90a}', "\u{91d1}"), ('\u{f90b}', "\u{5587}"), ('\u{f90c}', "\u{5948}"), ('\u{f90d}', "\u{61f6}"),
+  ('\u{f90e}', "\u{7669}"), ('\u{f90f}', "\u{7f85}"), ('\u{f910}', "\u{863f}"), ('\u{f911}', "\u{87ba}"), ('\u{f912}', "\u{88f8}"), ('\u{f913}', "\u{908f}"),
+  ('\u{f914}', "\u{6a02}"), ('\u{f915}', "\u{6d1b}"), ('\u{f916}', "\u{70d9}"), ('\u{f917}', "\u{73de}"), ('\u{f918}', "\u{843d}"), ('\u{f919}', "\u{916a}"),
+  ('\u{f91a}', "\u{99f1}"), ('\u{f91b}', "\u{4e82}"), ('\u{f91c}', "\u{5375}"), ('\u{f91d}', "\u{6b04}"), ('\u{f91e}', "\u{721b}"), ('\u{f91f}', "\u{862d}"),
+  ('\u{f920}', "\u{9e1e}"), ('\u{f921}', "\u{5d50}"), ('\u{f922}', "\u{6feb}"), ('\u{f923}', "\u{85cd}"), ('\u{f924}', "\u{8964}"), ('\u{f925}', "\u{62c9}"),
+  ('\u{f926}', "\u{81d8}"), ('\u{f927}', "\u{881f}"), ('\u{f928}', "\u{5eca}"), ('\u{f929}', "\u{6717}"), ('\u{f92a}', "\u{6d6a}"), ('\u{f92b}', "\u{72fc}"),
+  ('\u{f92c}', "\u{90ce}"), ('\u{f92d}', "\u{4f86}"), ('\u{f92e}', "\u{51b7}"), ('\u{f92f}', "\u{52de}"), ('\u{f930}', "\u{64c4}"), ('\u{f931}', "\u{6ad3}"),
+  ('\u{f932}', "\u{7210}"), ('\u{f933}', "\u{76e7}"), ('\u{f934}', "\u{8001}"), ('\u{f935}', "\u{8606}"), ('\u{f936}', "\u{865c}"), ('\u{f937}', "\u{8def}"),
+  ('\u{f938}', "\u{9732}"), ('\u{f939}', "\u{9b6f}"), ('\u{f93a}', "\u{9dfa}"), ('\u{f93b}', "\u{788c}"), ('\u{f93c}', "\u{797f}"), ('\u{f93d}', "\u{7da0}"),
+  ('\u{f93e}', "\u{83c9}"), ('\u{f93f}', "\u{9304}"), ('\u{f940}', "\u{9e7f}"), ('\u{f941}', "\u{8ad6}"), ('\u{f942}', "\u{58df}"), ('\u{f943}', "\u{5f04}"),
+  ('\u{f944}', "\u{7c60}"), ('\u{f945}', "\u{807e}"), ('\u{f946}', "\u{7262}"), ('\u{f947}', "\u{78ca}"), ('\u{f948}', "\u{8cc2}"), ('\u{f949}', "\u{96f7}"),
+  ('\u{f94a}', "\u{58d8}"), ('\u{f94b}', "\u{5c62}"), ('\u{f94c}', "\u{6a13}"), ('\u{f94d}', "\u{6dda}"), ('\u{f94e}', "\u{6f0f}"), ('\u{f94f}', "\u{7d2f}"),
+  ('\u{f950}', "\u{7e37}"), ('\u{f951}', "\u{964b}"), ('\u{f952}', "\u{52d2}"), ('\u{f953}', "\u{808b}"), ('\u{f954}', "\u{51dc}"), ('\u{f955}', "\u{51cc}"),
+  ('\u{f956}', "\u{7a1c}"), ('\u{f957}', "\u{7dbe}"), ('\u{f958}', "\u{83f1}"), ('\u{f959}', "\u{9675}"), ('\u{f95a}', "\u{8b80}"), ('\u{f95b}', "\u{62cf}"),
+  ('\u{f95c}', "\u{6a02}"), ('\u{f95d}', "\u{8afe}"), ('\u{f95e}', "\u{4e39}"), ('\u{f95f}', "\u{5be7}"), ('\u{f960}', "\u{6012}"), ('\u{f961}', "\u{7387}"),
+  ('\u{f962}', "\u{7570}"), ('\u{f963}', "\u{5317}"), ('\u{f964}', "\u{78fb}"), ('\u{f965}', "\u{4fbf}"), ('\u{f966}', "\u{5fa9}"), ('\u{f967}', "\u{4e0d}"),
+  ('\u{f968}', "\u{6ccc}"), ('\u{f969}', "\u{6578}"), ('\u{f96a}', "\u{7d22}"), ('\u{f96b}', "\u{53c3}"), ('\u{f96c}', "\u{585e}"), ('\u{f96d}', "\u{7701}"),
+  ('\u{f96e}', "\u{8449}"), ('\u{f96f}', "\u{8aaa}"), ('\u{f970}', "\u{6bba}"), ('\u{f971}', "\u{8fb0}"), ('\u{f972}', "\u{6c88}"), ('\u{f973}', "\u{62fe}"),
+  ('\u{f974}', "\u{82e5}"), ('\u{f975}', "\u{63a0}"), ('\u{f976}', "\u{7565}"), ('\u{f977}', "\u{4eae}"), ('\u{f978}', "\u{5169}"), ('\u{f979}', "\u{51c9}"),
+  ('\u{f97a}', "\u{6881}"), ('\u{f97b}', "\u{7ce7}"), ('\u{f97c}', "\u{826f}"), ('\u{f97d}', "\u{8ad2}"), ('\u{f97e}', "\u{91cf}"), ('\u{f97f}', "\u{52f5}"),
+  ('\u{f980}', "\u{5442}"), ('\u{f981}', "\u{5973}"), ('\u{f982}', "\u{5eec}"), ('\u{f983}', "\u{65c5}"), ('\u{f984}', "\u{6ffe}"), ('\u{f985}', "\u{792a}"),
+  ('\u{f986}', "\u{95ad}"), ('\u{f987}', "\u{9a6a}"), ('\u{f988}', "\u{9e97}"), ('\u{f989}', "\u{9ece}"), ('\u{f98a}', "\u{529b}"), ('\u{f98b}', "\u{66c6}"),
+  ('\u{f98c}', "\u{6b77}"), ('\u{f98d}', "\u{8f62}"), ('\u{f98e}', "\u{5e74}"), ('\u{f98f}', "\u{6190}"), ('\u{f990}', "\u{6200}"), ('\u{f991}', "\u{649a}"),
+  ('\u{f992}', "\u{6f23}"), ('\u{f993}', "\u{7149}"), ('\u{f994}', "\u{7489}"), ('\u{f995}', "\u{79ca}"), ('\u{f996}', "\u{7df4}"), ('\u{f997}', "\u{806f}"),
+  ('\u{f998}', "\u{8f26}"), ('\u{f999}', "\u{84ee}"), ('\u{f99a}', "\u{9023}"), ('\u{f99b}', "\u{934a}"), ('\u{f99c}', "\u{5217}"), ('\u{f99d}', "\u{52a3}"),
+  ('\u{f99e}', "\u{54bd}"), ('\u{f99f}', "\u{70c8}"), ('\u{f9a0}', "\u{88c2}"), ('\u{f9a1}', "\u{8aaa}"), ('\u{f9a2}', "\u{5ec9}"), ('\u{f9a3}', "\u{5ff5}"),
+  ('\u{f9a4}', "\u{637b}"), ('\u{f9a5}', "\u{6bae}"), ('\u{f9a6}', "\u{7c3e}"), ('\u{f9a7}', "\u{7375}"), ('\u{f9a8}', "\u{4ee4}"), ('\u{f9a9}', "\u{56f9}"),
+  ('\u{f9aa}', "\u{5be7}"), ('\u{f9ab}', "\u{5dba}"), ('\u{f9ac}', "\u{601c}"), ('\u{f9ad}', "\u{73b2}"), ('\u{f9ae}', "\u{7469}"), ('\u{f9af}', "\u{7f9a}"),
+  ('\u{f9b0}', "\u{8046}"), ('\u{f9b1}', "\u{9234}"), ('\u{f9b2}', "\u{96f6}"), ('\u{f9b3}', "\u{9748}"), ('\u{f9b4}', "\u{9818}"), ('\u{f9b5}', "\u{4f8b}"),
+  ('\u{f9b6}', "\u{79ae}"), ('\u{f9b7}', "\u{91b4}"), ('\u{f9b8}', "\u{96b7}"), ('\u{f9b9}', "\u{60e1}"), ('\u{f9ba}', "\u{4e86}"), ('\u{f9bb}', "\u{50da}"),
+  ('\u{f9bc}', "\u{5bee}"), ('\u{f9bd}', "\u{5c3f}"), ('\u{f9be}', "\u{6599}"), ('\u{f9bf}', "\u{6a02}"), ('\u{f9c0}', "\u{71ce}"), ('\u{f9c1}', "\u{7642}"),
+  ('\u{f9c2}', "\u{84fc}"), ('\u{f9c3}', "\u{907c}"), ('\u{f9c4}', "\u{9f8d}"), ('\u{f9c5}', "\u{6688}"), ('\u{f9c6}', "\u{962e}"), ('\u{f9c7}', "\u{5289}"),
+  ('\u{f9c8}', "\u{677b}"), ('\u{f9c9}', "\u{67f3}"), ('\u{f9ca}', "\u{6d41}"), ('\u{f9cb}', "\u{6e9c}"), ('\u{f9cc}', "\u{7409}"), ('\u{f9cd}', "\u{7559}"),
+  ('\u{f9ce}', "\u{786b}"), ('\u{f9cf}', "\u{7d10}"), ('\u{f9d0}', "\u{985e}"), ('\u{f9d1}', "\u{516d}"), ('\u{f9d2}', "\u{622e}"), ('\u{f9d3}', "\u{9678}"),
+  ('\u{f9d4}', "\u{502b}"), ('\u{f9d5}', "\u{5d19}"), ('\u{f9d6}', "\u{6dea}"), ('\u{f9d7}', "\u{8f2a}"), ('\u{f9d8}', "\u{5f8b}"), ('\u{f9d9}', "\u{6144}"),
+  ('\u{f9da}', "\u{6817}"), ('\u{f9db}', "\u{7387}"), ('\u{f9dc}', "\u{9686}"), ('\u{f9dd}', "\u{5229}"), ('\u{f9de}', "\u{540f}"), ('\u{f9df}', "\u{5c65}"),
+  ('\u{f9e0}', "\u{6613}"), ('\u{f9e1}', "\u{674e}"), ('\u{f9e2}', "\u{68a8}"), ('\u{f9e3}', "\u{6ce5}"), ('\u{f9e4}', "\u{7406}"), ('\u{f9e5}', "\u{75e2}"),
+  ('\u{f9e6}', "\u{7f79}"), ('\u{f9e7}', "\u{88cf}"), ('\u{f9e8}', "\u{88e1}"), ('\u{f9e9}', "\u{91cc}"), ('\u{f9ea}', "\u{96e2}"), ('\u{f9eb}', "\u{533f}"),
+  ('\u{f9ec}', "\u{6eba}"), ('\u{f9ed}', "\u{541d}"), ('\u{f9ee}', "\u{71d0}"), ('\u{f9ef}', "\u{7498}"), ('\u{f9f0}', "\u{85fa}"), ('\u{f9f1}', "\u{96a3}"),
+  ('\u{f9f2}', "\u{9c57}"), ('\u{f9f3}', "\u{9e9f}"), ('\u{f9f4}', "\u{6797}"), ('\u{f9f5}', "\u{6dcb}"), ('\u{f9f6}', "\u{81e8}"), ('\u{f9f7}', "\u{7acb}"),
+  ('\u{f9f8}', "\u{7b20}"), ('\u{f9f9}', "\u{7c92}"), ('\u{f9fa}', "\u{72c0}"), ('\u{f9fb}', "\u{7099}"), ('\u{f9fc}', "\u{8b58}"), ('\u{f9fd}', "\u{4ec0}"),
+  ('\u{f9fe}', "\u{8336}"), ('\u{f9ff}', "\u{523a}"), ('\u{fa00}', "\u{5207}"), ('\u{fa01}', "\u{5ea6}"), ('\u{fa02}', "\u{62d3}"), ('\u{fa03}', "\u{7cd6}"),
+  ('\u{fa04}', "\u{5b85}"), ('\u{fa05}', "\u{6d1e}"), ('\u{fa06}', "\u{66b4}"), ('\u{fa07}', "\u{8f3b}"), ('\u{fa08}', "\u{884c}"), ('\u{fa09}', "\u{964d}"),
+  ('\u{fa0a}', "\u{898b}"), ('\u{fa0b}', "\u{5ed3}"), ('\u{fa0c}', "\u{5140}"), ('\u{fa0d}', "\u{55c0}"), ('\u{fa10}', "\u{585a}"), ('\u{fa12}', "\u{6674}"),
+  ('\u{fa15}', "\u{51de}"), ('\u{fa16}', "\u{732a}"), ('\u{fa17}', "\u{76ca}"), ('\u{fa18}', "\u{793c}"), ('\u{fa19}', "\u{795e}"), ('\u{fa1a}', "\u{7965}"),
+  ('\u{fa1b}', "\u{798f}"), ('\u{fa1c}', "\u{9756}"), ('\u{fa1d}', "\u{7cbe}"), ('\u{fa1e}', "\u{7fbd}"), ('\u{fa20}', "\u{8612}"), ('\u{fa22}', "\u{8af8}"),
+  ('\u{fa25}', "\u{9038}"), ('\u{fa26}', "\u{90fd}"), ('\u{fa2a}', "\u{98ef}"), ('\u{fa2b}', "\u{98fc}"), ('\u{fa2c}', "\u{9928}"), ('\u{fa2d}', "\u{9db4}"),
+  ('\u{fa2e}', "\u{90ce}"), ('\u{fa2f}', "\u{96b7}"), ('\u{fa30}', "\u{4fae}"), ('\u{fa31}', "\u{50e7}"), ('\u{fa32}', "\u{514d}"), ('\u{fa33}', "\u{52c9}"),
+  ('\u{fa34}', "\u{52e4}"), ('\u{fa35}', "\u{5351}"), ('\u{fa36}', "\u{559d}"), ('\u{fa37}', "\u{5606}"), ('\u{fa38}', "\u{5668}"), ('\u{fa39}', "\u{5840}"),
+  ('\u{fa3a}', "\u{58a8}"), ('\u{fa3b}', "\u{5c64}"), ('\u{fa3c}', "\u{5c6e}"), ('\u{fa3d}', "\u{6094}"), ('\u{fa3e}', "\u{6168}"), ('\u{fa3f}', "\u{618e}"),
+  ('\u{fa40}', "\u{61f2}"), ('\u{fa41}', "\u{654f}"), ('\u{fa42}', "\u{65e2}"), ('\u{fa43}', "\u{6691}"), ('\u{fa44}', "\u{6885}"), ('\u{fa45}', "\u{6d77}"),
+  ('\u{fa46}', "\u{6e1a}"), ('\u{fa47}', "\u{6f22}"), ('\u{fa48}', "\u{716e}"), ('\u{fa49}', "\u{722b}"), ('\u{fa4a}', "\u{7422}"), ('\u{fa4b}', "\u{7891}"),
+  ('\u{fa4c}', "\u{793e}"), ('\u{fa4d}', "\u{7949}"), ('\u{fa4e}', "\u{7948}"), ('\u{fa4f}', "\u{7950}"), ('\u{fa50}', "\u{7956}"), ('\u{fa51}', "\u{795d}"),
+  ('\u{fa52}', "\u{798d}"), ('\u{fa53}', "\u{798e}"), ('\u{fa54}', "\u{7a40}"), ('\u{fa55}', "\u{7a81}"), ('\u{fa56}', "\u{7bc0}"), ('\u{fa57}', "\u{7df4}"),
+  ('\u{fa58}', "\u{7e09}"), ('\u{fa59}', "\u{7e41}"), ('\u{fa5a}', "\u{7f72}"), ('\u{fa5b}', "\u{8005}"), ('\u{fa5c}', "\u{81ed}"), ('\u{fa5d}', "\u{8279}"),
+  ('\u{fa5e}', "\u{8279}"), ('\u{fa5f}', "\u{8457}"), ('\u{fa60}', "\u{8910}"), ('\u{fa61}', "\u{8996}"), ('\u{fa62}', "\u{8b01}"), ('\u{fa63}', "\u{8b39}"),
+  ('\u{fa64}', "\u{8cd3}"), ('\u{fa65}', "\u{8d08}"), ('\u{fa66}', "\u{8fb6}"), ('\u{fa67}', "\u{9038}"), ('\u{fa68}', "\u{96e3}"), ('\u{fa69}', "\u{97ff}"),
+  ('\u{fa6a}', "\u{983b}"), ('\u{fa6b}', "\u{6075}"), ('\u{fa6c}', "\u{242ee}"), ('\u{fa6d}', "\u{8218}"), ('\u{fa70}', "\u{4e26}"), ('\u{fa71}', "\u{51b5}"),
+  ('\u{fa72}', "\u{5168}"), ('\u{fa73}', "\u{4f80}"), ('\u{fa74}', "\u{5145}"), ('\u{fa75}', "\u{5180}"), ('\u{fa76}', "\u{52c7}"), ('\u{fa77}', "\u{52fa}"),
+  ('\u{fa78}', "\u{559d}"), ('\u{fa79}', "\u{5555}"), ('\u{fa7a}', "\u{5599}"), ('\u{fa7b}', "\u{55e2}"), ('\u{fa7c}', "\u{585a}"), ('\u{fa7d}', "\u{58b3}"),
+  ('\u{fa7e}', "\u{5944}"), ('\u{fa7f}', "\u{5954}"), ('\u{fa80}', "\u{5a62}"), ('\u{fa81}', "\u{5b28}"), ('\u{fa82}', "\u{5ed2}"), ('\u{fa83}', "\u{5ed9}"),
+  ('\u{fa84}', "\u{5f69}"), ('\u{fa85}', "\u{5fad}"), ('\u{fa86}', "\u{60d8}"), ('\u{fa87}', "\u{614e}"), ('\u{fa88}', "\u{6108}"), ('\u{fa89}', "\u{618e}"),
+  ('\u{fa8a}', "\u{6160}"), ('\u{fa8b}', "\u{61f2}"), ('\u{fa8c}', "\u{6234}"), ('\u{fa8d}', "\u{63c4}"), ('\u{fa8e}', "\u{641c}"), ('\u{fa8f}', "\u{6452}"),
+  ('\u{fa90}', "\u{6556}"), ('\u{fa91}', "\u{6674}"), ('\u{fa92}', "\u{6717}"), ('\u{fa93}', "\u{671b}"), ('\u{fa94}', "\u{6756}"), ('\u{fa95}', "\u{6b79}"),
+  ('\u{fa96}', "\u{6bba}"), ('\u{fa97}', "\u{6d41}"), ('\u{fa98}', "\u{6edb}"), ('\u{fa99}', "\u{6ecb}"), ('\u{fa9a}', "\u{6f22}"), ('\u{fa9b}', "\u{701e}"),
+  ('\u{fa9c}', "\u{716e}"), ('\u{fa9d}', "\u{77a7}"), ('\u{fa9e}', "\u{7235}"), ('\u{fa9f}', "\u{72af}"), ('\u{faa0}', "\u{732a}"), ('\u{faa1}', "\u{7471}"),
+  ('\u{faa2}', "\u{7506}"), ('\u{faa3}', "\u{753b}"), ('\u{faa4}', "\u{761d}"), ('\u{faa5}', "\u{761f}"), ('\u{faa6}', "\u{76ca}"), ('\u{faa7}', "\u{76db}"),
+  ('\u{faa8}', "\u{76f4}"), ('\u{faa9}', "\u{774a}"), ('\u{faaa}', "\u{7740}"), ('\u{faab}', "\u{78cc}"), ('\u{faac}', "\u{7ab1}"), ('\u{faad}', "\u{7bc0}"),
+  ('\u{faae}', "\u{7c7b}"), ('\u{faaf}', "\u{7d5b}"), ('\u{fab0}', "\u{7df4}"), ('\u{fab1}', "\u{7f3e}"), ('\u{fab2}', "\u{8005}"), ('\u{fab3}', "\u{8352}"),
+  ('\u{fab4}', "\u{83ef}"), ('\u{fab5}', "\u{8779}"), ('\u{fab6}', "\u{8941}"), ('\u{fab7}', "\u{8986}"), ('\u{fab8}', "\u{8996}"), ('\u{fab9}', "\u{8abf}"),
+  ('\u{faba}', "\u{8af8}"), ('\u{fabb}', "\u{8acb}"), ('\u{fabc}', "\u{8b01}"), ('\u{fabd}', "\u{8afe}"), ('\u{fabe}', "\u{8aed}"), ('\u{fabf}', "\u{8b39}"),
+  ('\u{fac0}', "\u{8b8a}"), ('\u{fac1}', "\u{8d08}"), ('\u{fac2}', "\u{8f38}"), ('\u{fac3}', "\u{9072}"), ('\u{fac4}', "\u{9199}"), ('\u{fac5}', "\u{9276}"),
+  ('\u{fac6}', "\u{967c}"), ('\u{fac7}', "\u{96e3}"), ('\u{fac8}', "\u{9756}"), ('\u{fac9}', "\u{97db}"), ('\u{faca}', "\u{97ff}"), ('\u{facb}', "\u{980b}"),
+  ('\u{facc}', "\u{983b}"), ('\u{facd}', "\u{9b12}"), ('\u{face}', "\u{9f9c}"), ('\u{facf}', "\u{2284a}"), ('\u{fad0}', "\u{22844}"), ('\u{fad1}', "\u{233d5}"),
+  ('\u{fad2}', "\u{3b9d}"), ('\u{fad3}', "\u{4018}"), ('\u{fad4}', "\u{4039}"), ('\u{fad5}', "\u{25249}"), ('\u{fad6}', "\u{25cd0}"), ('\u{fad7}', "\u{27ed3}"),
+  ('\u{fad8}', "\u{9f43}"), ('\u{fad9}', "\u{9f8e}"), ('\u{fb00}', "ff"), ('\u{fb01}', "fi"), ('\u{fb02}', "fl"), ('\u{fb03}', "ffi"),
+  ('\u{fb04}', "ffl"), ('\u{fb06}', "st"), ('\u{fb13}', "\u{574}\u{576}"), ('\u{fb14}', "\u{574}\u{565}"), ('\u{fb15}', "\u{574}\u{56b}"), ('\u{fb16}', "\u{57e}\u{576}"),
+  ('\u{fb17}', "\u{574}\u{56d}"), ('\u{fb20}', "\u{5e2}"), ('\u{fb21}', "\u{5d0}"), ('\u{fb22}', "\u{5d3}"), ('\u{fb23}', "\u{5d4}"), ('\u{fb24}', "\u{5db}"),
+  ('\u{fb25}', "\u{5dc}"), ('\u{fb26}', "\u{5dd}"), ('\u{fb27}', "\u{5e8}"), ('\u{fb28}', "\u{5ea}"), ('\u{fb29}', "-\u{307}"), ('\u{fb2b}', "\u{fb2a}"),
+  ('\u{fb2d}', "\u{fb2c}"), ('\u{fb2f}', "\u{fb2e}"), ('\u{fb30}', "\u{fb2e}"), ('\u{fb39}', "\u{fb1d}"), ('\u{fb49}', "\u{fb2a}"), ('\u{fb4f}', "\u{5d0}\u{5dc}"),
+  ('\u{fb50}', "\u{671}"), ('\u{fb51}', "\u{671}"), ('\u{fb52}', "\u{67b}"), ('\u{fb53}', "\u{67b}"), ('\u{fb54}', "\u{67b}"), ('\u{fb55}', "\u{67b}"),
+  ('\u{fb56}', "\u{649}\u{6db}"), ('\u{fb57}', "\u{649}\u{6db}"), ('\u{fb58}', "\u{649}\u{6db}"), ('\u{fb59}', "\u{649}\u{6db}"), ('\u{fb5a}', "\u{680}"), ('\u{fb5b}', "\u{680}"),
+  ('\u{fb5c}', "\u{680}"), ('\u{fb5d}', "\u{680}"), ('\u{fb5e}', "\u{67a}"), ('\u{fb5f}', "\u{67a}"), ('\u{fb60}', "\u{67a}"), ('\u{fb61}', "\u{67a}"),
+  ('\u{fb62}', "\u{67f}"), ('\u{fb63}', "\u{67f}"), ('\u{fb64}', "\u{67f}"), ('\u{fb65}', "\u{67f}"), ('\u{fb66}', "\u{649}\u{615}"), ('\u{fb67}', "\u{649}\u{615}"),
+  ('\u{fb68}', "\u{649}\u{615}"), ('\u{fb69}', "\u{649}\u{615}"), ('\u{fb6a}', "\u{6a1}\u{6db}"), ('\u{fb6b}', "\u{6a1}\u{6db}"), ('\u{fb6c}', "\u{6a1}\u{6db}"), ('\u{fb6d}', "\u{6a1}\u{6db}"),
+  ('\u{fb6e}', "\u{6a6}"), ('\u{fb6f}', "\u{6a6}"), ('\u{fb70}', "\u{6a6}"), ('\u{fb71}', "\u{6a6}"), ('\u{fb72}', "\u{684}"), ('\u{fb73}', "\u{684}"),
+  ('\u{fb74}', "\u{684}"), ('\u{fb75}', "\u{684}"), ('\u{fb76}', "\u{683}"), ('\u{fb77}', "\u{683}"), ('\u{fb78}', "\u{683}"), ('\u{fb79}', "\u{683}"),
+  ('\u{fb7a}', "\u{686}"), ('\u{fb7b}', "\u{686}"), ('\u{fb7c}', "\u{686}"), ('\u{fb7d}', "\u{686}"), ('\u{fb7e}', "\u{687}"), ('\u{fb7f}', "\u{687}"),
+  ('\u{fb80}', "\u{687}"), ('\u{fb81}', "\u{687}"), ('\u{fb82}', "\u{68d}"), ('\u{fb83}', "\u{68d}"), ('\u{fb84}', "\u{68c}"), ('\u{fb85}', "\u{68c}"),
+  ('\u{fb86}', "\u{62f}\u{6db}"), ('\u{fb87}', "\u{62f}\u{6db}"), ('\u{fb88}', "\u{62f}\u{615}"), ('\u{fb89}', "\u{62f}\u{615}"), ('\u{fb8a}', "\u{631}\u{6db}"), ('\u{fb8b}', "\u{631}\u{6db}"),
+  ('\u{fb8c}', "\u{631}\u{615}"), ('\u{fb8d}', "\u{631}\u{615}"), ('\u{fb8e}', "\u{643}"), ('\u{fb8f}', "\u{643}"), ('\u{fb90}', "\u{643}"), ('\u{fb91}', "\u{643}"),
+  ('\u{fb92}', "\u{6af}"), ('\u{fb93}', "\u{6af}"), ('\u{fb94}', "\u{6af}"), ('\u{fb95}', "\u{6af}"), ('\u{fb96}', "\u{6b3}"), ('\u{fb97}', "\u{6b3}"),
+  ('\u{fb98}', "\u{6b3}"), ('\u{fb99}', "\u{6b3}"), ('\u{fb9a}', "\u{6b1}"), ('\u{fb9b}', "\u{6b1}"), ('\u{fb9c}', "\u{6b1}"), ('\u{fb9d}', "\u{6b1}"),
+  ('\u{fb9e}', "\u{649}"), ('\u{fb9f}', "\u{649}"), ('\u{fba0}', "\u{649}\u{615}"), ('\u{fba1}', "\u{649}\u{615}"), ('\u{fba2}', "\u{649}\u{615}"), ('\u{fba3}', "\u{649}\u{615}"),
+  ('\u{fba4}', "\u{6c0}"), ('\u{fba5}', "\u{6c0}"), ('\u{fba6}', "o"), ('\u{fba7}', "o"), ('\u{fba8}', "o"), ('\u{fba9}', "o"),
+  ('\u{fbaa}', "o"), ('\u{fbab}', "o"), ('\u{fbac}', "o"), ('\u{fbad}', "o"), ('\u{fbae}', "\u{649}"), ('\u{fbaf}', "\u{649}"),
+  ('\u{fbb0}', "\u{6d3}"), ('\u{fbb1}', "\u{6d3}"), ('\u{fbd3}', "\u{643}\u{6db}"), ('\u{fbd4}', "\u{643}\u{6db}"), ('\u{fbd5}', "\u{643}\u{6db}"), ('\u{fbd6}', "\u{643}\u{6db}"),
+  ('\u{fbd7}', "\u{648}\u{313}"), ('\u{fbd8}', "\u{648}\u{313}"), ('\u{fbd9}', "\u{648}\u{306}"), ('\u{fbda}', "\u{648}\u{306}"), ('\u{fbdb}', "\u{648}\u{670}"), ('\u{fbdc}', "\u{648}\u{670}"),
+  ('\u{fbdd}', "\u{648}\u{313}\u{674}"), ('\u{fbde}', "\u{648}\u{6db}"), ('\u{fbdf}', "\u{648}\u{6db}"), ('\u{fbe0}', "\u{6c5}"), ('\u{fbe1}', "\u{6c5}"), ('\u{fbe2}', "\u{648}\u{302}"),
+  ('\u{fbe3}', "\u{648}\u{302}"), ('\u{fbe4}', "\u{67b}"), ('\u{fbe5}', "\u{67b}"), ('\u{fbe6}', "\u{67b}"), ('\u{fbe7}', "\u{67b}"), ('\u{fbe8}', "\u{649}"),
+  ('\u{fbe9}', "\u{649}"), ('\u{fbea}', "\u{649}\u{674}l"), ('\u{fbeb}', "\u{649}\u{674}l"), ('\u{fbec}', "\u{649}\u{674}o"), ('\u{fbed}', "\u{649}\u{674}o"), ('\u{fbee}', "\u{649}\u{674}\u{648}"),
+  ('\u{fbef}', "\u{649}\u{674}\u{648}"), ('\u{fbf0}', "\u{649}\u{674}\u{648}\u{313}"), ('\u{fbf1}', "\u{649}\u{674}\u{648}\u{313}"), ('\u{fbf2}', "\u{649}\u{674}\u{648}\u{306}"), ('\u{fbf3}', "\u{649}\u{674}\u{648}\u{306}"), ('\u{fbf4}', "\u{649}\u{674}\u{648}\u{670}"),
+  ('\u{fbf5}', "\u{649}\u{674}\u{648}\u{670}"), ('\u{fbf6}', "\u{649}\u{674}\u{67b}"), ('\u{fbf7}', "\u{649}\u{674}\u{67b}"), ('\u{fbf8}', "\u{649}\u{674}\u{67b}"), ('\u{fbf9}', "\u{649}\u{674}\u{649}"), ('\u{fbfa}', "\u{649}\u{674}\u{649}"),
+  ('\u{fbfb}', "\u{649}\u{674}\u{649}"), ('\u{fbfc}', "\u{649}"), ('\u{fbfd}', "\u{649}"), ('\u{fbfe}', "\u{649}"), ('\u{fbff}', "\u{649}"), ('\u{fc00}', "\u{649}\u{674}\u{62c}"),
+  ('\u{fc01}', "\u{649}\u{674}\u{62d}"), ('\u{fc02}', "\u{649}\u{674}\u{645}"), ('\u{fc03}', "\u{649}\u{674}\u{649}"), ('\u{fc04}', "\u{649}\u{674}\u{649}"), ('\u{fc05}', "\u{628}\u{62c}"), ('\u{fc06}', "\u{628}\u{62d}"),
+  ('\u{fc07}', "\u{628}\u{62e}"), ('\u{fc08}', "\u{628}\u{645}"), ('\u{fc09}', "\u{628}\u{649}"), ('\u{fc0a}', "\u{628}\u{649}"), ('\u{fc0b}', "\u{62a}\u{62c}"), ('\u{fc0c}', "\u{62a}\u{62d}"),
+  ('\u{fc0d}', "\u{62a}\u{62e}"), ('\u{fc0e}', "\u{62a}\u{645}"), ('\u{fc0f}', "\u{62a}\u{649}"), ('\u{fc10}', "\u{62a}\u{649}"), ('\u{fc11}', "\u{649}\u{6db}\u{62c}"), ('\u{fc12}', "\u{649}\u{6db}\u{645}"),
+  ('\u{fc13}', "\u{649}\u{6db}\u{649}"), ('\u{fc14}', "\u{649}\u{6db}\u{649}"), ('\u{fc15}', "\u{62c}\u{62d}"), ('\u{fc16}', "\u{62c}\u{645}"), ('\u{fc17}', "\u{62d}\u{62c}"), ('\u{fc18}', "\u{62d}\u{645}"),
+  ('\u{fc19}', "\u{62e}\u{62c}"), ('\u{fc1a}', "\u{62e}\u{62d}"), ('\u{fc1b}', "\u{62e}\u{645}"), ('\u{fc1c}', "\u{633}\u{62c}"), ('\u{fc1d}', "\u{633}\u{62d}"), ('\u{fc1e}', "\u{633}\u{62e}"),
+  ('\u{fc1f}', "\u{633}\u{645}"), ('\u{fc20}', "\u{635}\u{62d}"), ('\u{fc21}', "\u{635}\u{645}"), ('\u{fc22}', "\u{636}\u{62c}"), ('\u{fc23}', "\u{636}\u{62d}"), ('\u{fc24}', "\u{636}\u{62e}"),
+  ('\u{fc25}', "\u{636}\u{645}"), ('\u{fc26}', "\u{637}\u{62d}"), ('\u{fc27}', "\u{637}\u{645}"), ('\u{fc28}', "\u{638}\u{645}"), ('\u{fc29}', "\u{639}\u{62c}"), ('\u{fc2a}', "\u{639}\u{645}"),
+  ('\u{fc2b}', "\u{63a}\u{62c}"), ('\u{fc2c}', "\u{63a}\u{645}"), ('\u{fc2d}', "\u{641}\u{62c}"), ('\u{fc2e}', "\u{641}\u{62d}"), ('\u{fc2f}', "\u{641}\u{62e}"), ('\u{fc30}', "\u{641}\u{645}"),
+  ('\u{fc31}', "\u{641}\u{649}"), ('\u{fc32}', "\u{641}\u{649}"), ('\u{fc33}', "\u{642}\u{62d}"), ('\u{fc34}', "\u{642}\u{645}"), ('\u{fc35}', "\u{642}\u{649}"), ('\u{fc36}', "\u{642}\u{649}"),
+  ('\u{fc37}', "\u{643}l"), ('\u{fc38}', "\u{643}\u{62c}"), ('\u{fc39}', "\u{643}\u{62d}"), ('\u{fc3a}', "\u{643}\u{62e}"), ('\u{fc3b}', "\u{643}\u{644}"), ('\u{fc3c}', "\u{643}\u{645}"),
+  ('\u{fc3d}', "\u{643}\u{649}"), ('\u{fc3e}', "\u{643}\u{649}"), ('\u{fc3f}', "\u{644}\u{62c}"), ('\u{fc40}', "\u{644}\u{62d}"), ('\u{fc41}', "\u{644}\u{62e}"), ('\u{fc42}', "\u{644}\u{645}"),
+  ('\u{fc43}', "\u{644}\u{649}"), ('\u{fc44}', "\u{644}\u{649}"), ('\u{fc45}', "\u{645}\u{62c}"), ('\u{fc46}', "\u{645}\u{62d}"), ('\u{fc47}', "\u{645}\u{62e}"), ('\u{fc48}', "\u{645}\u{645}"),
+  ('\u{fc49}', "\u{645}\u{649}"), ('\u{fc4a}', "\u{645}\u{649}"), ('\u{fc4b}', "\u{628}\u{62e}"), ('\u{fc4c}', "\u{646}\u{62d}"), ('\u{fc4d}', "\u{646}\u{62e}"), ('\u{fc4e}', "\u{646}\u{645}"),
+  ('\u{fc4f}', "\u{646}\u{649}"), ('\u{fc50}', "\u{646}\u{649}"), ('\u{fc51}', "o\u{62c}"), ('\u{fc52}', "o\u{645}"), ('\u{fc53}', "o\u{649}"), ('\u{fc54}', "o\u{649}"),
+  ('\u{fc55}', "\u{649}\u{62c}"), ('\u{fc56}', "\u{649}\u{62d}"), ('\u{fc57}', "\u{649}\u{62e}"), ('\u{fc58}', "\u{649}\u{645}"), ('\u{fc59}', "\u{649}\u{649}"), ('\u{fc5a}', "\u{649}\u{649}"),
+  ('\u{fc5b}', "\u{630}\u{670}"), ('\u{fc5c}', "\u{631}\u{670}"), ('\u{fc5d}', "\u{649}\u{670}"), ('\u{fc5e}', "\u{fe72}\u{651}"), ('\u{fc5f}', "\u{fe74}\u{651}"), ('\u{fc60}', "\u{fe76}\u{651}"),
+  ('\u{fc61}', "\u{fe78}\u{651}"), ('\u{fc62}', "\u{fe7a}\u{651}"), ('\u{fc63}', "\u{fe7c}\u{670}"), ('\u{fc64}', "\u{649}\u{674}\u{631}"), ('\u{fc65}', "\u{649}\u{674}\u{632}"), ('\u{fc66}', "\u{649}\u{674}\u{645}"),
+  ('\u{fc67}', "\u{649}\u{674}\u{646}"), ('\u{fc68}', "\u{649}\u{674}\u{649}"), ('\u{fc69}', "\u{649}\u{674}\u{649}"), ('\u{fc6a}', "\u{628}\u{631}"), ('\u{fc6b}', "\u{628}\u{632}"), ('\u{fc6c}', "\u{628}\u{645}"),
+  ('\u{fc6d}', "\u{628}\u{646}"), ('\u{fc6e}', "\u{628}\u{649}"), ('\u{fc6f}', "\u{628}\u{649}"), ('\u{fc70}', "\u{62a}\u{631}"), ('\u{fc71}', "\u{62a}\u{632}"), ('\u{fc72}', "\u{62a}\u{645}"),
+  ('\u{fc73}', "\u{62a}\u{646}"), ('\u{fc74}', "\u{62a}\u{649}"), ('\u{fc75}', "\u{62a}\u{649}"), ('\u{fc76}', "\u{649}\u{6db}\u{631}"), ('\u{fc77}', "\u{649}\u{6db}\u{632}"), ('\u{fc78}', "\u{649}\u{6db}\u{645}"),
+  ('\u{fc79}', "\u{649}\u{6db}\u{646}"), ('\u{fc7a}', "\u{649}\u{6db}\u{649}"), ('\u{fc7b}', "\u{649}\u{6db}\u{649}"), ('\u{fc7c}', "\u{641}\u{649}"), ('\u{fc7d}', "\u{641}\u{649}"), ('\u{fc7e}', "\u{642}\u{649}"),
+  ('\u{fc7f}', "\u{642}\u{649}"), ('\u{fc80}', "\u{643}l"), ('\u{fc81}', "\u{643}\u{644}"), ('\u{fc82}', "\u{643}\u{645}"), ('\u{fc83}', "\u{643}\u{649}"), ('\u{fc84}', "\u{643}\u{649}"),
+  ('\u{fc85}', "\u{644}\u{645}"), ('\u{fc86}', "\u{644}\u{649}"), ('\u{fc87}', "\u{644}\u{649}"), ('\u{fc88}', "\u{645}l"), ('\u{fc89}', "\u{645}\u{645}"), ('\u{fc8a}', "\u{646}\u{631}"),
+  ('\u{fc8b}', "\u{646}\u{632}"), ('\u{fc8c}', "\u{646}\u{645}"), ('\u{fc8d}', "\u{646}\u{646}"), ('\u{fc8e}', "\u{646}\u{649}"), ('\u{fc8f}', "\u{646}\u{649}"), ('\u{fc90}', "\u{649}\u{670}"),
+  ('\u{fc91}', "\u{649}\u{631}"), ('\u{fc92}', "\u{649}\u{632}"), ('\u{fc93}', "\u{649}\u{645}"), ('\u{fc94}', "\u{649}\u{646}"), ('\u{fc95}', "\u{649}\u{649}"), ('\u{fc96}', "\u{649}\u{649}"),
+  ('\u{fc97}', "\u{649}\u{674}\u{62c}"), ('\u{fc98}', "\u{649}\u{674}\u{62d}"), ('\u{fc99}', "\u{649}\u{674}\u{62e}"), ('\u{fc9a}', "\u{649}\u{674}\u{645}"), ('\u{fc9b}', "\u{649}\u{674}o"), ('\u{fc9c}', "\u{628}\u{62c}"),
+  ('\u{fc9d}', "\u{628}\u{62d}"), ('\u{fc9e}', "\u{628}\u{62e}"), ('\u{fc9f}', "\u{628}\u{645}"), ('\u{fca0}', "\u{628}o"), ('\u{fca1}', "\u{62a}\u{62c}"), ('\u{fca2}', "\u{62a}\u{62d}"),
+  ('\u{fca3}', "\u{62a}\u{62e}"), ('\u{fca4}', "\u{62a}\u{645}"), ('\u{fca5}', "\u{62a}o"), ('\u{fca6}', "\u{649}\u{6db}\u{645}"), ('\u{fca7}', "\u{62c}\u{62d}"), ('\u{fca8}', "\u{62c}\u{645}"),
+  ('\u{fca9}', "\u{62d}\u{62c}"), ('\u{fcaa}', "\u{62d}\u{645}"), ('\u{fcab}', "\u{62e}\u{62c}"), ('\u{fcac}', "\u{62e}\u{645}"), ('\u{fcad}', "\u{633}\u{62c}"), ('\u{fcae}', "\u{633}\u{62d}"),
+  ('\u{fcaf}', "\u{633}\u{62e}"), ('\u{fcb0}', "\u{633}\u{645}"), ('\u{fcb1}', "\u{635}\u{62d}"), ('\u{fcb2}', "\u{635}\u{62e}"), ('\u{fcb3}', "\u{635}\u{645}"), ('\u{fcb4}', "\u{636}\u{62c}"),
+  ('\u{fcb5}', "\u{636}\u{62d}"), ('\u{fcb6}', "\u{636}\u{62e}"), ('\u{fcb7}', "\u{636}\u{645}"), ('\u{fcb8}', "\u{637}\u{62d}"), ('\u{fcb9}', "\u{638}\u{645}"), ('\u{fcba}', "\u{639}\u{62c}"),
+  ('\u{fcbb}', "\u{639}\u{645}"), ('\u{fcbc}', "\u{63a}\u{62c}"), ('\u{fcbd}', "\u{63a}\u{645}"), ('\u{fcbe}', "\u{641}\u{62c}"), ('\u{fcbf}', "\u{641}\u{62d}"), ('\u{fcc0}', "\u{641}\u{62e}"),
+  ('\u{fcc1}', "\u{641}\u{645}"), ('\u{fcc2}', "\u{642}\u{62d}"), ('\u{fcc3}', "\u{642}\u{645}"), ('\u{fcc4}', "\u{643}\u{62c}"), ('\u{fcc5}', "\u{643}\u{62d}"), ('\u{fcc6}', "\u{643}\u{62e}"),
+  ('\u{fcc7}', "\u{643}\u{644}"), ('\u{fcc8}', "\u{643}\u{645}"), ('\u{fcc9}', "\u{644}\u{62c}"), ('\u{fcca}', "\u{644}\u{62d}"), ('\u{fccb}', "\u{644}\u{62e}"), ('\u{fccc}', "\u{644}\u{645}"),
+  ('\u{fccd}', "\u{644}o"), ('\u{fcce}', "\u{645}\u{62c}"), ('\u{fccf}', "\u{645}\u{62d}"), ('\u{fcd0}', "\u{645}\u{62e}"), ('\u{fcd1}', "\u{645}\u{645}"), ('\u{fcd2}', "\u{628}\u{62e}"),
+  ('\u{fcd3}', "\u{646}\u{62d}"), ('\u{fcd4}', "\u{646}\u{62e}"), ('\u{fcd5}', "\u{646}\u{645}"), ('\u{fcd6}', "\u{646}o"), ('\u{fcd7}', "o\u{62c}"), ('\u{fcd8}', "o\u{645}"),
+  ('\u{fcd9}', "o\u{670}"), ('\u{fcda}', "\u{649}\u{62c}"), ('\u{fcdb}', "\u{649}\u{62d}"), ('\u{fcdc}', "\u{649}\u{62e}"), ('\u{fcdd}', "\u{649}\u{645}"), ('\u{fcde}', "\u{649}o"),
+  ('\u{fcdf}', "\u{649}\u{674}\u{645}"), ('\u{fce0}', "\u{649}\u{674}o"), ('\u{fce1}', "\u{628}\u{645}"), ('\u{fce2}', "\u{628}o"), ('\u{fce3}', "\u{62a}\u{645}"), ('\u{fce4}', "\u{62a}o"),
+  ('\u{fce5}', "\u{649}\u{6db}\u{645}"), ('\u{fce6}', "\u{649}\u{6db}o"), ('\u{fce7}', "\u{633}\u{645}"), ('\u{fce8}', "\u{633}o"), ('\u{fce9}', "\u{633}\u{6db}\u{645}"), ('\u{fcea}', "\u{633}\u{6db}o"),
+  ('\u{fceb}', "\u{643}\u{644}"), ('\u{fcec}', "\u{643}\u{645}"), ('\u{fced}', "\u{644}\u{645}"), ('\u{fcee}', "\u{646}\u{645}"), ('\u{fcef}', "\u{646}o"), ('\u{fcf0}', "\u{649}\u{645}"),
+  ('\u{fcf1}', "\u{649}o"), ('\u{fcf2}', "\u{fe77}\u{651}"), ('\u{fcf3}', "\u{fe79}\u{651}"), ('\u{fcf4}', "\u{fe7b}\u{651}"), ('\u{fcf5}', "\u{637}\u{649}"), ('\u{fcf6}', "\u{637}\u{649}"),
+  ('\u{fcf7}', "\u{639}\u{649}"), ('\u{fcf8}', "\u{639}\u{649}"), ('\u{fcf9}', "\u{63a}\u{649}"), ('\u{fcfa}', "\u{63a}\u{649}"), ('\u{fcfb}', "\u{633}\u{649}"), ('\u{fcfc}', "\u{633}\u{649}"),
+  ('\u{fcfd}', "\u{633}\u{6db}\u{649}"), ('\u{fcfe}', "\u{633}\u{6db}\u{649}"), ('\u{fcff}', "\u{62d}\u{649}"), ('\u{fd00}', "\u{62d}\u{649}"), ('\u{fd01}', "\u{62c}\u{649}"), ('\u{fd02}', "\u{62c}\u{649}"),
+  ('\u{fd03}', "\u{62e}\u{649}"), ('\u{fd04}', "\u{62e}\u{649}"), ('\u{fd05}', "\u{635}\u{649}"), ('\u{fd06}', "\u{635}\u{649}"), ('\u{fd07}', "\u{636}\u{649}"), ('\u{fd08}', "\u{636}\u{649}"),
+  ('\u{fd09}', "\u{633}\u{6db}\u{62c}"), ('\u{fd0a}', "\u{633}\u{6db}\u{62d}"), ('\u{fd0b}', "\u{633}\u{6db}\u{62e}"), ('\u{fd0c}', "\u{633}\u{6db}\u{645}"), ('\u{fd0d}', "\u{633}\u{6db}\u{631}"), ('\u{fd0e}', "\u{633}\u{631}"),
+  ('\u{fd0f}', "\u{635}\u{631}"), ('\u{fd10}', "\u{636}\u{631}"), ('\u{fd11}', "\u{637}\u{649}"), ('\u{fd12}', "\u{637}\u{649}"), ('\u{fd13}', "\u{639}\u{649}"), ('\u{fd14}', "\u{639}\u{649}"),
+  ('\u{fd15}', "\u{63a}\u{649}"), ('\u{fd16}', "\u{63a}\u{649}"), ('\u{fd17}', "\u{633}\u{649}"), ('\u{fd18}', "\u{633}\u{649}"), ('\u{fd19}', "\u{633}\u{6db}\u{649}"), ('\u{fd1a}', "\u{633}\u{6db}\u{649}"),
+  ('\u{fd1b}', "\u{62d}\u{649}"), ('\u{fd1c}', "\u{62d}\u{649}"), ('\u{fd1d}', "\u{62c}\u{649}"), ('\u{fd1e}', "\u{62c}\u{649}"), ('\u{fd1f}', "\u{62e}\u{649}"), ('\u{fd20}', "\u{62e}\u{649}"),
+  ('\u{fd21}', "\u{635}\u{649}"), ('\u{fd22}', "\u{635}\u{649}"), ('\u{fd23}', "\u{636}\u{649}"), ('\u{fd24}', "\u{636}\u{649}"), ('\u{fd25}', "\u{633}\u{6db}\u{62c}"), ('\u{fd26}', "\u{633}\u{6db}\u{62d}"),
+  ('\u{fd27}', "\u{633}\u{6db}\u{62e}"), ('\u{fd28}', "\u{633}\u{6db}\u{645}"), ('\u{fd29}', "\u{633}\u{6db}\u{631}"), ('\u{fd2a}', "\u{633}\u{631}"), ('\u{fd2b}', "\u{635}\u{631}"), ('\u{fd2c}', "\u{636}\u{631}"),
+  ('\u{fd2d}', "\u{633}\u{6db}\u{62c}"), ('\u{fd2e}', "\u{633}\u{6db}\u{62d}"), ('\u{fd2f}', "\u{633}\u{6db}\u{62e}"), ('\u{fd30}', "\u{633}\u{6db}\u{645}"), ('\u{fd31}', "\u{633}o"), ('\u{fd32}', "\u{633}\u{6db}o"),
+  ('\u{fd33}', "\u{637}\u{645}"), ('\u{fd34}', "\u{633}\u{62c}"), ('\u{fd35}', "\u{633}\u{62d}"), ('\u{fd36}', "\u{633}\u{62e}"), ('\u{fd37}', "\u{633}\u{6db}\u{62c}"), ('\u{fd38}', "\u{633}\u{6db}\u{62d}"),
+  ('\u{fd39}', "\u{633}\u{6db}\u{62e}"), ('\u{fd3a}', "\u{637}\u{645}"), ('\u{fd3b}', "\u{638}\u{645}"), ('\u{fd3c}', "l\u{30b}"), ('\u{fd3d}', "l\u{30b}"), ('\u{fd3e}', "("),
+  ('\u{fd3f}', ")"), ('\u{fd50}', "\u{62a}\u{62c}\u{645}"), ('\u{fd51}', "\u{62a}\u{62d}\u{62c}"), ('\u{fd52}', "\u{62a}\u{62d}\u{62c}"), ('\u{fd53}', "\u{62a}\u{62d}\u{645}"), ('\u{fd54}', "\u{62a}\u{62e}\u{645}"),
+  ('\u{fd55}', "\u{62a}\u{645}\u{62c}"), ('\u{fd56}', "\u{62a}\u{645}\u{62d}"), ('\u{fd57}', "\u{62a}\u{645}\u{62e}"), ('\u{fd58}', "\u{62c}\u{645}\u{62d}"), ('\u{fd59}', "\u{62c}\u{645}\u{62d}"), ('\u{fd5a}', "\u{62d}\u{645}\u{649}"),
+  ('\u{fd5b}', "\u{62d}\u{645}\u{649}"), ('\u{fd5c}', "\u{633}\u{62d}\u{62c}"), ('\u{fd5d}', "\u{633}\u{62c}\u{62d}"), ('\u{fd5e}', "\u{633}\u{62c}\u{649}"), ('\u{fd5f}', "\u{633}\u{645}\u{62d}"), ('\u{fd60}', "\u{633}\u{645}\u{62d}"),
+  ('\u{fd61}', "\u{633}\u{645}\u{62c}"), ('\u{fd62}', "\u{633}\u{645}\u{645}"), ('\u{fd63}', "\u{633}\u{645}\u{645}"), ('\u{fd64}', "\u{635}\u{62d}\u{62d}"), ('\u{fd65}', "\u{635}\u{62d}\u{62d}"), ('\u{fd66}', "\u{635}\u{645}\u{645}"),
+  ('\u{fd67}', "\u{633}\u{6db}\u{62d}\u{645}"), ('\u{fd68}', "\u{633}\u{6db}\u{62d}\u{645}"), ('\u{fd69}', "\u{633}\u{6db}\u{62c}\u{649}"), ('\u{fd6a}', "\u{633}\u{6db}\u{645}\u{62e}"), ('\u{fd6b}', "\u{633}\u{6db}\u{645}\u{62e}"), ('\u{fd6c}', "\u{633}\u{6db}\u{645}\u{645}"),
+  ('\u{fd6d}', "\u{633}\u{6db}\u{645}\u{645}"), ('\u{fd6e}', "\u{636}\u{62d}\u{649}"), ('\u{fd6f}', "\u{636}\u{62e}\u{645}"), ('\u{fd70}', "\u{636}\u{62e}\u{645}"), ('\u{fd71}', "\u{637}\u{645}\u{62d}"), ('\u{fd72}', "\u{637}\u{645}\u{62d}"),
+  ('\u{fd73}', "\u{637}\u{645}\u{645}"), ('\u{fd74}', "\u{637}\u{645}\u{649}"), ('\u{fd75}', "\u{639}\u{62c}\u{645}"), ('\u{fd76}', "\u{639}\u{645}\u{645}"), ('\u{fd77}', "\u{639}\u{645}\u{645}"), ('\u{fd78}', "\u{639}\u{645}\u{649}"),
+  ('\u{fd79}', "\u{63a}\u{645}\u{645}"), ('\u{fd7a}', "\u{63a}\u{645}\u{649}"), ('\u{fd7b}', "\u{63a}\u{645}\u{649}"), ('\u{fd7c}', "\u{641}\u{62e}\u{645}"), ('\u{fd7d}', "\u{641}\u{62e}\u{645}"), ('\u{fd7e}', "\u{642}\u{645}\u{62d}"),
+  ('\u{fd7f}', "\u{642}\u{645}\u{645}"), ('\u{fd80}', "\u{644}\u{62d}\u{645}"), ('\u{fd81}', "\u{644}\u{62d}\u{649}"), ('\u{fd82}', "\u{644}\u{62d}\u{649}"), ('\u{fd83}', "\u{644}\u{62c}\u{62c}"), ('\u{fd84}', "\u{644}\u{62c}\u{62c}"),
+  ('\u{fd85}', "\u{644}\u{62e}\u{645}"), ('\u{fd86}', "\u{644}\u{62e}\u{645}"), ('\u{fd87}', "\u{644}\u{645}\u{62d}"), ('\u{fd88}', "\u{644}\u{645}\u{62d}"), ('\u{fd89}', "\u{645}\u{62d}\u{62c}"), ('\u{fd8a}', "\u{645}\u{62d}\u{645}"),
+  ('\u{fd8b}', "\u{645}\u{62d}\u{649}"), ('\u{fd8c}', "\u{645}\u{62c}\u{62d}"), ('\u{fd8d}', "\u{645}\u{62c}\u{645}"), ('\u{fd8e}', "\u{645}\u{62e}\u{62c}"), ('\u{fd8f}', "\u{645}\u{62e}\u{645}"), ('\u{fd92}', "\u{645}\u{62c}\u{62e}"),
+  ('\u{fd93}', "o\u{645}\u{62c}"), ('\u{fd94}', "o\u{645}\u{645}"), ('\u{fd95}', "\u{646}\u{62d}\u{645}"), ('\u{fd96}', "\u{646}\u{62d}\u{649}"), ('\u{fd97}', "\u{646}\u{62c}\u{645}"), ('\u{fd98}', "\u{646}\u{62c}\u{645}"),
+  ('\u{fd99}', "\u{646}\u{62c}\u{649}"), ('\u{fd9a}', "\u{646}\u{645}\u{649}"), ('\u{fd9b}', "\u{646}\u{645}\u{649}"), ('\u{fd9c}', "\u{649}\u{645}\u{645}"), ('\u{fd9d}', "\u{649}\u{645}\u{645}"), ('\u{fd9e}', "\u{628}\u{62e}\u{649}"),
+  ('\u{fd9f}', "\u{62a}\u{62c}\u{649}"), ('\u{fda0}', "\u{62a}\u{62c}\u{649}"), ('\u{fda1}', "\u{62a}\u{62e}\u{649}"), ('\u{fda2}', "\u{62a}\u{62e}\u{649}"), ('\u{fda3}', "\u{62a}\u{645}\u{649}"), ('\u{fda4}', "\u{62a}\u{645}\u{649}"),
+  ('\u{fda5}', "\u{62c}\u{645}\u{649}"), ('\u{fda6}', "\u{62c}\u{62d}\u{649}"), ('\u{fda7}', "\u{62c}\u{645}\u{649}"), ('\u{fda8}', "\u{633}\u{62e}\u{649}"), ('\u{fda9}', "\u{635}\u{62d}\u{649}"), ('\u{fdaa}', "\u{633}\u{6db}\u{62d}\u{649}"),
+  ('\u{fdab}', "\u{636}\u{62d}\u{649}"), ('\u{fdac}', "\u{644}\u{62c}\u{649}"), ('\u{fdad}', "\u{644}\u{645}\u{649}"), ('\u{fdae}', "\u{649}\u{62d}\u{649}"), ('\u{fdaf}', "\u{649}\u{62c}\u{649}"), ('\u{fdb0}', "\u{649}\u{645}\u{649}"),
+  ('\u{fdb1}', "\u{645}\u{645}\u{649}"), ('\u{fdb2}', "\u{642}\u{645}\u{649}"), ('\u{fdb3}', "\u{646}\u{62d}\u{649}"), ('\u{fdb4}', "\u{642}\u{645}\u{62d}"), ('\u{fdb5}', "\u{644}\u{62d}\u{645}"), ('\u{fdb6}', "\u{639}\u{645}\u{649}"),
+  ('\u{fdb7}', "\u{643}\u{645}\u{649}"), ('\u{fdb8}', "\u{646}\u{62c}\u{62d}"), ('\u{fdb9}', "\u{645}\u{62e}\u{649}"), ('\u{fdba}', "\u{644}\u{62c}\u{645}"), ('\u{fdbb}', "\u{643}\u{645}\u{645}"), ('\u{fdbc}', "\u{644}\u{62c}\u{645}"),
+  ('\u{fdbd}', "\u{646}\u{62c}\u{62d}"), ('\u{fdbe}', "\u{62c}\u{62d}\u{649}"), ('\u{fdbf}', "\u{62d}\u{62c}\u{649}"), ('\u{fdc0}', "\u{645}\u{62c}\u{649}"), ('\u{fdc1}', "\u{641}\u{645}\u{649}"), ('\u{fdc2}', "\u{628}\u{62d}\u{649}"),
+  ('\u{fdc3}', "\u{643}\u{645}\u{645}"), ('\u{fdc4}', "\u{639}\u{62c}\u{645}"), ('\u{fdc5}', "\u{635}\u{645}\u{645}"), ('\u{fdc6}', "\u{633}\u{62e}\u{649}"), ('\u{fdc7}', "\u{646}\u{62c}\u{649}"), ('\u{fdf0}', "\u{635}\u{644}\u{649}"),
+  ('\u{fdf1}', "\u{642}\u{644}\u{649}"), ('\u{fdf2}', "l\u{644}\u{644}\u{651}\u{670}o"), ('\u{fdf3}', "l\u{643}\u{628}\u{631}"), ('\u{fdf4}', "\u{645}\u{62d}\u{645}\u{62f}"), ('\u{fdf5}', "\u{635}\u{644}\u{639}\u{645}"), ('\u{fdf6}', "\u{631}\u{633}\u{648}\u{644}"),
+  ('\u{fdf7}', "\u{639}\u{644}\u{649}o"), ('\u{fdf8}', "\u{648}\u{633}\u{644}\u{645}"), ('\u{fdf9}', "\u{635}\u{644}\u{649}"), ('\u{fdfa}', "\u{635}\u{644}\u{649} l\u{644}\u{644}o \u{639}\u{644}\u{649}o \u{648}\u{633}\u{644}\u{645}"), ('\u{fdfb}', "\u{62c}\u{644} \u{62c}\u{644}l\u{644}o"), ('\u{fdfc}', "\u{631}\u{649}l\u{644}"),
+  ('\u{fe19}', "\u{2d57}"), ('\u{fe30}', ":"), ('\u{fe31}', "\u{2502}"), ('\u{fe34}', "\u{2307}"), ('\u{fe35}', "\u{23dc}"), ('\u{fe36}', "\u{23dd}"),
+  ('\u{fe37}', "\u{23de}"), ('\u{fe38}', "\u{23df}"), ('\u{fe39}', "\u{23e0}"), ('\u{fe3a}', "\u{23e1}"), ('\u{fe49}', "\u{2c9}"), ('\u{fe4a}', "\u{2c9}"),
+  ('\u{fe4b}', "\u{2c9}"), ('\u{fe4c}', "\u{2c9}"), ('\u{fe4d}', "_"), ('\u{fe4e}', "_"), ('\u{fe4f}', "_"), ('\u{fe58}', "-"),
+  ('\u{fe68}', "\u{5c}"), ('\u{fe80}', "\u{621}"), ('\u{fe81}', "\u{622}"), ('\u{fe82}', "\u{622}"), ('\u{fe83}', "l\u{674}"), ('\u{fe84}', "l\u{674}"),
+  ('\u{fe85}', "\u{648}\u{674}"), ('\u{fe86}', "\u{648}\u{674}"), ('\u{fe87}', "l\u{655}"), ('\u{fe88}', "l\u{655}"), ('\u{fe89}', "\u{649}\u{674}"), ('\u{fe8a}', "\u{649}\u{674}"),
+  ('\u{fe8b}', "\u{649}\u{674}"), ('\u{fe8c}', "\u{649}\u{674}"), ('\u{fe8d}', "l"), ('\u{fe8e}', "l"), ('\u{fe8f}', "\u{628}"), ('\u{fe90}', "\u{628}"),
+  ('\u{fe91}', "\u{628}"), ('\u{fe92}', "\u{628}"), ('\u{fe93}', "\u{629}"), ('\u{fe94}', "\u{629}"), ('\u{fe95}', "\u{62a}"), ('\u{fe96}', "\u{62a}"),
+  ('\u{fe97}', "\u{62a}"), ('\u{fe98}', "\u{62a}"), ('\u{fe99}', "\u{649}\u{6db}"), ('\u{fe9a}', "\u{649}\u{6db}"), ('\u{fe9b}', "\u{649}\u{6db}"), ('\u{fe9c}', "\u{649}\u{6db}"),
+  ('\u{fe9d}', "\u{62c}"), ('\u{fe9e}', "\u{62c}"), ('\u{fe9f}', "\u{62c}"), ('\u{fea0}', "\u{62c}"), ('\u{fea1}', "\u{62d}"), ('\u{fea2}', "\u{62d}"),
+  ('\u{fea3}', "\u{62d}"), ('\u{fea4}', "\u{62d}"), ('\u{fea5}', "\u{62e}"), ('\u{fea6}', "\u{62e}"), ('\u{fea7}', "\u{62e}"), ('\u{fea8}', "\u{62e}"),
+  ('\u{fea9}', "\u{62f}"), ('\u{feaa}', "\u{62f}"), ('\u{feab}', "\u{630}"), ('\u{feac}', "\u{630}"), ('\u{fead}', "\u{631}"), ('\u{feae}', "\u{631}"),
+  ('\u{feaf}', "\u{632}"), ('\u{feb0}', "\u{632}"), ('\u{feb1}', "\u{633}"), ('\u{feb2}', "\u{633}"), ('\u{feb3}', "\u{633}"), ('\u{feb4}', "\u{633}"),
+  ('\u{feb5}', "\u{633}\u{6db}"), ('\u{feb6}', "\u{633}\u{6db}"), ('\u{feb7}', "\u{633}\u{6db}"), ('\u{feb8}', "\u{633}\u{6db}"), ('\u{feb9}', "\u{635}"), ('\u{feba}', "\u{635}"),
+  ('\u{febb}', "\u{635}"), ('\u{febc}', "\u{635}"), ('\u{febd}', "\u{636}"), ('\u{febe}', "\u{636}"), ('\u{febf}', "\u{636}"), ('\u{fec0}', "\u{636}"),
+  ('\u{fec1}', "\u{637}"), ('\u{fec2}', "\u{637}"), ('\u{fec3}', "\u{637}"), ('\u{fec4}', "\u{637}"), ('\u{fec5}', "\u{638}"), ('\u{fec6}', "\u{638}"),
+  ('\u{fec7}', "\u{638}"), ('\u{fec8}', "\u{638}"), ('\u{fec9}', "\u{639}"), ('\u{feca}', "\u{639}"), ('\u{fecb}', "\u{639}"), ('\u{fecc}', "\u{639}"),
+  ('\u{fecd}', "\u{63a}"), ('\u{fece}', "\u{63a}"), ('\u{fecf}', "\u{63a}"), ('\u{fed0}', "\u{63a}"), ('\u{fed1}', "\u{641}"), ('\u{fed2}', "\u{641}"),
+  ('\u{fed3}', "\u{641}"), ('\u{fed4}', "\u{641}"), ('\u{fed5}', "\u{642}"), ('\u{fed6}', "\u{642}"), ('\u{fed7}', "\u{642}"), ('\u{fed8}', "\u{642}"),
+  ('\u{fed9}', "\u{643}"), ('\u{feda}', "\u{643}"), ('\u{fedb}', "\u{643}"), ('\u{fedc}', "\u{643}"), ('\u{fedd}', "\u{644}"), ('\u{fede}', "\u{644}"),
+  ('\u{fedf}', "\u{644}"), ('\u{fee0}', "\u{644}"), ('\u{fee1}', "\u{645}"), ('\u{fee2}', "\u{645}"), ('\u{fee3}', "\u{645}"), ('\u{fee4}', "\u{645}"),
+  ('\u{fee5}', "\u{646}"), ('\u{fee6}', "\u{646}"), ('\u{fee7}', "\u{646}"), ('\u{fee8}', "\u{646}"), ('\u{fee9}', "o"), ('\u{feea}', "o"),
+  ('\u{feeb}', "o"), ('\u{feec}', "o"), ('\u{feed}', "\u{648}"), ('\u{feee}', "\u{648}"), ('\u{feef}', "\u{649}"), ('\u{fef0}', "\u{649}"),
+  ('\u{fef1}', "\u{649}"), ('\u{fef2}', "\u{649}"), ('\u{fef3}', "\u{649}"), ('\u{fef4}', "\u{649}"), ('\u{fef5}', "\u{644}\u{622}"), ('\u{fef6}', "\u{644}\u{622}"),
+  ('\u{fef7}', "\u{644}l\u{674}"), ('\u{fef8}', "\u{644}l\u{674}"), ('\u{fef9}', "\u{644}l\u{655}"), ('\u{fefa}', "\u{644}l\u{655}"), ('\u{fefb}', "\u{644}l"), ('\u{fefc}', "\u{644}l"),
+  ('\u{ff01}', "!"), ('\u{ff02}', "''"), ('\u{ff07}', "'"), ('\u{ff0d}', "\u{30fc}"), ('\u{ff1a}', ":"), ('\u{ff21}', "A"),
+  ('\u{ff22}', "B"), ('\u{ff23}', "C"), ('\u{ff25}', "E"), ('\u{ff28}', "H"), ('\u{ff29}', "l"), ('\u{ff2a}', "J"),
+  ('\u{ff2b}', "K"), ('\u{ff2d}', "M"), ('\u{ff2e}', "N"), ('\u{ff2f}', "O"), ('\u{ff30}', "P"), ('\u{ff33}', "S"),
+  ('\u{ff34}', "T"), ('\u{ff38}', "X"), ('\u{ff39}', "Y"), ('\u{ff3a}', "Z"), ('\u{ff3b}', "("), ('\u{ff3c}', "\u{5c}"),
+  ('\u{ff3d}', ")"), ('\u{ff3e}', "\u{fe3f}"), ('\u{ff40}', "'"), ('\u{ff41}', "a"), ('\u{ff43}', "c"), ('\u{ff45}', "e"),
+  ('\u{ff47}', "g"), ('\u{ff48}', "h"), ('\u{ff49}', "i"), ('\u{ff4a}', "j"), ('\u{ff4c}', "l"), ('\u{ff4f}', "o"),
+  ('\u{ff50}', "p"), ('\u{ff53}', "s"), ('\u{ff56}', "v"), ('\u{ff58}', "x"), ('\u{ff59}', "y"), ('\u{ff5c}', "\u{2502}"),
+  ('\u{ff5e}', "\u{301c}"), ('\u{ff65}', "\u{b7}"), ('\u{ffe3}', "\u{2c9}"), ('\u{ffe8}', "l"), ('\u{ffed}', "\u{25aa}"), ('\u{10101}', "\u{b7}"),
+  ('\u{1018e}', "N\u{30a}"), ('\u{10196}', "X\u{335}"), ('\u{10197}', "V\u{335}"), ('\u{10198}', "l\u{335}l\u{335}S\u{335}"), ('\u{10199}', "l\u{335}l\u{335}"), ('\u{101a0}', "\u{2ce8}"),
+  ('\u{10282}', "B"), ('\u{10285}', "\u{394}"), ('\u{10286}', "E"), ('\u{10287}', "F"), ('\u{1028a}', "l"), ('\u{1028d}', "\u{245}"),
+  ('\u{10290}', "X"), ('\u{10292}', "O"), ('\u{10294}', "\u{16dc}"), ('\u{10295}', "P"), ('\u{10296}', "S"), ('\u{10297}', "T"),
+  ('\u{1029b}', "+"), ('\u{102a0}', "A"), ('\u{102a1}', "B"), ('\u{102a2}', "C"), ('\u{102a3}', "\u{394}"), ('\u{102a5}', "F"),
+  ('\u{102ab}', "O"), ('\u{102ad}', "\u{3d8}"), ('\u{102b0}', "M"), ('\u{102b1}', "T"), ('\u{102b2}', "Y"), ('\u{102b3}', "\u{3a6}"),
+  ('\u{102b4}', "X"), ('\u{102b5}', "\u{3a8}"), ('\u{102b6}', "\u{3a9}"), ('\u{102b8}', "\u{2d40}"), ('\u{102cf}', "H"), ('\u{102e1}', "\u{62f}"),
+  ('\u{102e4}', "\u{648}"), ('\u{102e8}', "\u{637}"), ('\u{102f2}', "\u{635}"), ('\u{102f5}', "Z"), ('\u{10301}', "B"), ('\u{10302}', "C"),
+  ('\u{10309}', "l"), ('\u{10311}', "M"), ('\u{10312}', "\u{3d8}"), ('\u{10315}', "T"), ('\u{10317}', "X"), ('\u{1031a}', "8"),
+  ('\u{1031f}', "*"), ('\u{10320}', "l"), ('\u{10322}', "X"), ('\u{103d1}', "\u{10382}"), ('\u{103d3}', "\u{10393}"), ('\u{10401}', "\u{190}"),
+  ('\u{10404}', "O"), ('\u{10411}', "\u{a4f6}"), ('\u{10415}', "C"), ('\u{1041b}', "L"), ('\u{1041f}', "\u{2c70}"), ('\u{10420}', "S"),
+  ('\u{10423}', "\u{186}"), ('\u{10425}', "\u{418}"), ('\u{10429}', "\u{a793}"), ('\u{1042a}', "\u{29a}"), ('\u{1042c}', "o"), ('\u{1043d}', "c"),
+  ('\u{1043f}', "\u{277}"), ('\u{10442}', "\u{25e}"), ('\u{10443}', "\u{29f}"), ('\u{10448}', "s"), ('\u{1044b}', "\u{254}"), ('\u{1044d}', "\u{1d0e}"),
+  ('\u{104a0}', "\u{10486}"), ('\u{104b0}', "\u{245}"), ('\u{104b4}', "R"), ('\u{104bc}', "\u{4c3}"), ('\u{104c2}', "O"), ('\u{104c3}', "\u{298}"),
+  ('\u{104c4}', "\u{de}"), ('\u{104cd}', "\u{40b}"), ('\u{104ce}', "U"), ('\u{104d0}', "\u{16e6}"), ('\u{104d1}', "\u{3a8}"), ('\u{104d2}', "7"),
+  ('\u{104d8}', "\u{28c}"), ('\u{104db}', "\u{3bb}"), ('\u{104ea}', "o"), ('\u{104eb}', "\u{a669}"), ('\u{104f6}', "u"), ('\u{104f9}', "\u{3c8}"),
+  ('\u{10513}', "N"), ('\u{10516}', "O"), ('\u{10518}', "K"), ('\u{1051c}', "C"), ('\u{1051d}', "V"), ('\u{10525}', "F"),
+  ('\u{10526}', "L"), ('\u{10527}', "X"), ('\u{10a3a}', "\u{323}"), ('\u{10a50}', "."), ('\u{10a57}', "\u{10a56}\u{10a56}"), ('\u{10cfa}', "\u{10ca5}"),
+  ('\u{10cfc}', "\u{10c82}"), ('\u{110bb}', "\u{970}"), ('\u{111c7}', "\u{970}"), ('\u{111ca}', "\u{323}"), ('\u{111cb}', "\u{93a}"), ('\u{111db}', "\u{a8fc}"),
+  ('\u{111dc}', "\u{a8fb}"), ('\u{111de}', "\u{2248}"), ('\u{11300}', "\u{30a}"), ('\u{11413}', "\u{11434}\u{11442}\u{11412}"), ('\u{11419}', "\u{11434}\u{11442}\u{11418}"), ('\u{11424}', "\u{11434}\u{11442}\u{11423}"),
+  ('\u{1142a}', "\u{11434}\u{11442}\u{11429}"), ('\u{1142d}', "\u{11434}\u{11442}\u{1142c}"), ('\u{1142f}', "\u{11434}\u{11442}\u{1142e}"), ('\u{1144c}', "\u{1144b}\u{1144b}"), ('\u{11492}', "\u{998}"), ('\u{11494}', "\u{99a}"),
+  ('\u{11496}', "\u{99c}"), ('\u{11498}', "\u{99e}"), ('\u{11499}', "\u{99f}"), ('\u{1149b}', "\u{9a1}"), ('\u{1149d}', "\u{9b2}"), ('\u{1149e}', "\u{9a4}"),
+  ('\u{1149f}', "\u{9a5}"), ('\u{114a0}', "\u{9a6}"), ('\u{114a1}', "\u{9a7}"), ('\u{114a2}', "\u{9a8}"), ('\u{114a3}', "\u{9aa}"), ('\u{114a7}', "\u{9ae}"),
+  ('\u{114a8}', "\u{9af}"), ('\u{114a9}', "\u{9ac}"), ('\u{114aa}', "\u{9a3}"), ('\u{114ab}', "\u{9b0}"), ('\u{114ad}', "\u{9b7}"), ('\u{114ae}', "\u{9b8}"),
+  ('\u{114b0}', "\u{9be}"), ('\u{114b1}', "\u{9bf}"), ('\u{114b9}', "\u{9c7}"), ('\u{114bc}', "\u{9cb}"), ('\u{114bd}', "\u{9d7}"), ('\u{114be}', "\u{9cc}"),
+  ('\u{114bf}', "\u{306}\u{307}"), ('\u{114c1}', "\u{983}"), ('\u{114c2}', "\u{9cd}"), ('\u{114c3}', "\u{323}"), ('\u{114c4}', "\u{9bd}"), ('\u{114c5}', "w\u{307}"),
+  ('\u{114d0}', "O"), ('\u{114d1}', "\u{9e7}"), ('\u{114d2}', "\u{9e8}"), ('\u{114d6}', "\u{9ec}"), ('\u{115d8}', "\u{11582}"), ('\u{115d9}', "\u{11582}"),
+  ('\u{115da}', "\u{11583}"), ('\u{115db}', "\u{11584}"), ('\u{115dc}', "\u{115b2}"), ('\u{115dd}', "\u{115b3}"), ('\u{11642}', "\u{11641}\u{11641}"), ('\u{11700}', "rn"),
+  ('\u{11706}', "v"), ('\u{1170a}', "w"), ('\u{1170e}', "w"), ('\u{1170f}', "w"), ('\u{118a0}', "V"), ('\u{118a2}', "F"),
+  ('\u{118a3}', "L"), ('\u{118a4}', "Y"), ('\u{118a6}', "E"), ('\u{118a8}', "\u{2207}"), ('\u{118a9}', "Z"), ('\u{118ac}', "9"),
+  ('\u{118ae}', "E"), ('\u{118af}', "4"), ('\u{118b2}', "L"), ('\u{118b5}', "O"), ('\u{118b7}', "\u{16dc}"), ('\u{118b8}', "U"),
+  ('\u{118bb}', "5"), ('\u{118bc}', "T"), ('\u{118c0}', "v"), ('\u{118c1}', "s"), ('\u{118c2}', "F"), ('\u{118c3}', "i"),
+  ('\u{118c4}', "z"), ('\u{118c6}', "7"), ('\u{118c8}', "o"), ('\u{118ca}', "3"), ('\u{118cc}', "9"), ('\u{118ce}', "\u{a793}"),
+  ('\u{118d5}', "6"), ('\u{118d6}', "9"), ('\u{118d7}', "o"), ('\u{118d8}', "u"), ('\u{118dc}', "y"), ('\u{118e0}', "O"),
+  ('\u{118e3}', "rn"), ('\u{118e4}', "\u{669}"), ('\u{118e5}', "Z"), ('\u{118e6}', "W"), ('\u{118e9}', "C"), ('\u{118ec}', "X"),
+  ('\u{118ef}', "W"), ('\u{118f2}', "C"), ('\u{11ae6}', "\u{11ae5}\u{11aef}"), ('\u{11ae7}', "\u{11ae5}\u{11af0}"), ('\u{11ae8}', "\u{11ae5}\u{11ae5}"), ('\u{11ae9}', "\u{11ae5}\u{11ae5}\u{11aef}"),
+  ('\u{11aea}', "\u{11ae5}\u{11ae5}\u{11af0}"), ('\u{11aec}', "\u{11aeb}\u{11aef}"), ('\u{11aed}', "\u{11aeb}\u{11aeb}"), ('\u{11aee}', "\u{11aeb}\u{11aeb}\u{11aef}"), ('\u{11af4}', "\u{11af3}\u{11aef}"), ('\u{11af5}', "\u{11af3}\u{11af0}"),
+  ('\u{11af6}', "\u{11af3}\u{11af3}"), ('\u{11af7}', "\u{11af3}\u{11af3}\u{11aef}"), ('\u{11af8}', "\u{11af3}\u{11af3}\u{11af0}"), ('\u{11c42}', "\u{11c41}\u{11c41}"), ('\u{11cb2}', "\u{11caa}"), ('\u{12038}', "\u{1039a}"),
+  ('\u{132f9}', "\u{1099e}"), ('\u{16f07}', "\u{393}"), ('\u{16f08}', "V"), ('\u{16f0a}', "T"), ('\u{16f16}', "L"), ('\u{16f1a}', "\u{394}"),
+  ('\u{16f1c}', "\u{a658}"), ('\u{16f26}', "\u{a4f6}"), ('\u{16f28}', "l"), ('\u{16f2d}', "\u{190}"), ('\u{16f35}', "R"), ('\u{16f3a}', "S"),
+  ('\u{16f3b}', "3"), ('\u{16f3d}', "\u{245}"), ('\u{16f3f}', ">"), ('\u{16f40}', "A"), ('\u{16f42}', "U"), ('\u{16f43}', "Y"),
+  ('\u{16f51}', "'"), ('\u{16f52}', "'"), ('\u{1ccd6}', "A"), ('\u{1ccd7}', "B"), ('\u{1ccd8}', "C"), ('\u{1ccd9}', "D"),
+  ('\u{1ccda}', "E"), ('\u{1ccdb}', "F"), ('\u{1ccdc}', "G"), ('\u{1ccdd}', "H"), ('\u{1ccde}', "l"), ('\u{1ccdf}', "J"),
+  ('\u{1cce0}', "K"), ('\u{1cce1}', "L"), ('\u{1cce2}', "M"), ('\u{1cce3}', "N"), ('\u{1cce4}', "O"), ('\u{1cce5}', "P"),
+  ('\u{1cce6}', "Q"), ('\u{1cce7}', "R"), ('\u{1cce8}', "S"), ('\u{1cce9}', "T"), ('\u{1ccea}', "U"), ('\u{1cceb}', "V"),
+  ('\u{1ccec}', "W"), ('\u{1cced}', "X"), ('\u{1ccee}', "Y"), ('\u{1ccef}', "Z"), ('\u{1ccf0}', "O"), ('\u{1ccf1}', "l"),
+  ('\u{1ccf2}', "2"), ('\u{1ccf3}', "3"), ('\u{1ccf4}', "4"), ('\u{1ccf5}', "5"), ('\u{1ccf6}', "6"), ('\u{1ccf7}', "7"),
+  ('\u{1ccf8}', "8"), ('\u{1ccf9}', "9"), ('\u{1d114}', "{"), ('\u{1d16d}', "."), ('\u{1d202}', "\u{4fe}"), ('\u{1d206}', "3"),
+  ('\u{1d20b}', "\u{418}"), ('\u{1d20d}', "V"), ('\u{1d20f}', "\u{5c}"), ('\u{1d212}', "7"), ('\u{1d213}', "F"), ('\u{1d214}', "\u{102bc}"),
+  ('\u{1d215}', "\u{a4f6}"), ('\u{1d216}', "R"), ('\u{1d217}', "\u{2c6f}"), ('\u{1d21a}', "O\u{335}"), ('\u{1d21b}', "\u{2144}"), ('\u{1d21c}', "\u{a4d5}"),
+  ('\u{1d221}', "\u{190}"), ('\u{1d222}', "\u{460}"), ('\u{1d22a}', "L"), ('\u{1d22b}', "\u{a4f6}"), ('\u{1d230}', "\u{a7fb}"), ('\u{1d236}', "<"),
+  ('\u{1d237}', ">"), ('\u{1d238}', "\u{228f}"), ('\u{1d239}', "\u{2290}"), ('\u{1d23a}', "/"), ('\u{1d23b}', "\u{5c}"), ('\u{1d23f}', "\u{16cb}"),
+  ('\u{1d245}', "\u{548}"), ('\u{1d400}', "A"), ('\u{1d401}', "B"), ('\u{1d402}', "C"), ('\u{1d403}', "D"), ('\u{1d404}', "E"),
+  ('\u{1d405}', "F"), ('\u{1d406}', "G"), ('\u{1d407}', "H"), ('\u{1d408}', "l"), ('\u{1d409}', "J"), ('\u{1d40a}', "K"),
+  ('\u{1d40b}', "L"), ('\u{1d40c}', "M"), ('\u{1d40d}', "N"), ('\u{1d40e}', "O"), ('\u{1d40f}', "P"), ('\u{1d410}', "Q"),
+  ('\u{1d411}', "R"), ('\u{1d412}', "S"), ('\u{1d413}', "T"), ('\u{1d414}', "U"), ('\u{1d415}', "V"), ('\u{1d416}', "W"),
+  ('\u{1d417}', "X"), ('\u{1d418}', "Y"), ('\u{1d419}', "Z"), ('\u{1d41a}', "a"), ('\u{1d41b}', "b"), ('\u{1d41c}', "c"),
+  ('\u{1d41d}', "d"), ('\u{1d41e}', "e"), ('\u{1d41f}', "f"), ('\u{1d420}', "g"), ('\u{1d421}', "h"), ('\u{1d422}', "i"),
+  ('\u{1d423}', "j"), ('\u{1d424}', "k"), ('\u{1d425}', "l"), ('\u{1d426}', "rn"), ('\u{1d427}', "n"), ('\u{1d428}', "o"),
+  ('\u{1d429}', "p"), ('\u{1d42a}', "q"), ('\u{1d42b}', "r"), ('\u{1d42c}', "s"), ('\u{1d42d}', "t"), ('\u{1d42e}', "u"),
+  ('\u{1d42f}', "v"), ('\u{1d430}', "w"), ('\u{1d431}', "x"), ('\u{1d432}', "y"), ('\u{1d433}', "z"), ('\u{1d434}', "A"),
+  ('\u{1d435}', "B"), ('\u{1d436}', "C"), ('\u{1d437}', "D"), ('\u{1d438}', "E"), ('\u{1d439}', "F"), ('\u{1d43a}', "G"),
+  ('\u{1d43b}', "H"), ('\u{1d43c}', "l"), ('\u{1d43d}', "J"), ('\u{1d43e}', "K"), ('\u{1d43f}', "L"), ('\u{1d440}', "M"),
+  ('\u{1d441}', "N"), ('\u{1d442}', "O"), ('\u{1d443}', "P"), ('\u{1d444}', "Q"), ('\u{1d445}', "R"), ('\u{1d446}', "S"),
+  ('\u{1d447}', "T"), ('\u{1d448}', "U"), ('\u{1d449}', "V"), ('\u{1d44a}', "W"), ('\u{1d44b}', "X"), ('\u{1d44c}', "Y"),
+  ('\u{1d44d}', "Z"), ('\u{1d44e}', "a"), ('\u{1d44f}', "b"), ('\u{1d450}', "c"), ('\u{1d451}', "d"), ('\u{1d452}', "e"),
+  ('\u{1d453}', "f"), ('\u{1d454}', "g"), ('\u{1d456}', "i"), ('\u{1d457}', "j"), ('\u{1d458}', "k"), ('\u{1d459}', "l"),
+  ('\u{1d45a}', "rn"), ('\u{1d45b}', "n"), ('\u{1d45c}', "o"), ('\u{1d45d}', "p"), ('\u{1d45e}', "q"), ('\u{1d45f}', "r"),
+  ('\u{1d460}', "s"), ('\u{1d461}', "t"), ('\u{1d462}', "u"), ('\u{1d463}', "v"), ('\u{1d464}', "w"), ('\u{1d465}', "x"),
+  ('\u{1d466}', "y"), ('\u{1d467}', "z"), ('\u{1d468}', "A"), ('\u{1d469}', "B"), ('\u{1d46a}', "C"), ('\u{1d46b}', "D"),
+  ('\u{1d46c}', "E"), ('\u{1d46d}', "F"), ('\u{1d46e}', "G"), ('\u{1d46f}', "H"), ('\u{1d470}', "l"), ('\u{1d471}', "J"),
+  ('\u{1d472}', "K"), ('\u{1d473}', "L"), ('\u{1d474}', "M"), ('\u{1d475}', "N"), ('\u{1d476}', "O"), ('\u{1d477}', "P"),
+  ('\u{1d478}', "Q"), ('\u{1d479}', "R"), ('\u{1d47a}', "S"), ('\u{1d47b}', "T"), ('\u{1d47c}', "U"), ('\u{1d47d}', "V"),
+  ('\u{1d47e}', "W"), ('\u{1d47f}', "X"), ('\u{1d480}', "Y"), ('\u{1d481}', "Z"), ('\u{1d482}', "a"), ('\u{1d483}', "b"),
+  ('\u{1d484}', "c"), ('\u{1d485}', "d"), ('\u{1d486}', "e"), ('\u{1d487}', "f"), ('\u{1d488}', "g"), ('\u{1d489}', "h"),
+  ('\u{1d48a}', "i"), ('\u{1d48b}', "j"), ('\u{1d48c}', "k"), ('\u{1d48d}', "l"), ('\u{1d48e}', "rn"), ('\u{1d48f}', "n"),
+  ('\u{1d490}', "o"), ('\u{1d491}', "p"), ('\u{1d492}', "q"), ('\u{1d493}', "r"), ('\u{1d494}', "s"), ('\u{1d495}', "t"),
+  ('\u{1d496}', "u"), ('\u{1d497}', "v"), ('\u{1d498}', "w"), ('\u{1d499}', "x"), ('\u{1d49a}', "y"), ('\u{1d49b}', "z"),
+  ('\u{1d49c}', "A"), ('\u{1d49e}', "C"), ('\u{1d49f}', "D"), ('\u{1d4a2}', "G"), ('\u{1d4a5}', "J"), ('\u{1d4a6}', "K"),
+  ('\u{1d4a9}', "N"), ('\u{1d4aa}', "O"), ('\u{1d4ab}', "P"), ('\u{1d4ac}', "Q"), ('\u{1d4ae}', "S"), ('\u{1d4af}', "T"),
+  ('\u{1d4b0}', "U"), ('\u{1d4b1}', "V"), ('\u{1d4b2}', "W"), ('\u{1d4b3}', "X"), ('\u{1d4b4}', "Y"), ('\u{1d4b5}', "Z"),
+  ('\u{1d4b6}', "a"), ('\u{1d4b7}', "b"), ('\u{1d4b8}', "c"), ('\u{1d4b9}', "d"), ('\u{1d4bb}', "f"), ('\u{1d4bd}', "h"),
+  ('\u{1d4be}', "i"), ('\u{1d4bf}', "j"), ('\u{1d4c0}', "k"), ('\u{1d4c1}', "l"), ('\u{1d4c2}', "rn"), ('\u{1d4c3}', "n"),
+  ('\u{1d4c5}', "p"), ('\u{1d4c6}', "q"), ('\u{1d4c7}', "r"), ('\u{1d4c8}', "s"), ('\u{1d4c9}', "t"), ('\u{1d4ca}', "u"),
+  ('\u{1d4cb}', "v"), ('\u{1d4cc}', "w"), ('\u{1d4cd}', "x"), ('\u{1d4ce}', "y"), ('\u{1d4cf}', "z"), ('\u{1d4d0}', "A"),
+  ('\u{1d4d1}', "B"), ('\u{1d4d2}', "C"), ('\u{1d4d3}', "D"), ('\u{1d4d4}', "E"), ('\u{1d4d5}', "F"), ('\u{1d4d6}', "G"),
+  ('\u{1d4d7}', "H"), ('\u{1d4d8}', "l"), ('\u{1d4d9}', "J"), ('\u{1d4da}', "K"), ('\u{1d4db}', "L"), ('\u{1d4dc}', "M"),
+  ('\u{1d4dd}', "N"), ('\u{1d4de}', "O"), ('\u{1d4df}', "P"), ('\u{1d4e0}', "Q"), ('\u{1d4e1}', "R"), ('\u{1d4e2}', "S"),
+  ('\u{1d4e3}', "T"), ('\u{1d4e4}', "U"), ('\u{1d4e5}', "V"), ('\u{1d4e6}', "W"), ('\u{1d4e7}', "X"), ('\u{1d4e8}', "Y"),
+  ('\u{1d4e9}', "Z"), ('\u{1d4ea}', "a"), ('\u{1d4eb}', "b"), ('\u{1d4ec}', "c"), ('\u{1d4ed}', "d"), ('\u{1d4ee}', "e"),
+  ('\u{1d4ef}', "f"), ('\u{1d4f0}', "g"), ('\u{1d4f1}', "h"), ('\u{1d4f2}', "i"), ('\u{1d4f3}', "j"), ('\u{1d4f4}', "k"),
+  ('\u{1d4f5}', "l"), ('\u{1d4f6}', "rn"), ('\u{1d4f7}', "n"), ('\u{1d4f8}', "o"), ('\u{1d4f9}', "p"), ('\u{1d4fa}', "q"),
+  ('\u{1d4fb}', "r"), ('\u{1d4fc}', "s"), ('\u{1d4fd}', "t"), ('\u{1d4fe}', "u"), ('\u{1d4ff}', "v"), ('\u{1d500}', "w"),
+  ('\u{1d501}', "x"), ('\u{1d502}', "y"), ('\u{1d503}', "z"), ('\u{1d504}', "A"), ('\u{1d505}', "B"), ('\u{1d507}', "D"),
+  ('\u{1d508}', "E"), ('\u{1d509}', "F"), ('\u{1d50a}', "G"), ('\u{1d50d}', "J"), ('\u{1d50e}', "K"), ('\u{1d50f}', "L"),
+  ('\u{1d510}', "M"), ('\u{1d511}', "N"), ('\u{1d512}', "O"), ('\u{1d513}', "P"), ('\u{1d514}', "Q"), ('\u{1d516}', "S"),
+  ('\u{1d517}', "T"), ('\u{1d518}', "U"), ('\u{1d519}', "V"), ('\u{1d51a}', "W"), ('\u{1d51b}', "X"), ('\u{1d51c}', "Y"),
+  ('\u{1d51e}', "a"), ('\u{1d51f}', "b"), ('\u{1d520}', "c"), ('\u{1d521}', "d"), ('\u{1d522}', "e"), ('\u{1d523}', "f"),
+  ('\u{1d524}', "g"), ('\u{1d525}', "h"), ('\u{1d526}', "i"), ('\u{1d527}', "j"), ('\u{1d528}', "k"), ('\u{1d529}', "l"),
+  ('\u{1d52a}', "rn"), ('\u{1d52b}', "n"), ('\u{1d52c}', "o"), ('\u{1d52d}', "p"), ('\u{1d52e}', "q"), ('\u{1d52f}', "r"),
+  ('\u{1d530}', "s"), ('\u{1d531}', "t"), ('\u{1d532}', "u"), ('\u{1d533}', "v"), ('\u{1d534}', "w"), ('\u{1d535}', "x"),
+  ('\u{1d536}', "y"), ('\u{1d537}', "z"), ('\u{1d538}', "A"), ('\u{1d539}', "B"), ('\u{1d53b}', "D"), ('\u{1d53c}', "E"),
+  ('\u{1d53d}', "F"), ('\u{1d53e}', "G"), ('\u{1d540}', "l"), ('\u{1d541}', "J"), ('\u{1d542}', "K"), ('\u{1d543}', "L"),
+  ('\u{1d544}', "M"), ('\u{1d546}', "O"), ('\u{1d54a}', "S"), ('\u{1d54b}', "T"), ('\u{1d54c}', "U"), ('\u{1d54d}', "V"),
+  ('\u{1d54e}', "W"), ('\u{1d54f}', "X"), ('\u{1d550}', "Y"), ('\u{1d552}', "a"), ('\u{1d553}', "b"), ('\u{1d554}', "c"),
+  ('\u{1d555}', "d"), ('\u{1d556}', "e"), ('\u{1d557}', "f"), ('\u{1d558}', "g"), ('\u{1d559}', "h"), ('\u{1d55a}', "i"),
+  ('\u{1d55b}', "j"), ('\u{1d55c}', "k"), ('\u{1d55d}', "l"), ('\u{1d55e}', "rn"), ('\u{1d55f}', "n"), ('\u{1d560}', "o"),
+  ('\u{1d561}', "p"), ('\u{1d562}', "q"), ('\u{1d563}', "r"), ('\u{1d564}', "s"), ('\u{1d565}', "t"), ('\u{1d566}', "u"),
+  ('\u{1d567}', "v"), ('\u{1d568}', "w"), ('\u{1d569}', "x"), ('\u{1d56a}', "y"), ('\u{1d56b}', "z"), ('\u{1d56c}', "A"),
+  ('\u{1d56d}', "B"), ('\u{1d56e}', "C"), ('\u{1d56f}', "D"), ('\u{1d570}', "E"), ('\u{1d571}', "F"), ('\u{1d572}', "G"),
+  ('\u{1d573}', "H"), ('\u{1d574}', "l"), ('\u{1d575}', "J"), ('\u{1d576}', "K"), ('\u{1d577}', "L"), ('\u{1d578}', "M"),
+  ('\u{1d579}', "N"), ('\u{1d57a}', "O"), ('\u{1d57b}', "P"), ('\u{1d57c}', "Q"), ('\u{1d57d}', "R"), ('\u{1d57e}', "S"),
+  ('\u{1d57f}', "T"), ('\u{1d580}', "U"), ('\u{1d581}', "V"), ('\u{1d582}', "W"), ('\u{1d583}', "X"), ('\u{1d584}', "Y"),
+  ('\u{1d585}', "Z"), ('\u{1d586}', "a"), ('\u{1d587}', "b"), ('\u{1d588}', "c"), ('\u{1d589}', "d"), ('\u{1d58a}', "e"),
+  ('\u{1d58b}', "f"), ('\u{1d58c}', "g"), ('\u{1d58d}', "h"), ('\u{1d58e}', "i"), ('\u{1d58f}', "j"), ('\u{1d590}', "k"),
+  ('\u{1d591}', "l"), ('\u{1d592}', "rn"), ('\u{1d593}', "n"), ('\u{1d594}', "o"), ('\u{1d595}', "p"), ('\u{1d596}', "q"),
+  ('\u{1d597}', "r"), ('\u{1d598}', "s"), ('\u{1d599}', "t"), ('\u{1d59a}', "u"), ('\u{1d59b}', "v"), ('\u{1d59c}', "w"),
+  ('\u{1d59d}', "x"), ('\u{1d59e}', "y"), ('\u{1d59f}', "z"), ('\u{1d5a0}', "A"), ('\u{1d5a1}', "B"), ('\u{1d5a2}', "C"),
+  ('\u{1d5a3}', "D"), ('\u{1d5a4}', "E"), ('\u{1d5a5}', "F"), ('\u{1d5a6}', "G"), ('\u{1d5a7}', "H"), ('\u{1d5a8}', "l"),
+  ('\u{1d5a9}', "J"), ('\u{1d5aa}', "K"), ('\u{1d5ab}', "L"), ('\u{1d5ac}', "M"), ('\u{1d5ad}', "N"), ('\u{1d5ae}', "O"),
+  ('\u{1d5af}', "P"), ('\u{1d5b0}', "Q"), ('\u{1d5b1}', "R"), ('\u{1d5b2}', "S"), ('\u{1d5b3}', "T"), ('\u{1d5b4}', "U"),
+  ('\u{1d5b5}', "V"), ('\u{1d5b6}', "W"), ('\u{1d5b7}', "X"), ('\u{1d5b8}', "Y"), ('\u{1d5b9}', "Z"), ('\u{1d5ba}', "a"),
+  ('\u{1d5bb}', "b"), ('\u{1d5bc}', "c"), ('\u{1d5bd}', "d"), ('\u{1d5be}', "e"), ('\u{1d5bf}', "f"), ('\u{1d5c0}', "g"),
+  ('\u{1d5c1}', "h"), ('\u{1d5c2}', "i"), ('\u{1d5c3}', "j"), ('\u{1d5c4}', "k"), ('\u{1d5c5}', "l"), ('\u{1d5c6}', "rn"),
+  ('\u{1d5c7}', "n"), ('\u{1d5c8}', "o"), ('\u{1d5c9}', "p"), ('\u{1d5ca}', "q"), ('\u{1d5cb}', "r"), ('\u{1d5cc}', "s"),
+  ('\u{1d5cd}', "t"), ('\u{1d5ce}', "u"), ('\u{1d5cf}', "v"), ('\u{1d5d0}', "w"), ('\u{1d5d1}', "x"), ('\u{1d5d2}', "y"),
+  ('\u{1d5d3}', "z"), ('\u{1d5d4}', "A"), ('\u{1d5d5}', "B"), ('\u{1d5d6}', "C"), ('\u{1d5d7}', "D"), ('\u{1d5d8}', "E"),
+  ('\u{1d5d9}', "F"), ('\u{1d5da}', "G"), ('\u{1d5db}', "H"), ('\u{1d5dc}', "l"), ('\u{1d5dd}', "J"), ('\u{1d5de}', "K"),
+  ('\u{1d5df}', "L"), ('\u{1d5e0}', "M"), ('\u{1d5e1}', "N"), ('\u{1d5e2}', "O"), ('\u{1d5e3}', "P"), ('\u{1d5e4}', "Q"),
+  ('\u{1d5e5}', "R"), ('\u{1d5e6}', "S"), ('\u{1d5e7}', "T"), ('\u{1d5e8}', "U"), ('\u{1d5e9}', "V"), ('\u{1d5ea}', "W"),
+  ('\u{1d5eb}', "X"), ('\u{1d5ec}', "Y"), ('\u{1d5ed}', "Z"), ('\u{1d5ee}', "a"), ('\u{1d5ef}', "b"), ('\u{1d5f0}', "c"),
+  ('\u{1d5f1}', "d"), ('\u{1d5f2}', "e"), ('\u{1d5f3}', "f"), ('\u{1d5f4}', "g"), ('\u{1d5f5}', "h"), ('\u{1d5f6}', "i"),
+  ('\u{1d5f7}', "j"), ('\u{1d5f8}', "k"), ('\u{1d5f9}', "l"), ('\u{1d5fa}', "rn"), ('\u{1d5fb}', "n"), ('\u{1d5fc}', "o"),
+  ('\u{1d5fd}', "p"), ('\u{1d5fe}', "q"), ('\u{1d5ff}', "r"), ('\u{1d600}', "s"), ('\u{1d601}', "t"), ('\u{1d602}', "u"),
+  ('\u{1d603}', "v"), ('\u{1d604}', "w"), ('\u{1d605}', "x"), ('\u{1d606}', "y"), ('\u{1d607}', "z"), ('\u{1d608}', "A"),
+  ('\u{1d609}', "B"), ('\u{1d60a}', "C"), ('\u{1d60b}', "D"), ('\u{1d60c}', "E"), ('\u{1d60d}', "F"), ('\u{1d60e}', "G"),
+  ('\u{1d60f}', "H"), ('\u{1d610}', "l"), ('\u{1d611}', "J"), ('\u{1d612}', "K"), ('\u{1d613}', "L"), ('\u{1d614}', "M"),
+  ('\u{1d615}', "N"), ('\u{1d616}', "O"), ('\u{1d617}', "P"), ('\u{1d618}', "Q"), ('\u{1d619}', "R"), ('\u{1d61a}', "S"),
+  ('\u{1d61b}', "T"), ('\u{1d61c}', "U"), ('\u{1d61d}', "V"), ('\u{1d61e}', "W"), ('\u{1d61f}', "X"), ('\u{1d620}', "Y"),
+  ('\u{1d621}', "Z"), ('\u{1d622}', "a"), ('\u{1d623}', "b"), ('\u{1d624}', "c"), ('\u{1d625}', "d"), ('\u{1d626}', "e"),
+  ('\u{1d627}', "f"), ('\u{1d628}', "g"), ('\u{1d629}', "h"), ('\u{1d62a}', "i"), ('\u{1d62b}', "j"), ('\u{1d62c}', "k"),
+  ('\u{1d62d}', "l"), ('\u{1d62e}', "rn"), ('\u{1d62f}', "n"), ('\u{1d630}', "o"), ('\u{1d631}', "p"), ('\u{1d632}', "q"),
+  ('\u{1d633}', "r"), ('\u{1d634}', "s"), ('\u{1d635}', "t"), ('\u{1d636}', "u"), ('\u{1d637}', "v"), ('\u{1d638}', "w"),
+  ('\u{1d639}', "x"), ('\u{1d63a}', "y"), ('\u{1d63b}', "z"), ('\u{1d63c}', "A"), ('\u{1d63d}', "B"), ('\u{1d63e}', "C"),
+  ('\u{1d63f}', "D"), ('\u{1d640}', "E"), ('\u{1d641}', "F"), ('\u{1d642}', "G"), ('\u{1d643}', "H"), ('\u{1d644}', "l"),
+  ('\u{1d645}', "J"), ('\u{1d646}', "K"), ('\u{1d647}', "L"), ('\u{1d648}', "M"), ('\u{1d649}', "N"), ('\u{1d64a}', "O"),
+  ('\u{1d64b}', "P"), ('\u{1d64c}', "Q"), ('\u{1d64d}', "R"), ('\u{1d64e}', "S"), ('\u{1d64f}', "T"), ('\u{1d650}', "U"),
+  ('\u{1d651}', "V"), ('\u{1d652}', "W"), ('\u{1d653}', "X"), ('\u{1d654}', "Y"), ('\u{1d655}', "Z"), ('\u{1d656}', "a"),
+  ('\u{1d657}', "b"), ('\u{1d658}', "c"), ('\u{1d659}', "d"), ('\u{1d65a}', "e"), ('\u{1d65b}', "f"), ('\u{1d65c}', "g"),
+  ('\u{1d65d}', "h"), ('\u{1d65e}', "i"), ('\u{1d65f}', "j"), ('\u{1d660}', "k"), ('\u{1d661}', "l"), ('\u{1d662}', "rn"),
+  ('\u{1d663}', "n"), ('\u{1d664}', "o"), ('\u{1d665}', "p"), ('\u{1d666}', "q"), ('\u{1d667}', "r"), ('\u{1d668}', "s"),
+  ('\u{1d669}', "t"), ('\u{1d66a}', "u"), ('\u{1d66b}', "v"), ('\u{1d66c}', "w"), ('\u{1d66d}', "x"), ('\u{1d66e}', "y"),
+  ('\u{1d66f}', "z"), ('\u{1d670}', "A"), ('\u{1d671}', "B"), ('\u{1d672}', "C"), ('\u{1d673}', "D"), ('\u{1d674}', "E"),
+  ('\u{1d675}', "F"), ('\u{1d676}', "G"), ('\u{1d677}', "H"), ('\u{1d678}', "l"), ('\u{1d679}', "J"), ('\u{1d67a}', "K"),
+  ('\u{1d67b}', "L"), ('\u{1d67c}', "M"), ('\u{1d67d}', "N"), ('\u{1d67e}', "O"), ('\u{1d67f}', "P"), ('\u{1d680}', "Q"),
+  ('\u{1d681}', "R"), ('\u{1d682}', "S"), ('\u{1d683}', "T"), ('\u{1d684}', "U"), ('\u{1d685}', "V"), ('\u{1d686}', "W"),
+  ('\u{1d687}', "X"), ('\u{1d688}', "Y"), ('\u{1d689}', "Z"), ('\u{1d68a}', "a"), ('\u{1d68b}', "b"), ('\u{1d68c}', "c"),
+  ('\u{1d68d}', "d"), ('\u{1d68e}', "e"), ('\u{1d68f}', "f"), ('\u{1d690}', "g"), ('\u{1d691}', "h"), ('\u{1d692}', "i"),
+  ('\u{1d693}', "j"), ('\u{1d694}', "k"), ('\u{1d695}', "l"), ('\u{1d696}', "rn"), ('\u{1d697}', "n"), ('\u{1d698}', "o"),
+  ('\u{1d699}', "p"), ('\u{1d69a}', "q"), ('\u{1d69b}', "r"), ('\u{1d69c}', "s"), ('\u{1d69d}', "t"), ('\u{1d69e}', "u"),
+  ('\u{1d69f}', "v"), ('\u{1d6a0}', "w"), ('\u{1d6a1}', "x"), ('\u{1d6a2}', "y"), ('\u{1d6a3}', "z"), ('\u{1d6a4}', "i"),
+  ('\u{1d6a5}', "\u{237}"), ('\u{1d6a8}', "A"), ('\u{1d6a9}', "B"), ('\u{1d6aa}', "\u{393}"), ('\u{1d6ab}', "\u{394}"), ('\u{1d6ac}', "E"),
+  ('\u{1d6ad}', "Z"), ('\u{1d6ae}', "H"), ('\u{1d6af}', "O\u{335}"), ('\u{1d6b0}', "l"), ('\u{1d6b1}', "K"), ('\u{1d6b2}', "\u{245}"),
+  ('\u{1d6b3}', "M"), ('\u{1d6b4}', "N"), ('\u{1d6b5}', "\u{39e}"), ('\u{1d6b6}', "O"), ('\u{1d6b7}', "\u{3a0}"), ('\u{1d6b8}', "P"),
+  ('\u{1d6b9}', "O\u{335}"), ('\u{1d6ba}', "\u{1a9}"), ('\u{1d6bb}', "T"), ('\u{1d6bc}', "Y"), ('\u{1d6bd}', "\u{3a6}"), ('\u{1d6be}', "X"),
+  ('\u{1d6bf}', "\u{3a8}"), ('\u{1d6c0}', "\u{3a9}"), ('\u{1d6c1}', "\u{2207}"), ('\u{1d6c2}', "a"), ('\u{1d6c3}', "\u{df}"), ('\u{1d6c4}', "y"),
+  ('\u{1d6c5}', "\u{1e9f}"), ('\u{1d6c6}', "\u{a793}"), ('\u{1d6c7}', "\u{3b6}"), ('\u{1d6c8}', "n\u{329}"), ('\u{1d6c9}', "O\u{335}"), ('\u{1d6ca}', "i"),
+  ('\u{1d6cb}', "\u{138}"), ('\u{1d6cc}', "\u{3bb}"), ('\u{1d6cd}', "\u{3bc}"), ('\u{1d6ce}', "v"), ('\u{1d6cf}', "\u{3be}"), ('\u{1d6d0}', "o"),
+  ('\u{1d6d1}', "\u{3c0}"), ('\u{1d6d2}', "p"), ('\u{1d6d3}', "\u{3c2}"), ('\u{1d6d4}', "o"), ('\u{1d6d5}', "\u{1d1b}"), ('\u{1d6d6}', "u"),
+  ('\u{1d6d7}', "\u{278}"), ('\u{1d6d8}', "\u{3c7}"), ('\u{1d6d9}', "\u{3c8}"), ('\u{1d6da}', "\u{3c9}"), ('\u{1d6db}', "\u{2202}"), ('\u{1d6dc}', "\u{a793}"),
+  ('\u{1d6dd}', "O\u{335}"), ('\u{1d6de}', "\u{138}"), ('\u{1d6df}', "\u{278}"), ('\u{1d6e0}', "p"), ('\u{1d6e1}', "\u{3c0}"), ('\u{1d6e2}', "A"),
+  ('\u{1d6e3}', "B"), ('\u{1d6e4}', "\u{393}"), ('\u{1d6e5}', "\u{394}"), ('\u{1d6e6}', "E"), ('\u{1d6e7}', "Z"), ('\u{1d6e8}', "H"),
+  ('\u{1d6e9}', "O\u{335}"), ('\u{1d6ea}', "l"), ('\u{1d6eb}', "K"), ('\u{1d6ec}', "\u{245}"), ('\u{1d6ed}', "M"), ('\u{1d6ee}', "N"),
+  ('\u{1d6ef}', "\u{39e}"), ('\u{1d6f0}', "O"), ('\u{1d6f1}', "\u{3a0}"), ('\u{1d6f2}', "P"), ('\u{1d6f3}', "O\u{335}"), ('\u{1d6f4}', "\u{1a9}"),
+  ('\u{1d6f5}', "T"), ('\u{1d6f6}', "Y"), ('\u{1d6f7}', "\u{3a6}"), ('\u{1d6f8}', "X"), ('\u{1d6f9}', "\u{3a8}"), ('\u{1d6fa}', "\u{3a9}"),
+  ('\u{1d6fb}', "\u{2207}"), ('\u{1d6fc}', "a"), ('\u{1d6fd}', "\u{df}"), ('\u{1d6fe}', "y"), ('\u{1d6ff}', "\u{1e9f}"), ('\u{1d700}', "\u{a793}"),
+  ('\u{1d701}', "\u{3b6}"), ('\u{1d702}', "n\u{329}"), ('\u{1d703}', "O\u{335}"), ('\u{1d704}', "i"), ('\u{1d705}', "\u{138}"), ('\u{1d706}', "\u{3bb}"),
+  ('\u{1d707}', "\u{3bc}"), ('\u{1d708}', "v"), ('\u{1d709}', "\u{3be}"), ('\u{1d70a}', "o"), ('\u{1d70b}', "\u{3c0}"), ('\u{1d70c}', "p"),
+  ('\u{1d70d}', "\u{3c2}"), ('\u{1d70e}', "o"), ('\u{1d70f}', "\u{1d1b}"), ('\u{1d710}', "u"), ('\u{1d711}', "\u{278}"), ('\u{1d712}', "\u{3c7}"),
+  ('\u{1d713}', "\u{3c8}"), ('\u{1d714}', "\u{3c9}"), ('\u{1d715}', "\u{2202}"), ('\u{1d716}', "\u{a793}"), ('\u{1d717}', "O\u{335}"), ('\u{1d718}', "\u{138}"),
+  ('\u{1d719}', "\u{278}"), ('\u{1d71a}', "p"), ('\u{1d71b}', "\u{3c0}"), ('\u{1d71c}', "A"), ('\u{1d71d}', "B"), ('\u{1d71e}', "\u{393}"),
+  ('\u{1d71f}', "\u{394}"), ('\u{1d720}', "E"), ('\u{1d721}', "Z"), ('\u{1d722}', "H"), ('\u{1d723}', "O\u{335}"), ('\u{1d724}', "l"),
+  ('\u{1d725}', "K"), ('\u{1d726}', "\u{245}"), ('\u{1d727}', "M"), ('\u{1d728}', "N"), ('\u{1d729}', "\u{39e}"), ('\u{1d72a}', "O"),
+  ('\u{1d72b}', "\u{3a0}"), ('\u{1d72c}', "P"), ('\u{1d72d}', "O\u{335}"), ('\u{1d72e}', "\u{1a9}"), ('\u{1d72f}', "T"), ('\u{1d730}', "Y"),
+  ('\u{1d731}', "\u{3a6}"), ('\u{1d732}', "X"), ('\u{1d733}', "\u{3a8}"), ('\u{1d734}', "\u{3a9}"), ('\u{1d735}', "\u{2207}"), ('\u{1d736}', "a"),
+  ('\u{1d737}', "\u{df}"), ('\u{1d738}', "y"), ('\u{1d739}', "\u{1e9f}"), ('\u{1d73a}', "\u{a793}"), ('\u{1d73b}', "\u{3b6}"), ('\u{1d73c}', "n\u{329}"),
+  ('\u{1d73d}', "O\u{335}"), ('\u{1d73e}', "i"), ('\u{1d73f}', "\u{138}"), ('\u{1d740}', "\u{3bb}"), ('\u{1d741}', "\u{3bc}"), ('\u{1d742}', "v"),
+  ('\u{1d743}', "\u{3be}"), ('\u{1d744}', "o"), ('\u{1d745}', "\u{3c0}"), ('\u{1d746}', "p"), ('\u{1d747}', "\u{3c2}"), ('\u{1d748}', "o"),
+  ('\u{1d749}', "\u{1d1b}"), ('\u{1d74a}', "u"), ('\u{1d74b}', "\u{278}"), ('\u{1d74c}', "\u{3c7}"), ('\u{1d74d}', "\u{3c8}"), ('\u{1d74e}', "\u{3c9}"),
+  ('\u{1d74f}', "\u{2202}"), ('\u{1d750}', "\u{a793}"), ('\u{1d751}', "O\u{335}"), ('\u{1d752}', "\u{138}"), ('\u{1d753}', "\u{278}"), ('\u{1d754}', "p"),
+  ('\u{1d755}', "\u{3c0}"), ('\u{1d756}', "A"), ('\u{1d757}', "B"), ('\u{1d758}', "\u{393}"), ('\u{1d759}', "\u{394}"), ('\u{1d75a}', "E"),
+  ('\u{1d75b}', "Z"), ('\u{1d75c}', "H"), ('\u{1d75d}', "O\u{335}"), ('\u{1d75e}', "l"), ('\u{1d75f}', "K"), ('\u{1d760}', "\u{245}"),
+  ('\u{1d761}', "M"), ('\u{1d762}', "N"), ('\u{1d763}', "\u{39e}"), ('\u{1d764}', "O"), ('\u{1d765}', "\u{3a0}"), ('\u{1d766}', "P"),
+  ('\u{1d767}', "O\u{335}"), ('\u{1d768}', "\u{1a9}"), ('\u{1d769}', "T"), ('\u{1d76a}', "Y"), ('\u{1d76b}', "\u{3a6}"), ('\u{1d76c}', "X"),
+  ('\u{1d76d}', "\u{3a8}"), ('\u{1d76e}', "\u{3a9}"), ('\u{1d76f}', "\u{2207}"), ('\u{1d770}', "a"), ('\u{1d771}', "\u{df}"), ('\u{1d772}', "y"),
+  ('\u{1d773}', "\u{1e9f}"), ('\u{1d774}', "\u{a793}"), ('\u{1d775}', "\u{3b6}"), ('\u{1d776}', "n\u{329}"), ('\u{1d777}', "O\u{335}"), ('\u{1d778}', "i"),
+  ('\u{1d779}', "\u{138}"), ('\u{1d77a}', "\u{3bb}"), ('\u{1d77b}', "\u{3bc}"), ('\u{1d77c}', "v"), ('\u{1d77d}', "\u{3be}"), ('\u{1d77e}', "o"),
+  ('\u{1d77f}', "\u{3c0}"), ('\u{1d780}', "p"), ('\u{1d781}', "\u{3c2}"), ('\u{1d782}', "o"), ('\u{1d783}', "\u{1d1b}"), ('\u{1d784}', "u"),
+  ('\u{1d785}', "\u{278}"), ('\u{1d786}', "\u{3c7}"), ('\u{1d787}', "\u{3c8}"), ('\u{1d788}', "\u{3c9}"), ('\u{1d789}', "\u{2202}"), ('\u{1d78a}', "\u{a793}"),
+  ('\u{1d78b}', "O\u{335}"), ('\u{1d78c}', "\u{138}"), ('\u{1d78d}', "\u{278}"), ('\u{1d78e}', "p"), ('\u{1d78f}', "\u{3c0}"), ('\u{1d790}', "A"),
+  ('\u{1d791}', "B"), ('\u{1d792}', "\u{393}"), ('\u{1d793}', "\u{394}"), ('\u{1d794}', "E"), ('\u{1d795}', "Z"), ('\u{1d796}', "H"),
+  ('\u{1d797}', "O\u{335}"), ('\u{1d798}', "l"), ('\u{1d799}', "K"), ('\u{1d79a}', "\u{245}"), ('\u{1d79b}', "M"), ('\u{1d79c}', "N"),
+  ('\u{1d79d}', "\u{39e}"), ('\u{1d79e}', "O"), ('\u{1d79f}', "\u{3a0}"), ('\u{1d7a0}', "P"), ('\u{1d7a1}', "O\u{335}"), ('\u{1d7a2}', "\u{1a9}"),
+  ('\u{1d7a3}', "T"), ('\u{1d7a4}', "Y"), ('\u{1d7a5}', "\u{3a6}"), ('\u{1d7a6}', "X"), ('\u{1d7a7}', "\u{3a8}"), ('\u{1d7a8}', "\u{3a9}"),
+  ('\u{1d7a9}', "\u{2207}"), ('\u{1d7aa}', "a"), ('\u{1d7ab}', "\u{df}"), ('\u{1d7ac}', "y"), ('\u{1d7ad}', "\u{1e9f}"), ('\u{1d7ae}', "\u{a793}"),
+  ('\u{1d7af}', "\u{3b6}"), ('\u{1d7b0}', "n\u{329}"), ('\u{1d7b1}', "O\u{335}"), ('\u{1d7b2}', "i"), ('\u{1d7b3}', "\u{138}"), ('\u{1d7b4}', "\u{3bb}"),
+  ('\u{1d7b5}', "\u{3bc}"), ('\u{1d7b6}', "v"), ('\u{1d7b7}', "\u{3be}"), ('\u{1d7b8}', "o"), ('\u{1d7b9}', "\u{3c0}"), ('\u{1d7ba}', "p"),
+  ('\u{1d7bb}', "\u{3c2}"), ('\u{1d7bc}', "o"), ('\u{1d7bd}', "\u{1d1b}"), ('\u{1d7be}', "u"), ('\u{1d7bf}', "\u{278}"), ('\u{1d7c0}', "\u{3c7}"),
+  ('\u{1d7c1}', "\u{3c8}"), ('\u{1d7c2}', "\u{3c9}"), ('\u{1d7c3}', "\u{2202}"), ('\u{1d7c4}', "\u{a793}"), ('\u{1d7c5}', "O\u{335}"), ('\u{1d7c6}', "\u{138}"),
+  ('\u{1d7c7}', "\u{278}"), ('\u{1d7c8}', "p"), ('\u{1d7c9}', "\u{3c0}"), ('\u{1d7ca}', "F"), ('\u{1d7cb}', "\u{3dd}"), ('\u{1d7ce}', "O"),
+  ('\u{1d7cf}', "l"), ('\u{1d7d0}', "2"), ('\u{1d7d1}', "3"), ('\u{1d7d2}', "4"), ('\u{1d7d3}', "5"), ('\u{1d7d4}', "6"),
+  ('\u{1d7d5}', "7"), ('\u{1d7d6}', "8"), ('\u{1d7d7}', "9"), ('\u{1d7d8}', "O"), ('\u{1d7d9}', "l"), ('\u{1d7da}', "2"),
+  ('\u{1d7db}', "3"), ('\u{1d7dc}', "4"), ('\u{1d7dd}', "5"), ('\u{1d7de}', "6"), ('\u{1d7df}', "7"), ('\u{1d7e0}', "8"),
+  ('\u{1d7e1}', "9"), ('\u{1d7e2}', "O"), ('\u{1d7e3}', "l"), ('\u{1d7e4}', "2"), ('\u{1d7e5}', "3"), ('\u{1d7e6}', "4"),
+  ('\u{1d7e7}', "5"), ('\u{1d7e8}', "6"), ('\u{1d7e9}', "7"), ('\u{1d7ea}', "8"), ('\u{1d7eb}', "9"), ('\u{1d7ec}', "O"),
+  ('\u{1d7ed}', "l"), ('\u{1d7ee}', "2"), ('\u{1d7ef}', "3"), ('\u{1d7f0}', "4"), ('\u{1d7f1}', "5"), ('\u{1d7f2}', "6"),
+  ('\u{1d7f3}', "7"), ('\u{1d7f4}', "8"), ('\u{1d7f5}', "9"), ('\u{1d7f6}', "O"), ('\u{1d7f7}', "l"), ('\u{1d7f8}', "2"),
+  ('\u{1d7f9}', "3"), ('\u{1d7fa}', "4"), ('\u{1d7fb}', "5"), ('\u{1d7fc}', "6"), ('\u{1d7fd}', "7"), ('\u{1d7fe}', "8"),
+  ('\u{1d7ff}', "9"), ('\u{1e8c7}', "l"), ('\u{1e8c8}', "\u{2220}"), ('\u{1e8c9}', "\u{663}"), ('\u{1e8cb}', "8"), ('\u{1e8cc}', "\u{2202}"),
+  ('\u{1e8cd}', "\u{2202}\u{335}"), ('\u{1ee00}', "l"), ('\u{1ee01}', "\u{628}"), ('\u{1ee02}', "\u{62c}"), ('\u{1ee03}', "\u{62f}"), ('\u{1ee05}', "\u{648}"),
+  ('\u{1ee06}', "\u{632}"), ('\u{1ee07}', "\u{62d}"), ('\u{1ee08}', "\u{637}"), ('\u{1ee09}', "\u{649}"), ('\u{1ee0a}', "\u{643}"), ('\u{1ee0b}', "\u{644}"),
+  ('\u{1ee0c}', "\u{645}"), ('\u{1ee0d}', "\u{646}"), ('\u{1ee0e}', "\u{633}"), ('\u{1ee0f}', "\u{639}"), ('\u{1ee10}', "\u{641}"), ('\u{1ee11}', "\u{635}"),
+  ('\u{1ee12}', "\u{642}"), ('\u{1ee13}', "\u{631}"), ('\u{1ee14}', "\u{633}\u{6db}"), ('\u{1ee15}', "\u{62a}"), ('\u{1ee16}', "\u{649}\u{6db}"), ('\u{1ee17}', "\u{62e}"),
+  ('\u{1ee18}', "\u{630}"), ('\u{1ee19}', "\u{636}"), ('\u{1ee1a}', "\u{638}"), ('\u{1ee1b}', "\u{63a}"), ('\u{1ee1c}', "\u{649}"), ('\u{1ee1d}', "\u{649}"),
+  ('\u{1ee1e}', "\u{6a1}"), ('\u{1ee1f}', "\u{6a1}"), ('\u{1ee21}', "\u{628}"), ('\u{1ee22}', "\u{62c}"), ('\u{1ee24}', "o"), ('\u{1ee27}', "\u{62d}"),
+  ('\u{1ee29}', "\u{649}"), ('\u{1ee2a}', "\u{643}"), ('\u{1ee2b}', "\u{644}"), ('\u{1ee2c}', "\u{645}"), ('\u{1ee2d}', "\u{646}"), ('\u{1ee2e}', "\u{633}"),
+  ('\u{1ee2f}', "\u{639}"), ('\u{1ee30}', "\u{641}"), ('\u{1ee31}', "\u{635}"), ('\u{1ee32}', "\u{642}"), ('\u{1ee34}', "\u{633}\u{6db}"), ('\u{1ee35}', "\u{62a}"),
+  ('\u{1ee36}', "\u{649}\u{6db}"), ('\u{1ee37}', "\u{62e}"), ('\u{1ee39}', "\u{636}"), ('\u{1ee3b}', "\u{63a}"), ('\u{1ee42}', "\u{62c}"), ('\u{1ee47}', "\u{62d}"),
+  ('\u{1ee49}', "\u{649}"), ('\u{1ee4b}', "\u{644}"), ('\u{1ee4d}', "\u{646}"), ('\u{1ee4e}', "\u{633}"), ('\u{1ee4f}', "\u{639}"), ('\u{1ee51}', "\u{635}"),
+  ('\u{1ee52}', "\u{642}"), ('\u{1ee54}', "\u{633}\u{6db}"), ('\u{1ee57}', "\u{62e}"), ('\u{1ee59}', "\u{636}"), ('\u{1ee5b}', "\u{63a}"), ('\u{1ee5d}', "\u{649}"),
+  ('\u{1ee5f}', "\u{6a1}"), ('\u{1ee61}', "\u{628}"), ('\u{1ee62}', "\u{62c}"), ('\u{1ee64}', "o"), ('\u{1ee67}', "\u{62d}"), ('\u{1ee68}', "\u{637}"),
+  ('\u{1ee69}', "\u{649}"), ('\u{1ee6a}', "\u{643}"), ('\u{1ee6c}', "\u{645}"), ('\u{1ee6d}', "\u{646}"), ('\u{1ee6e}', "\u{633}"), ('\u{1ee6f}', "\u{639}"),
+  ('\u{1ee70}', "\u{641}"), ('\u{1ee71}', "\u{635}"), ('\u{1ee72}', "\u{642}"), ('\u{1ee74}', "\u{633}\u{6db}"), ('\u{1ee75}', "\u{62a}"), ('\u{1ee76}', "\u{649}\u{6db}"),
+  ('\u{1ee77}', "\u{62e}"), ('\u{1ee79}', "\u{636}"), ('\u{1ee7a}', "\u{638}"), ('\u{1ee7b}', "\u{63a}"), ('\u{1ee7c}', "\u{649}"), ('\u{1ee7e}', "\u{6a1}"),
+  ('\u{1ee80}', "l"), ('\u{1ee81}', "\u{628}"), ('\u{1ee82}', "\u{62c}"), ('\u{1ee83}', "\u{62f}"), ('\u{1ee84}', "o"), ('\u{1ee85}', "\u{648}"),
+  ('\u{1ee86}', "\u{632}"), ('\u{1ee87}', "\u{62d}"), ('\u{1ee88}', "\u{637}"), ('\u{1ee89}', "\u{649}"), ('\u{1ee8b}', "\u{644}"), ('\u{1ee8c}', "\u{645}"),
+  ('\u{1ee8d}', "\u{646}"), ('\u{1ee8e}', "\u{633}"), ('\u{1ee8f}', "\u{639}"), ('\u{1ee90}', "\u{641}"), ('\u{1ee91}', "\u{635}"), ('\u{1ee92}', "\u{642}"),
+  ('\u{1ee93}', "\u{631}"), ('\u{1ee94}', "\u{633}\u{6db}"), ('\u{1ee95}', "\u{62a}"), ('\u{1ee96}', "\u{649}\u{6db}"), ('\u{1ee97}', "\u{62e}"), ('\u{1ee98}', "\u{630}"),
+  ('\u{1ee99}', "\u{636}"), ('\u{1ee9a}', "\u{638}"), ('\u{1ee9b}', "\u{63a}"), ('\u{1eea1}', "\u{628}"), ('\u{1eea2}', "\u{62c}"), ('\u{1eea3}', "\u{62f}"),
+  ('\u{1eea5}', "\u{648}"), ('\u{1eea6}', "\u{632}"), ('\u{1eea7}', "\u{62d}"), ('\u{1eea8}', "\u{637}"), ('\u{1eea9}', "\u{649}"), ('\u{1eeab}', "\u{644}"),
+  ('\u{1eeac}', "\u{645}"), ('\u{1eead}', "\u{646}"), ('\u{1eeae}', "\u{633}"), ('\u{1eeaf}', "\u{639}"), ('\u{1eeb0}', "\u{641}"), ('\u{1eeb1}', "\u{635}"),
+  ('\u{1eeb2}', "\u{642}"), ('\u{1eeb3}', "\u{631}"), ('\u{1eeb4}', "\u{633}\u{6db}"), ('\u{1eeb5}', "\u{62a}"), ('\u{1eeb6}', "\u{649}\u{6db}"), ('\u{1eeb7}', "\u{62e}"),
+  ('\u{1eeb8}', "\u{630}"), ('\u{1eeb9}', "\u{636}"), ('\u{1eeba}', "\u{638}"), ('\u{1eebb}', "\u{63a}"), ('\u{1f100}', "O."), ('\u{1f101}', "O,"),
+  ('\u{1f102}', "l,"), ('\u{1f103}', "2,"), ('\u{1f104}', "3,"), ('\u{1f105}', "4,"), ('\u{1f106}', "5,"), ('\u{1f107}', "6,"),
+  ('\u{1f108}', "7,"), ('\u{1f109}', "8,"), ('\u{1f10a}', "9,"), ('\u{1f10f}', "$\u{20e0}"), ('\u{1f110}', "(A)"), ('\u{1f111}', "(B)"),
+  ('\u{1f112}', "(C)"), ('\u{1f113}', "(D)"), ('\u{1f114}', "(E)"), ('\u{1f115}', "(F)"), ('\u{1f116}', "(G)"), ('\u{1f117}', "(H)"),
+  ('\u{1f118}', "(l)"), ('\u{1f119}', "(J)"), ('\u{1f11a}', "(K)"), ('\u{1f11b}', "(L)"), ('\u{1f11c}', "(M)"), ('\u{1f11d}', "(N)"),
+  ('\u{1f11e}', "(O)"), ('\u{1f11f}', "(P)"), ('\u{1f120}', "(Q)"), ('\u{1f121}', "(R)"), ('\u{1f122}', "(S)"), ('\u{1f123}', "(T)"),
+  ('\u{1f124}', "(U)"), ('\u{1f125}', "(V)"), ('\u{1f126}', "(W)"), ('\u{1f127}', "(X)"), ('\u{1f128}', "(Y)"), ('\u{1f129}', "(Z)"),
+  ('\u{1f12a}', "(S)"), ('\u{1f16d}', "\u{33c4}\u{9}\u{20dd}"), ('\u{1f16e}', "C\u{20e0}"), ('\u{1f240}', "(\u{672c})"), ('\u{1f241}', "(\u{4e09})"), ('\u{1f242}', "(\u{4e8c})"),
+  ('\u{1f243}', "(\u{5b89})"), ('\u{1f244}', "(\u{70b9})"), ('\u{1f245}', "(\u{6253})"), ('\u{1f246}', "(\u{76d7})"), ('\u{1f247}', "(\u{52dd})"), ('\u{1f248}', "(\u{6557})"),
+  ('\u{1f312}', "\u{263d}"), ('\u{1f318}', "\u{263e}"), ('\u{1f319}', "\u{263d}"), ('\u{1f700}', "QE"), ('\u{1f701}', "\u{a658}"), ('\u{1f702}', "\u{394}"),
+  ('\u{1f704}', "\u{102bc}"), ('\u{1f707}', "AR"), ('\u{1f708}', "V\u{1de4}"), ('\u{1f70a}', "\u{2629}"), ('\u{1f714}', "O\u{335}"), ('\u{1f728}', "\u{102a8}"),
+  ('\u{1f73a}', "\u{29df}"), ('\u{1f74c}', "C"), ('\u{1f754}', "\u{16dc}"), ('\u{1f755}', "\u{22a1}"), ('\u{1f75c}', "sss"), ('\u{1f75e}', "\u{224f}"),
+  ('\u{1f768}', "T"), ('\u{1f76b}', "MB"), ('\u{1f76c}', "VB"), ('\u{1f771}', "\u{22a0}"), ('\u{1fbf0}', "O"), ('\u{1fbf1}', "l"),
+  ('\u{1fbf2}', "2"), ('\u{1fbf3}', "3"), ('\u{1fbf4}', "4"), ('\u{1fbf5}', "5"), ('\u{1fbf6}', "6"), ('\u{1fbf7}', "7"),
+  ('\u{1fbf8}', "8"), ('\u{1fbf9}', "9"), ('\u{21fe8}', "\u{276c}"), ('\u{2f800}', "\u{4e3d}"), ('\u{2f801}', "\u{4e38}"), ('\u{2f802}', "\u{4e41}"),
+  ('\u{2f803}', "\u{20122}"), ('\u{2f804}', "\u{4f60}"), ('\u{2f805}', "\u{4fae}"), ('\u{2f806}', "\u{4fbb}"), ('\u{2f807}', "\u{4f75}"), ('\u{2f808}', "\u{507a}"),
+  ('\u{2f809}', "\u{5099}"), ('\u{2f80a}', "\u{50e7}"), ('\u{2f80b}', "\u{50cf}"), ('\u{2f80c}', "\u{349e}"), ('\u{2f80d}', "\u{2063a}"), ('\u{2f80e}', "\u{514d}"),
+  ('\u{2f80f}', "\u{5154}"), ('\u{2f810}', "\u{5164}"), ('\u{2f811}', "\u{5177}"), ('\u{2f812}', "\u{2051c}"), ('\u{2f813}', "\u{34b9}"), ('\u{2f814}', "\u{5167}"),
+  ('\u{2f815}', "\u{518d}"), ('\u{2f816}', "\u{2054b}"), ('\u{2f817}', "\u{5197}"), ('\u{2f818}', "\u{51a4}"), ('\u{2f819}', "\u{4ecc}"), ('\u{2f81a}', "\u{51ac}"),
+  ('\u{2f81b}', "\u{51b5}"), ('\u{2f81c}', "\u{291df}"), ('\u{2f81d}', "\u{51f5}"), ('\u{2f81e}', "\u{5203}"), ('\u{2f81f}', "\u{34df}"), ('\u{2f820}', "\u{523b}"),
+  ('\u{2f821}', "\u{5246}"), ('\u{2f822}', "\u{5272}"), ('\u{2f823}', "\u{5277}"), ('\u{2f824}', "\u{3515}"), ('\u{2f825}', "\u{52c7}"), ('\u{2f826}', "\u{52c9}"),
+  ('\u{2f827}', "\u{52e4}"), ('\u{2f828}', "\u{52fa}"), ('\u{2f829}', "\u{5305}"), ('\u{2f82a}', "\u{5306}"), ('\u{2f82b}', "\u{5317}"), ('\u{2f82c}', "\u{5349}"),
+  ('\u{2f82d}', "\u{5351}"), ('\u{2f82e}', "\u{535a}"), ('\u{2f82f}', "\u{5373}"), ('\u{2f830}', "\u{537d}"), ('\u{2f831}', "\u{537f}"), ('\u{2f832}', "\u{537f}"),
+  ('\u{2f833}', "\u{537f}"), ('\u{2f834}', "\u{20a2c}"), ('\u{2f835}', "\u{7070}"), ('\u{2f836}', "\u{53ca}"), ('\u{2f837}', "\u{53df}"), ('\u{2f838}', "\u{20b63}"),
+  ('\u{2f839}', "\u{53eb}"), ('\u{2f83a}', "\u{53f1}"), ('\u{2f83b}', "\u{5406}"), ('\u{2f83c}', "\u{549e}"), ('\u{2f83d}', "\u{5438}"), ('\u{2f83e}', "\u{5448}"),
+  ('\u{2f83f}', "\u{5468}"), ('\u{2f840}', "\u{54a2}"), ('\u{2f841}', "\u{54f6}"), ('\u{2f842}', "\u{5510}"), ('\u{2f843}', "\u{5553}"), ('\u{2f844}', "\u{5563}"),
+  ('\u{2f845}', "\u{5584}"), ('\u{2f846}', "\u{5584}"), ('\u{2f847}', "\u{5599}"), ('\u{2f848}', "\u{55ab}"), ('\u{2f849}', "\u{55b3}"), ('\u{2f84a}', "\u{55c2}"),
+  ('\u{2f84b}', "\u{5716}"), ('\u{2f84c}', "\u{5606}"), ('\u{2f84d}', "\u{5717}"), ('\u{2f84e}', "\u{5651}"), ('\u{2f84f}', "\u{5674}"), ('\u{2f850}', "\u{5207}"),
+  ('\u{2f851}', "\u{58ee}"), ('\u{2f852}', "\u{57ce}"), ('\u{2f853}', "\u{57f4}"), ('\u{2f854}', "\u{580d}"), ('\u{2f855}', "\u{578b}"), ('\u{2f856}', "\u{5832}"),
+  ('\u{2f857}', "\u{5831}"), ('\u{2f858}', "\u{58ac}"), ('\u{2f859}', "\u{214e4}"), ('\u{2f85a}', "\u{58f2}"), ('\u{2f85b}', "\u{58f7}"), ('\u{2f85c}', "\u{5906}"),
+  ('\u{2f85d}', "\u{591a}"), ('\u{2f85e}', "\u{5922}"), ('\u{2f85f}', "\u{5962}"), ('\u{2f860}', "\u{216a8}"), ('\u{2f861}', "\u{216ea}"), ('\u{2f862}', "\u{59ec}"),
+  ('\u{2f863}', "\u{5a1b}"), ('\u{2f864}', "\u{5a27}"), ('\u{2f865}', "\u{59d8}"), ('\u{2f866}', "\u{5a66}"), ('\u{2f867}', "\u{36ee}"), ('\u{2f868}', "\u{36fc}"),
+  ('\u{2f869}', "\u{5b08}"), ('\u{2f86a}', "\u{5b3e}"), ('\u{2f86b}', "\u{5b3e}"), ('\u{2f86c}', "\u{219c8}"), ('\u{2f86d}', "\u{5bc3}"), ('\u{2f86e}', "\u{5bd8}"),
+  ('\u{2f86f}', "\u{5be7}"), ('\u{2f870}', "\u{5bf3}"), ('\u{2f871}', "\u{21b18}"), ('\u{2f872}', "\u{5bff}"), ('\u{2f873}', "\u{5c06}"), ('\u{2f874}', "\u{5f53}"),
+  ('\u{2f875}', "\u{5c22}"), ('\u{2f876}', "\u{3781}"), ('\u{2f877}', "\u{5c60}"), ('\u{2f878}', "\u{5c6e}"), ('\u{2f879}', "\u{5cc0}"), ('\u{2f87a}', "\u{5c8d}"),
+  ('\u{2f87b}', "\u{21de4}"), ('\u{2f87c}', "\u{5d43}"), ('\u{2f87d}', "\u{21de6}"), ('\u{2f87e}', "\u{5d6e}"), ('\u{2f87f}', "\u{5d6b}"), ('\u{2f880}', "\u{5d7c}"),
+  ('\u{2f881}', "\u{5de1}"), ('\u{2f882}', "\u{5de2}"), ('\u{2f883}', "\u{382f}"), ('\u{2f884}', "\u{5dfd}"), ('\u{2f885}', "\u{5e28}"), ('\u{2f886}', "\u{5e3d}"),
+  ('\u{2f887}', "\u{5e69}"), ('\u{2f888}', "\u{3862}"), ('\u{2f889}', "\u{22183}"), ('\u{2f88a}', "\u{387c}"), ('\u{2f88b}', "\u{5eb0}"), ('\u{2f88c}', "\u{5eb3}"),
+  ('\u{2f88d}', "\u{5eb6}"), ('\u{2f88e}', "\u{5eca}"), ('\u{2f88f}', "\u{2a392}"), ('\u{2f890}', "\u{5efe}"), ('\u{2f891}', "\u{22331}"), ('\u{2f892}', "\u{22331}"),
+  ('\u{2f893}', "\u{8201}"), ('\u{2f894}', "\u{5f22}"), ('\u{2f895}', "\u{5f22}"), ('\u{2f896}', "\u{38c7}"), ('\u{2f897}', "\u{232b8}"), ('\u{2f898}', "\u{261da}"),
+  ('\u{2f899}', "\u{5f62}"), ('\u{2f89a}', "\u{5f6b}"), ('\u{2f89b}', "\u{38e3}"), ('\u{2f89c}', "\u{5f9a}"), ('\u{2f89d}', "\u{5fcd}"), ('\u{2f89e}', "\u{5fd7}"),
+  ('\u{2f89f}', "\u{5ff9}"), ('\u{2f8a0}', "\u{6081}"), ('\u{2f8a1}', "\u{393a}"), ('\u{2f8a2}', "\u{391c}"), ('\u{2f8a3}', "\u{6094}"), ('\u{2f8a4}', "\u{226d4}"),
+  ('\u{2f8a5}', "\u{60c7}"), ('\u{2f8a6}', "\u{6148}"), ('\u{2f8a7}', "\u{614c}"), ('\u{2f8a8}', "\u{614e}"), ('\u{2f8a9}', "\u{614c}"), ('\u{2f8aa}', "\u{617a}"),
+  ('\u{2f8ab}', "\u{618e}"), ('\u{2f8ac}', "\u{61b2}"), ('\u{2f8ad}', "\u{61a4}"), ('\u{2f8ae}', "\u{61af}"), ('\u{2f8af}', "\u{61de}"), ('\u{2f8b0}', "\u{61f2}"),
+  ('\u{2f8b1}', "\u{61f6}"), ('\u{2f8b2}', "\u{6210}"), ('\u{2f8b3}', "\u{621b}"), ('\u{2f8b4}', "\u{625d}"), ('\u{2f8b5}', "\u{62b1}"), ('\u{2f8b6}', "\u{62d4}"),
+  ('\u{2f8b7}', "\u{6350}"), ('\u{2f8b8}', "\u{22b0c}"), ('\u{2f8b9}', "\u{633d}"), ('\u{2f8ba}', "\u{62fc}"), ('\u{2f8bb}', "\u{6368}"), ('\u{2f8bc}', "\u{6383}"),
+  ('\u{2f8bd}', "\u{63e4}"), ('\u{2f8be}', "\u{22bf1}"), ('\u{2f8bf}', "\u{6422}"), ('\u{2f8c0}', "\u{63c5}"), ('\u{2f8c1}', "\u{63a9}"), ('\u{2f8c2}', "\u{3a2e}"),
+  ('\u{2f8c3}', "\u{6469}"), ('\u{2f8c4}', "\u{647e}"), ('\u{2f8c5}', "\u{649d}"), ('\u{2f8c6}', "\u{6477}"), ('\u{2f8c7}', "\u{3a6c}"), ('\u{2f8c8}', "\u{654f}"),
+  ('\u{2f8c9}', "\u{656c}"), ('\u{2f8ca}', "\u{2300a}"), ('\u{2f8cb}', "\u{65e3}"), ('\u{2f8cc}', "\u{66f8}"), ('\u{2f8cd}', "\u{6649}"), ('\u{2f8ce}', "\u{3b19}"),
+  ('\u{2f8cf}', "\u{6691}"), ('\u{2f8d0}', "\u{3b08}"), ('\u{2f8d1}', "\u{3ae4}"), ('\u{2f8d2}', "\u{5192}"), ('\u{2f8d3}', "\u{5195}"), ('\u{2f8d4}', "\u{6700}"),
+  ('\u{2f8d5}', "\u{669c}"), ('\u{2f8d6}', "\u{80ad}"), ('\u{2f8d7}', "\u{43d9}"), ('\u{2f8d8}', "\u{6717}"), ('\u{2f8d9}', "\u{671b}"), ('\u{2f8da}', "\u{6721}"),
+  ('\u{2f8db}', "\u{675e}"), ('\u{2f8dc}', "\u{6753}"), ('\u{2f8dd}', "\u{233c3}"), ('\u{2f8de}', "\u{3b49}"), ('\u{2f8df}', "\u{67fa}"), ('\u{2f8e0}', "\u{6785}"),
+  ('\u{2f8e1}', "\u{6852}"), ('\u{2f8e2}', "\u{6885}"), ('\u{2f8e3}', "\u{2346d}"), ('\u{2f8e4}', "\u{688e}"), ('\u{2f8e5}', "\u{681f}"), ('\u{2f8e6}', "\u{6914}"),
+  ('\u{2f8e7}', "\u{3b9d}"), ('\u{2f8e8}', "\u{6942}"), ('\u{2f8e9}', "\u{69a3}"), ('\u{2f8ea}', "\u{69ea}"), ('\u{2f8eb}', "\u{6aa8}"), ('\u{2f8ec}', "\u{236a3}"),
+  ('\u{2f8ed}', "\u{6adb}"), ('\u{2f8ee}', "\u{3c18}"), ('\u{2f8ef}', "\u{6b21}"), ('\u{2f8f0}', "\u{238a7}"), ('\u{2f8f1}', "\u{6b54}"), ('\u{2f8f2}', "\u{3c4e}"),
+  ('\u{2f8f3}', "\u{6b72}"), ('\u{2f8f4}', "\u{6b9f}"), ('\u{2f8f5}', "\u{6bba}"), ('\u{2f8f6}', "\u{6bbb}"), ('\u{2f8f7}', "\u{23a8d}"), ('\u{2f8f8}', "\u{21d0b}"),
+  ('\u{2f8f9}', "\u{23afa}"), ('\u{2f8fa}', "\u{6c4e}"), ('\u{2f8fb}', "\u{23cbc}"), ('\u{2f8fc}', "\u{6cbf}"), ('\u{2f8fd}', "\u{6ccd}"), ('\u{2f8fe}', "\u{6c67}"),
+  ('\u{2f8ff}', "\u{6d16}"), ('\u{2f900}', "\u{6d3e}"), ('\u{2f901}', "\u{6d77}"), ('\u{2f902}', "\u{6d41}"), ('\u{2f903}', "\u{6d69}"), ('\u{2f904}', "\u{6d78}"),
+  ('\u{2f905}', "\u{6d85}"), ('\u{2f906}', "\u{23d1e}"), ('\u{2f907}', "\u{6d34}"), ('\u{2f908}', "\u{6e2f}"), ('\u{2f909}', "\u{6e6e}"), ('\u{2f90a}', "\u{3d33}"),
+  ('\u{2f90b}', "\u{6ecb}"), ('\u{2f90c}', "\u{6ec7}"), ('\u{2f90d}', "\u{23ed1}"), ('\u{2f90e}', "\u{6df9}"), ('\u{2f90f}', "\u{6f6e}"), ('\u{2f910}', "\u{23f5e}"),
+  ('\u{2f911}', "\u{23f8e}"), ('\u{2f912}', "\u{6fc6}"), ('\u{2f913}', "\u{7039}"), ('\u{2f914}', "\u{701e}"), ('\u{2f915}', "\u{701b}"), ('\u{2f916}', "\u{3d96}"),
+  ('\u{2f917}', "\u{704a}"), ('\u{2f918}', "\u{707d}"), ('\u{2f919}', "\u{7077}"), ('\u{2f91a}', "\u{70ad}"), ('\u{2f91b}', "\u{20525}"), ('\u{2f91c}', "\u{7145}"),
+  ('\u{2f91d}', "\u{24263}"), ('\u{2f91e}', "\u{719c}"), ('\u{2f91f}', "\u{243ab}"), ('\u{2f920}', "\u{7228}"), ('\u{2f921}', "\u{7235}"), ('\u{2f922}', "\u{7250}"),
+  ('\u{2f923}', "\u{24608}"), ('\u{2f924}', "\u{7280}"), ('\u{2f925}', "\u{7295}"), ('\u{2f926}', "\u{24735}"), ('\u{2f927}', "\u{24814}"), ('\u{2f928}', "\u{737a}"),
+  ('\u{2f929}', "\u{738b}"), ('\u{2f92a}', "\u{3eac}"), ('\u{2f92b}', "\u{73a5}"), ('\u{2f92c}', "\u{3eb8}"), ('\u{2f92d}', "\u{3eb8}"), ('\u{2f92e}', "\u{7447}"),
+  ('\u{2f92f}', "\u{745c}"), ('\u{2f930}', "\u{7471}"), ('\u{2f931}', "\u{7485}"), ('\u{2f932}', "\u{74ca}"), ('\u{2f933}', "\u{3f1b}"), ('\u{2f934}', "\u{7524}"),
+  ('\u{2f935}', "\u{24c36}"), ('\u{2f936}', "\u{753e}"), ('\u{2f937}', "\u{24c92}"), ('\u{2f938}', "\u{7570}"), ('\u{2f939}', "\u{2219f}"), ('\u{2f93a}', "\u{7610}"),
+  ('\u{2f93b}', "\u{24fa1}"), ('\u{2f93c}', "\u{24fb8}"), ('\u{2f93d}', "\u{25044}"), ('\u{2f93e}', "\u{3ffc}"), ('\u{2f93f}', "\u{4008}"), ('\u{2f940}', "\u{76f4}"),
+  ('\u{2f941}', "\u{250f3}"), ('\u{2f942}', "\u{250f2}"), ('\u{2f943}', "\u{25119}"), ('\u{2f944}', "\u{25133}"), ('\u{2f945}', "\u{771e}"), ('\u{2f946}', "\u{771f}"),
+  ('\u{2f947}', "\u{771f}"), ('\u{2f948}', "\u{774a}"), ('\u{2f949}', "\u{4039}"), ('\u{2f94a}', "\u{778b}"), ('\u{2f94b}', "\u{4046}"), ('\u{2f94c}', "\u{4096}"),
+  ('\u{2f94d}', "\u{2541d}"), ('\u{2f94e}', "\u{784e}"), ('\u{2f94f}', "\u{788c}"), ('\u{2f950}', "\u{78cc}"), ('\u{2f951}', "\u{40e3}"), ('\u{2f952}', "\u{25626}"),
+  ('\u{2f953}', "\u{7956}"), ('\u{2f954}', "\u{2569a}"), ('\u{2f955}', "\u{256c5}"), ('\u{2f956}', "\u{798f}"), ('\u{2f957}', "\u{79eb}"), ('\u{2f958}', "\u{412f}"),
+  ('\u{2f959}', "\u{7a40}"), ('\u{2f95a}', "\u{7a4a}"), ('\u{2f95b}', "\u{7a4f}"), ('\u{2f95c}', "\u{2597c}"), ('\u{2f95d}', "\u{25aa7}"), ('\u{2f95e}', "\u{25aa7}"),
+  ('\u{2f95f}', "\u{7aee}"), ('\u{2f960}', "\u{4202}"), ('\u{2f961}', "\u{25bab}"), ('\u{2f962}', "\u{7bc6}"), ('\u{2f963}', "\u{7bc9}"), ('\u{2f964}', "\u{4227}"),
+  ('\u{2f965}', "\u{25c80}"), ('\u{2f966}', "\u{7cd2}"), ('\u{2f967}', "\u{42a0}"), ('\u{2f968}', "\u{7ce8}"), ('\u{2f969}', "\u{7ce3}"), ('\u{2f96a}', "\u{7d00}"),
+  ('\u{2f96b}', "\u{25f86}"), ('\u{2f96c}', "\u{7d63}"), ('\u{2f96d}', "\u{4301}"), ('\u{2f96e}', "\u{7dc7}"), ('\u{2f96f}', "\u{7e02}"), ('\u{2f970}', "\u{7e45}"),
+  ('\u{2f971}', "\u{4334}"), ('\u{2f972}', "\u{26228}"), ('\u{2f973}', "\u{26247}"), ('\u{2f974}', "\u{4359}"), ('\u{2f975}', "\u{262d9}"), ('\u{2f976}', "\u{7f7a}"),
+  ('\u{2f977}', "\u{2633e}"), ('\u{2f978}', "\u{7f95}"), ('\u{2f979}', "\u{7ffa}"), ('\u{2f97a}', "\u{8005}"), ('\u{2f97b}', "\u{264da}"), ('\u{2f97c}', "\u{26523}"),
+  ('\u{2f97d}', "\u{8060}"), ('\u{2f97e}', "\u{265a8}"), ('\u{2f97f}', "\u{8070}"), ('\u{2f980}', "\u{2335f}"), ('\u{2f981}', "\u{43d5}"), ('\u{2f982}', "\u{80b2}"),
+  ('\u{2f983}', "\u{8103}"), ('\u{2f984}', "\u{440b}"), ('\u{2f985}', "\u{813e}"), ('\u{2f986}', "\u{5ab5}"), ('\u{2f987}', "\u{267a7}"), ('\u{2f988}', "\u{267b5}"),
+  ('\u{2f989}', "\u{23393}"), ('\u{2f98a}', "\u{2339c}"), ('\u{2f98b}', "\u{8201}"), ('\u{2f98c}', "\u{8204}"), ('\u{2f98d}', "\u{8f9e}"), ('\u{2f98e}', "\u{446b}"),
+  ('\u{2f98f}', "\u{8291}"), ('\u{2f990}', "\u{828b}"), ('\u{2f991}', "\u{829d}"), ('\u{2f992}', "\u{52b3}"), ('\u{2f993}', "\u{82b1}"), ('\u{2f994}', "\u{82b3}"),
+  ('\u{2f995}', "\u{82bd}"), ('\u{2f996}', "\u{82e6}"), ('\u{2f997}', "\u{26b3c}"), ('\u{2f998}', "\u{82e5}"), ('\u{2f999}', "\u{831d}"), ('\u{2f99a}', "\u{8363}"),
+  ('\u{2f99b}', "\u{83ad}"), ('\u{2f99c}', "\u{8323}"), ('\u{2f99d}', "\u{83bd}"), ('\u{2f99e}', "\u{83e7}"), ('\u{2f99f}', "\u{8457}"), ('\u{2f9a0}', "\u{8353}"),
+  ('\u{2f9a1}', "\u{83ca}"), ('\u{2f9a2}', "\u{83cc}"), ('\u{2f9a3}', "\u{83dc}"), ('\u{2f9a4}', "\u{26c36}"), ('\u{2f9a5}', "\u{26d6b}"), ('\u{2f9a6}', "\u{26cd5}"),
+  ('\u{2f9a7}', "\u{452b}"), ('\u{2f9a8}', "\u{84f1}"), ('\u{2f9a9}', "\u{84f3}"), ('\u{2f9aa}', "\u{8516}"), ('\u{2f9ab}', "\u{273ca}"), ('\u{2f9ac}', "\u{8564}"),
+  ('\u{2f9ad}', "\u{26f2c}"), ('\u{2f9ae}', "\u{455d}"), ('\u{2f9af}', "\u{4561}"), ('\u{2f9b0}', "\u{26fb1}"), ('\u{2f9b1}', "\u{270d2}"), ('\u{2f9b2}', "\u{456b}"),
+  ('\u{2f9b3}', "\u{8650}"), ('\u{2f9b4}', "\u{865c}"), ('\u{2f9b5}', "\u{8667}"), ('\u{2f9b6}', "\u{8669}"), ('\u{2f9b7}', "\u{86a9}"), ('\u{2f9b8}', "\u{8688}"),
+  ('\u{2f9b9}', "\u{870e}"), ('\u{2f9ba}', "\u{86e2}"), ('\u{2f9bb}', "\u{8779}"), ('\u{2f9bc}', "\u{8728}"), ('\u{2f9bd}', "\u{876b}"), ('\u{2f9be}', "\u{8786}"),
+  ('\u{2f9bf}', "\u{45d7}"), ('\u{2f9c0}', "\u{87e1}"), ('\u{2f9c1}', "\u{8801}"), ('\u{2f9c2}', "\u{45f9}"), ('\u{2f9c3}', "\u{8860}"), ('\u{2f9c4}', "\u{8863}"),
+  ('\u{2f9c5}', "\u{27667}"), ('\u{2f9c6}', "\u{88d7}"), ('\u{2f9c7}', "\u{88de}"), ('\u{2f9c8}', "\u{4635}"), ('\u{2f9c9}', "\u{88fa}"), ('\u{2f9ca}', "\u{34bb}"),
+  ('\u{2f9cb}', "\u{278ae}"), ('\u{2f9cc}', "\u{27966}"), ('\u{2f9cd}', "\u{46be}"), ('\u{2f9ce}', "\u{46c7}"), ('\u{2f9cf}', "\u{8aa0}"), ('\u{2f9d0}', "\u{8aed}"),
+  ('\u{2f9d1}', "\u{8b8a}"), ('\u{2f9d2}', "\u{8c55}"), ('\u{2f9d3}', "\u{27ca8}"), ('\u{2f9d4}', "\u{8cab}"), ('\u{2f9d5}', "\u{8cc1}"), ('\u{2f9d6}', "\u{8d1b}"),
+  ('\u{2f9d7}', "\u{8d77}"), ('\u{2f9d8}', "\u{27f2f}"), ('\u{2f9d9}', "\u{20804}"), ('\u{2f9da}', "\u{8dcb}"), ('\u{2f9db}', "\u{8dbc}"), ('\u{2f9dc}', "\u{8df0}"),
+  ('\u{2f9dd}', "\u{208de}"), ('\u{2f9de}', "\u{8ed4}"), ('\u{2f9df}', "\u{8f38}"), ('\u{2f9e0}', "\u{285d2}"), ('\u{2f9e1}', "\u{285ed}"), ('\u{2f9e2}', "\u{9094}"),
+  ('\u{2f9e3}', "\u{90f1}"), ('\u{2f9e4}', "\u{9111}"), ('\u{2f9e5}', "\u{2872e}"), ('\u{2f9e6}', "\u{911b}"), ('\u{2f9e7}', "\u{9238}"), ('\u{2f9e8}', "\u{92d7}"),
+  ('\u{2f9e9}', "\u{92d8}"), ('\u{2f9ea}', "\u{927c}"), ('\u{2f9eb}', "\u{93f9}"), ('\u{2f9ec}', "\u{9415}"), ('\u{2f9ed}', "\u{28bfa}"), ('\u{2f9ee}', "\u{958b}"),
+  ('\u{2f9ef}', "\u{4995}"), ('\u{2f9f0}', "\u{95b7}"), ('\u{2f9f1}', "\u{28d77}"), ('\u{2f9f2}', "\u{49e6}"), ('\u{2f9f3}', "\u{96c3}"), ('\u{2f9f4}', "\u{5db2}"),
+  ('\u{2f9f5}', "\u{9723}"), ('\u{2f9f6}', "\u{29145}"), ('\u{2f9f7}', "\u{2921a}"), ('\u{2f9f8}', "\u{4a6e}"), ('\u{2f9f9}', "\u{4a76}"), ('\u{2f9fa}', "\u{97e0}"),
+  ('\u{2f9fb}', "\u{2940a}"), ('\u{2f9fc}', "\u{4ab2}"), ('\u{2f9fd}', "\u{29496}"), ('\u{2f9fe}', "\u{980b}"), ('\u{2f9ff}', "\u{980b}"), ('\u{2fa00}', "\u{9829}"),
+  ('\u{2fa01}', "\u{295b6}"), ('\u{2fa02}', "\u{98e2}"), ('\u{2fa03}', "\u{4b33}"), ('\u{2fa04}', "\u{9929}"), ('\u{2fa05}', "\u{99a7}"), ('\u{2fa06}', "\u{99c2}"),
+  ('\u{2fa07}', "\u{99fe}"), ('\u{2fa08}', "\u{4bce}"), ('\u{2fa09}', "\u{29b30}"), ('\u{2fa0a}', "\u{9b12}"), ('\u{2fa0b}', "\u{9c40}"), ('\u{2fa0c}', "\u{9cfd}"),
+  ('\u{2fa0d}', "\u{4cce}"), ('\u{2fa0e}', "\u{4ced}"), ('\u{2fa0f}', "\u{9d67}"), ('\u{2fa10}', "\u{2a0ce}"), ('\u{2fa11}', "\u{4cf8}"), ('\u{2fa12}', "\u{2a105}"),
+  ('\u{2fa13}', "\u{2a20e}"), ('\u{2fa14}', "\u{2a291}"), ('\u{2fa15}', "\u{9ebb}"), ('\u{2fa16}', "\u{4d56}"), ('\u{2fa17}', "\u{9ef9}"), ('\u{2fa18}', "\u{9efe}"),
+  ('\u{2fa19}', "\u{9f05}"), ('\u{2fa1a}', "\u{9f0f}"), ('\u{2fa1b}', "\u{9f16}"), ('\u{2fa1c}', "\u{9f3b}"), ('\u{2fa1d}', "\u{2a600}"),
+];
@@ -3,30 +3,27 @@ mod commands;
 mod files;
 mod tables;
 mod database;
+mod generators;
+mod validation;
+mod homographs;
+mod policies;
+mod scoring;
+mod valuation;
+mod filters;
+mod screening;
+mod similarity;
+mod trademarks;
+mod thesaurus;
+mod cli;
+mod output;
+mod rate_limiter;
 
-use commands::{extensions_command, search_command, wishlist_command, init_command};
-use clap::Parser;
+use commands::{extensions_command, search_command, wishlist_command, init_command, markov_command, blend_command, typo_command, pattern_command, jobs_command, hack_command, expand_command, combine_command, acronym_command, reserved_command, value_command, blocklist_command, trademark_command, owned_command};
+use std::time::Duration;
+use clap::CommandFactory;
+use cli::{Cli, Command, ImportCommand, JobsCommand, OwnedCommand};
 use database::{connection};
-
-#[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
-struct Args {
-    /// Search domain names
-    #[arg(short, long, default_value = "")]
-    search: String,
-
-    /// Show and manage domain extensions
-    #[arg(short, long, default_value_t = false)]
-    extensions: bool,
-
-    /// Show and manage favorite domains
-    #[arg(short, long, default_value_t = false)]
-    wishlist: bool,
-
-    /// Init app and database
-    #[arg(short, long, default_value_t = false)]
-    init: bool,
-}
+use generators::markov_generator::MarkovOptions;
 
 #[tokio::main]
 async fn main() {
@@ -35,15 +32,144 @@ async fn main() {
         return;
     }
 
-    let args = Args::parse();
+    let cli = Cli::parse_args();
+    let display = cli.display;
 
-    if args.search.len() > 0 {
-        search_command::search_domain_names(args.search).await;
-    } else if args.extensions {
-        extensions_command::handle_extensions();
-    } else if args.wishlist {
-        wishlist_command::handle_wishlist();
-    } else if args.init {
-        init_command::handle_init();
+    let command = match (cli.command, cli.legacy.into_command()) {
+        (Some(command), _) => command,
+        (None, Some((flag, command))) => {
+            eprintln!("Warning: {} is deprecated, use the {} command instead", flag, get_command_name(&command));
+            command
+        }
+        (None, None) => {
+            Cli::command().print_help().expect("An error occurred while printing help");
+            return;
+        }
+    };
+
+    let filter = match (display.filter.is_empty(), display.hide_blocked) {
+        (true, true) => String::from("!blocked"),
+        (false, true) => format!("({}) && !blocked", display.filter),
+        _ => display.filter.clone(),
+    };
+
+    let trademark_classes: Vec<u32> = display.trademark_classes
+        .split(',')
+        .filter_map(|class| class.trim().parse::<u32>().ok())
+        .collect();
+    trademarks::set_selected_classes(trademark_classes);
+
+    if let Some(delay) = display.request_delay {
+        rate_limiter::set_request_delay(Duration::from_millis(delay));
     }
+
+    if !filter.is_empty() {
+        match filters::parse_filter(&filter) {
+            Ok(filter) => filters::set_initial_filter(filter),
+            Err(error) => {
+                eprintln!("{}", error);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    run_command(command).await;
+    std::process::exit(output::get_exit_code());
+}
+
+/// Run a command parsed from the command line
+async fn run_command(command: Command) {
+    match command {
+        Command::Init => init_command::handle_init(),
+        Command::Search { names, variants, expand, depth, limit, output } => {
+            output::set_output_format(output.output);
+            if expand {
+                expand_command::handle_expand(&names.join(","), depth, limit).await;
+            } else if variants {
+                search_command::search_domain_variants(names.join(" ")).await;
+            } else {
+                let inputs: Vec<String> = names
+                    .iter()
+                    .flat_map(|name| name.split(','))
+                    .map(|input| input.trim().to_string())
+                    .filter(|input| !input.is_empty())
+                    .collect();
+                search_command::search_domain_inputs(inputs).await;
+            }
+        }
+        Command::Wishlist { output } => {
+            output::set_output_format(output.output);
+            wishlist_command::handle_wishlist();
+        }
+        Command::Extensions { output } => {
+            output::set_output_format(output.output);
+            extensions_command::handle_extensions();
+        }
+        Command::Markov { count, corpus, order, min_length, max_length, temperature } => {
+            let options = MarkovOptions { count, order, min_length, max_length, temperature };
+            markov_command::handle_markov(corpus, options).await;
+        }
+        Command::Blend { left, right, limit } => blend_command::handle_blend(&left, &right, limit.limit).await,
+        Command::Typos { name, limit } => typo_command::handle_typos(&name, limit.limit).await,
+        Command::Pattern { pattern, limit } => pattern_command::handle_pattern(&pattern, limit.limit).await,
+        Command::Expand { words, depth, limit } => expand_command::handle_expand(&words, depth, limit.limit).await,
+        Command::Combine { files, separator, sample, limit } => {
+            combine_command::handle_combine(&files, separator, limit.limit, sample).await;
+        }
+        Command::Acronyms { phrase, limit } => acronym_command::handle_acronyms(&phrase, limit.limit).await,
+        Command::Hacks { word, limit } => hack_command::handle_hacks(&word, limit.limit).await,
+        Command::Scan { pattern } => jobs_command::handle_scan(&pattern).await,
+        Command::Jobs { command } => match command {
+            None | Some(JobsCommand::List) => jobs_command::handle_jobs(),
+            Some(JobsCommand::Show { id }) => jobs_command::handle_job_results(id),
+            Some(JobsCommand::Resume { id }) => jobs_command::handle_resume(id).await,
+            Some(JobsCommand::Pause { id }) => jobs_command::handle_pause(id),
+        },
+        Command::Import { command } => match command {
+            ImportCommand::Thesaurus { file } => expand_command::handle_import_thesaurus(&file),
+            ImportCommand::Reserved { file, tld } => reserved_command::handle_import_reserved(&file, &tld),
+            ImportCommand::ValueRules { file } => value_command::handle_import_value_rules(&file),
+            ImportCommand::Blocklist { file, language, global } => blocklist_command::handle_import_blocklist(&file, &language, global),
+            ImportCommand::Trademarks { file, office } => trademark_command::handle_import_trademarks(&file, &office),
+        },
+        Command::ValueRules => value_command::handle_value_rules(),
+        Command::Owned { command } => match command {
+            None | Some(OwnedCommand::List) => owned_command::handle_owned(),
+            Some(OwnedCommand::Add { domains }) => owned_command::handle_add_owned(&domains),
+        },
+    }
+}
+
+/// Get the command line replacing a deprecated flag (e.g. "domain-hunter import thesaurus")
+fn get_command_name(command: &Command) -> String {
+    let name = match command {
+        Command::Init => "init",
+        Command::Search { expand: true, .. } => "search --expand",
+        Command::Search { .. } => "search",
+        Command::Wishlist { .. } => "wishlist",
+        Command::Extensions { .. } => "extensions",
+        Command::Markov { .. } => "markov",
+        Command::Blend { .. } => "blend",
+        Command::Typos { .. } => "typos",
+        Command::Pattern { .. } => "pattern",
+        Command::Expand { .. } => "expand",
+        Command::Combine { .. } => "combine",
+        Command::Acronyms { .. } => "acronyms",
+        Command::Hacks { .. } => "hacks",
+        Command::Scan { .. } => "scan",
+        Command::Jobs { command: None } | Command::Jobs { command: Some(JobsCommand::List) } => "jobs",
+        Command::Jobs { command: Some(JobsCommand::Show { .. }) } => "jobs show",
+        Command::Jobs { command: Some(JobsCommand::Resume { .. }) } => "jobs resume",
+        Command::Jobs { command: Some(JobsCommand::Pause { .. }) } => "jobs pause",
+        Command::Import { command: ImportCommand::Thesaurus { .. } } => "import thesaurus",
+        Command::Import { command: ImportCommand::Reserved { .. } } => "import reserved",
+        Command::Import { command: ImportCommand::ValueRules { .. } } => "import value-rules",
+        Command::Import { command: ImportCommand::Blocklist { .. } } => "import blocklist",
+        Command::Import { command: ImportCommand::Trademarks { .. } } => "import trademarks",
+        Command::ValueRules => "value-rules",
+        Command::Owned { command: None } | Command::Owned { command: Some(OwnedCommand::List) } => "owned",
+        Command::Owned { command: Some(OwnedCommand::Add { .. }) } => "owned add",
+    };
+
+    format!("'domain-hunter {}'", name)
 }
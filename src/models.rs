@@ -1,4 +1,11 @@
 use serde::{Serialize,Deserialize};
+use crate::homographs::check_homograph;
+use crate::screening::check_blocklist;
+use crate::trademarks::{find_trademark_conflicts, TrademarkConflict};
+use crate::scoring::score;
+use crate::similarity::{find_similar_domains, KnownDomain, SimilarDomain};
+use crate::validation::to_unicode;
+use crate::valuation::{evaluate, Valuation};
 
 /// Selectable
 pub trait Selectable {
@@ -17,7 +24,19 @@ pub struct Extension {
   pub(crate) tld: String,
   pub(crate) name: String,
   #[serde(default = "default_selected")]
-  pub(crate) selected: bool
+  pub(crate) selected: bool,
+  #[serde(default)]
+  pub(crate) policy: Option<RegistryPolicy>
+}
+
+impl Extension {
+  // Summary of the registry policy (e.g. "Min 3 chars | IDN: Latin | Local presence")
+  pub(crate) fn policy_summary(&self) -> String {
+    match &self.policy {
+      Some(policy) => policy.summary(),
+      None => String::from("No known policy"),
+    }
+  }
 }
 
 impl Selectable for Extension {
@@ -34,6 +53,113 @@ impl Selectable for Extension {
   }
 }
 
+fn default_allowed() -> bool {
+  true
+}
+
+/// RegistryPolicy
+/// Represents the registration rules of a Top Level Domain
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RegistryPolicy {
+  pub(crate) tld: String,
+  pub(crate) min_length: usize,
+  pub(crate) max_length: usize,
+  #[serde(default = "default_allowed")]
+  pub(crate) allow_numeric_only: bool,
+  // Comma separated scripts allowed in internationalized names, "*" for any script, empty if not supported
+  #[serde(default)]
+  pub(crate) idn_scripts: String,
+  #[serde(default)]
+  pub(crate) local_presence: bool
+}
+
+impl RegistryPolicy {
+  pub(crate) fn summary(&self) -> String {
+    let mut rules: Vec<String> = vec![format!("{}-{} chars", self.min_length, self.max_length)];
+
+    if !self.allow_numeric_only {
+      rules.push(String::from("No numeric-only"));
+    }
+
+    match self.idn_scripts.as_str() {
+      "" => rules.push(String::from("No IDN")),
+      "*" => rules.push(String::from("IDN")),
+      scripts => rules.push(format!("IDN: {}", scripts)),
+    }
+
+    if self.local_presence {
+      rules.push(String::from("Local presence"));
+    }
+
+    rules.join(" | ")
+  }
+}
+
+/// ReservedName
+/// Represents a name reserved by a registry or ICANN for an extension ("*" for all extensions)
+#[derive(Debug)]
+pub struct ReservedName {
+  pub(crate) tld: String,
+  pub(crate) name: String,
+  pub(crate) reason: String
+}
+
+/// BlockedWord
+/// Represents an offensive or negative word in a language, flagged in domain names
+/// Exact words are only flagged when they are the whole name (e.g. "mist" but not "chemist")
+#[derive(Debug, Clone)]
+pub struct BlockedWord {
+  pub(crate) word: String,
+  pub(crate) language: String,
+  pub(crate) exact: bool
+}
+
+/// Trademark
+/// Represents a registered mark imported from a trademark office export
+#[derive(Debug, Clone)]
+pub struct Trademark {
+  pub(crate) mark: String,
+  pub(crate) owner: String,
+  pub(crate) number: String,
+  // Comma separated Nice classes (e.g. "9,42")
+  pub(crate) classes: String,
+  pub(crate) office: String
+}
+
+impl Trademark {
+  // Description of the mark (e.g. "GOOGLE | Google LLC | No. 123 | Classes 9, 42 | USPTO")
+  pub(crate) fn summary(&self) -> String {
+    let mut parts: Vec<String> = vec![self.mark.clone()];
+
+    if !self.owner.is_empty() {
+      parts.push(self.owner.clone());
+    }
+    if !self.number.is_empty() {
+      parts.push(format!("No. {}", self.number));
+    }
+    if !self.classes.is_empty() {
+      parts.push(format!("Classes {}", self.classes.replace(',', ", ")));
+    }
+    if !self.office.is_empty() {
+      parts.push(self.office.clone());
+    }
+
+    parts.join(" | ")
+  }
+}
+
+/// ValueRule
+/// Represents a rule of the valuation rule set, adding points to the value of matching available domains
+/// Rules with the "tier" kind give the minimum points of a tier instead (e.g. "High" from 45 points)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ValueRule {
+  pub(crate) kind: String,
+  pub(crate) value: String,
+  pub(crate) points: i64,
+  #[serde(default)]
+  pub(crate) description: String
+}
+
 /// Domain
 /// Represents a Domain (example.com, example.net...)
 #[derive(Serialize, Deserialize, Debug)]
@@ -42,13 +168,38 @@ pub struct Domain {
   pub(crate) tld: String,
   pub(crate) status: String,
   #[serde(default = "default_selected")]
-  pub(crate) selected: bool
+  pub(crate) selected: bool,
+  // Word the domain was generated from (e.g. "cloud" for "sky.com"), empty for direct searches
+  #[serde(default)]
+  pub(crate) source: String,
+  // Why the domain cannot be registered, or registration requirements (e.g. "Local presence required")
+  #[serde(default)]
+  pub(crate) reason: String,
+  // Suggested because the searched name is taken for every extension, shown in a separate section
+  #[serde(default)]
+  pub(crate) alternative: bool
 }
 
 impl Domain {
+  pub const NOT_REGISTRABLE: &'static str = "Not registrable";
+  pub const RESERVED: &'static str = "Reserved";
+
   pub(crate) fn is_available(&self) -> String {
+    let status = self.status_label();
+
+    if self.reason.is_empty() {
+      status
+    } else {
+      format!("{} ({})", status, self.reason)
+    }
+  }
+
+  // Availability without the reason (e.g. "Available", "Not available", "Reserved", "Not registrable")
+  pub(crate) fn status_label(&self) -> String {
     if self.status == "True" {
       String::from("Available")
+    } else if self.status == Domain::NOT_REGISTRABLE || self.status == Domain::RESERVED {
+      self.status.clone()
     } else {
       String::from("Not available")
     }
@@ -58,6 +209,93 @@ impl Domain {
   pub(crate) fn domain_name(&self) -> String {
     format!("{}.{}", self.domain, self.tld)
   }
+
+  // Unicode form of internationalized domain names (e.g. "café.com" for "xn--caf-dma.com")
+  pub(crate) fn unicode_domain_name(&self) -> String {
+    to_unicode(&self.domain_name())
+  }
+
+  // Pronounceability and memorability score of the label, from 0 to 100
+  pub(crate) fn score(&self) -> u8 {
+    score(&to_unicode(&self.domain))
+  }
+
+  // Indicative value of available domains from the valuation rules, `None` for other domains
+  pub(crate) fn valuation(&self) -> Option<Valuation> {
+    if self.status == "True" {
+      Some(evaluate(&self.domain, &self.tld))
+    } else {
+      None
+    }
+  }
+
+  // Mixed scripts and confusable characters of internationalized domain names
+  pub(crate) fn homograph_warning(&self) -> Option<String> {
+    check_homograph(&to_unicode(&self.domain))
+  }
+
+  // Offensive or negative words found in the name, in any language
+  pub(crate) fn blocklist_warning(&self) -> Option<String> {
+    check_blocklist(&self.domain)
+  }
+
+  // Imported trademarks conflicting with the name
+  pub(crate) fn trademark_conflicts(&self) -> Vec<TrademarkConflict> {
+    find_trademark_conflicts(&self.domain)
+  }
+
+  // Most relevant trademark conflict (e.g. "Trademark: Coca-Cola (Exact)")
+  pub(crate) fn trademark_warning(&self) -> Option<String> {
+    let conflicts = self.trademark_conflicts();
+
+    conflicts.first().map(|conflict| match conflicts.len() {
+      1 => format!("Trademark: {} ({})", conflict.trademark.mark, conflict.kind),
+      count => format!("Trademark: {} ({}) and {} more", conflict.trademark.mark, conflict.kind, count - 1),
+    })
+  }
+
+  // Wishlisted or owned domains close to this one (e.g. "Typo of cloudnest.com")
+  pub(crate) fn similar_domains(&self, known_domains: &[KnownDomain]) -> Vec<SimilarDomain> {
+    find_similar_domains(&self.domain, known_domains)
+  }
+
+  // Most relevant similar domain (e.g. "Typo of cloudnest.com (Wishlist)")
+  pub(crate) fn similarity_warning(&self, known_domains: &[KnownDomain]) -> Option<String> {
+    let similar_domains = self.similar_domains(known_domains);
+
+    similar_domains.first().map(|similar| {
+      let warning = format!("{} {} ({})", similar.reason, similar.domain.name, similar.domain.origin);
+      match similar_domains.len() {
+        1 => warning,
+        count => format!("{} and {} more", warning, count - 1),
+      }
+    })
+  }
+
+  // Homograph, blocklist and trademark warnings (e.g. "Blocked word 'merde' (fr)")
+  pub(crate) fn warning(&self) -> Option<String> {
+    let warnings: Vec<String> = [self.homograph_warning(), self.blocklist_warning(), self.trademark_warning()]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    if warnings.is_empty() {
+      None
+    } else {
+      Some(warnings.join(" | "))
+    }
+  }
+
+  // Both forms of internationalized domain names (e.g. "café.com (xn--caf-dma.com)")
+  pub(crate) fn display_name(&self) -> String {
+    let unicode_domain_name = self.unicode_domain_name();
+
+    if unicode_domain_name == self.domain_name() {
+      unicode_domain_name
+    } else {
+      format!("{} ({})", unicode_domain_name, self.domain_name())
+    }
+  }
 }
 
 impl Selectable for Domain {
@@ -80,7 +318,40 @@ impl Clone for Domain {
       domain: String::from(&self.domain),
       tld: String::from(&self.tld),
       status: String::from(&self.status),
-      selected: self.selected.clone()
+      selected: self.selected,
+      source: String::from(&self.source),
+      reason: String::from(&self.reason),
+      alternative: self.alternative
     }
   }
 }
+
+/// Job
+/// Represents a resumable scan of every label matching a pattern
+#[derive(Debug)]
+pub struct Job {
+  pub(crate) id: i64,
+  pub(crate) pattern: String,
+  pub(crate) tlds: String,
+  pub(crate) cursor: i64,
+  pub(crate) total: i64,
+  pub(crate) found: i64,
+  pub(crate) status: String
+}
+
+impl Job {
+  pub const RUNNING: &'static str = "Running";
+  pub const PAUSED: &'static str = "Paused";
+  pub const INTERRUPTED: &'static str = "Interrupted";
+  pub const FINISHED: &'static str = "Finished";
+
+  // Scanned labels compared to the total (e.g. "120/676 (17.8%)")
+  pub(crate) fn progress(&self) -> String {
+    let percent = if self.total > 0 {
+      self.cursor as f64 * 100.0 / self.total as f64
+    } else {
+      100.0
+    };
+    format!("{}/{} ({:.1}%)", self.cursor, self.total, percent)
+  }
+}
@@ -0,0 +1,261 @@
+use std::sync::OnceLock;
+use clap::ValueEnum;
+use serde::Serialize;
+use crate::filters::get_initial_filter;
+use crate::models::{Domain, Extension};
+use crate::similarity::{get_known_domains, KnownDomain};
+
+/// Format given with `--output`, the results are shown in a table by default
+static OUTPUT_FORMAT: OnceLock<OutputFormat> = OnceLock::new();
+
+/// Exit code of a search printed without the table
+static EXIT_CODE: OnceLock<i32> = OnceLock::new();
+
+/// Every searched domain is available
+pub const EXIT_ALL_AVAILABLE: i32 = 0;
+/// Some searched domains are available
+pub const EXIT_SOME_AVAILABLE: i32 = 3;
+/// No searched domain is available
+pub const EXIT_NONE_AVAILABLE: i32 = 4;
+/// The search provider could not be reached or returned an invalid response
+pub const EXIT_SEARCH_FAILED: i32 = 5;
+
+/// OutputFormat
+/// How results are shown: interactive table or printed for scripts
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+  /// Interactive table
+  Table,
+  /// JSON array of results
+  Json,
+  /// One JSON object per line
+  Ndjson,
+  /// CSV with a header row
+  Csv,
+  /// One tab separated name and status per line
+  Plain,
+}
+
+/// Result printed for scripts
+trait Record: Serialize {
+  const COLUMNS: &'static [&'static str];
+
+  fn values(&self) -> Vec<String>;
+
+  fn plain(&self) -> String;
+}
+
+/// DomainRecord
+/// Domain with the values shown in the domains table
+#[derive(Serialize, Debug)]
+struct DomainRecord {
+  domain: String,
+  ascii_domain: String,
+  tld: String,
+  status: String,
+  available: bool,
+  reason: String,
+  wishlisted: bool,
+  score: u8,
+  tier: Option<String>,
+  points: Option<i64>,
+  source: String,
+  alternative: bool,
+  warning: Option<String>,
+}
+
+impl Record for DomainRecord {
+  const COLUMNS: &'static [&'static str] = &[
+    "domain", "ascii_domain", "tld", "status", "available", "reason", "wishlisted", "score", "tier", "points", "source",
+    "alternative", "warning",
+  ];
+
+  fn values(&self) -> Vec<String> {
+    vec![
+      self.domain.clone(),
+      self.ascii_domain.clone(),
+      self.tld.clone(),
+      self.status.clone(),
+      self.available.to_string(),
+      self.reason.clone(),
+      self.wishlisted.to_string(),
+      self.score.to_string(),
+      self.tier.clone().unwrap_or_default(),
+      self.points.map(|points| points.to_string()).unwrap_or_default(),
+      self.source.clone(),
+      self.alternative.to_string(),
+      self.warning.clone().unwrap_or_default(),
+    ]
+  }
+
+  fn plain(&self) -> String {
+    format!("{}\t{}", self.ascii_domain, self.status)
+  }
+}
+
+/// ExtensionRecord
+/// Extension with the values shown in the extensions table
+#[derive(Serialize, Debug)]
+struct ExtensionRecord {
+  tld: String,
+  name: String,
+  selected: bool,
+  policy: String,
+}
+
+impl Record for ExtensionRecord {
+  const COLUMNS: &'static [&'static str] = &["tld", "name", "selected", "policy"];
+
+  fn values(&self) -> Vec<String> {
+    vec![self.tld.clone(), self.name.clone(), self.selected.to_string(), self.policy.clone()]
+  }
+
+  fn plain(&self) -> String {
+    let status = if self.selected { "Selected" } else { "Not selected" };
+    format!("{}\t{}", self.tld, status)
+  }
+}
+
+/// Set the format given with `--output`
+pub fn set_output_format(format: OutputFormat) {
+  let _ = OUTPUT_FORMAT.set(format);
+}
+
+/// Get the format given with `--output`, `Table` by default
+pub fn get_output_format() -> OutputFormat {
+  OUTPUT_FORMAT.get().copied().unwrap_or(OutputFormat::Table)
+}
+
+/// Keep the exit code telling whether all, some or none of the searched domains are available
+/// Every searched domain is counted, even when hidden by `--filter`, except alternatives to a taken name
+/// Searches shown in the table always exit with 0
+pub fn set_exit_code(domains: &[Domain]) {
+  if get_output_format() == OutputFormat::Table {
+    return;
+  }
+
+  let searched: Vec<&Domain> = domains.iter().filter(|domain| !domain.alternative).collect();
+  let available = searched.iter().filter(|domain| domain.status == "True").count();
+  let code = if available == 0 {
+    EXIT_NONE_AVAILABLE
+  } else if available == searched.len() {
+    EXIT_ALL_AVAILABLE
+  } else {
+    EXIT_SOME_AVAILABLE
+  };
+
+  let _ = EXIT_CODE.set(code);
+}
+
+/// Get the exit code of the search, 0 when no search was printed
+pub fn get_exit_code() -> i32 {
+  EXIT_CODE.get().copied().unwrap_or(EXIT_ALL_AVAILABLE)
+}
+
+/// Domains matching the filter given with `--filter`, along with the owned and wishlisted domains they are compared to
+fn get_printed_domains(domains: &[Domain]) -> (Vec<&Domain>, Vec<KnownDomain>) {
+  let filter = get_initial_filter();
+  let known_domains = get_known_domains(&domains.iter().collect::<Vec<&Domain>>());
+  let domains: Vec<&Domain> = domains
+      .iter()
+      .filter(|domain| filter.as_ref().is_none_or(|filter| filter.matches(domain, &known_domains)))
+      .collect();
+
+  (domains, known_domains)
+}
+
+/// Print the domains matching the filter given with `--filter`
+pub fn print_domains(domains: &[Domain], format: OutputFormat) {
+  let (domains, known_domains) = get_printed_domains(domains);
+
+  let records: Vec<DomainRecord> = domains
+      .iter()
+      .map(|domain| {
+        let valuation = domain.valuation();
+        let warnings: Vec<String> = [domain.warning(), domain.similarity_warning(&known_domains)]
+            .into_iter()
+            .flatten()
+            .collect();
+
+        DomainRecord {
+          domain: domain.unicode_domain_name(),
+          ascii_domain: domain.domain_name(),
+          tld: domain.tld.clone(),
+          status: domain.status_label(),
+          available: domain.status == "True",
+          reason: domain.reason.clone(),
+          wishlisted: domain.selected,
+          score: domain.score(),
+          tier: valuation.as_ref().map(|valuation| valuation.tier.clone()),
+          points: valuation.as_ref().map(|valuation| valuation.points),
+          source: domain.source.clone(),
+          alternative: domain.alternative,
+          warning: Some(warnings.join(" | ")).filter(|warning| !warning.is_empty()),
+        }
+      })
+      .collect();
+
+  // Plain output has no column telling alternatives apart, they are printed after an empty line
+  if format == OutputFormat::Plain {
+    let (results, alternatives): (Vec<DomainRecord>, Vec<DomainRecord>) =
+        records.into_iter().partition(|record| !record.alternative);
+    print_records(&results, format);
+    if !alternatives.is_empty() {
+      println!();
+      print_records(&alternatives, format);
+    }
+    return;
+  }
+
+  print_records(&records, format);
+}
+
+/// Print extensions along with their registry policy
+pub fn print_extensions(extensions: &[Extension], format: OutputFormat) {
+  let records: Vec<ExtensionRecord> = extensions
+      .iter()
+      .map(|extension| ExtensionRecord {
+        tld: extension.tld.clone(),
+        name: extension.name.clone(),
+        selected: extension.selected,
+        policy: extension.policy_summary(),
+      })
+      .collect();
+
+  print_records(&records, format);
+}
+
+/// Print records on the standard output
+fn print_records<T: Record>(records: &[T], format: OutputFormat) {
+  match format {
+    OutputFormat::Json => {
+      println!("{}", serde_json::to_string_pretty(records).expect("An error occurred while printing results"));
+    }
+    OutputFormat::Ndjson => {
+      for record in records {
+        println!("{}", serde_json::to_string(record).expect("An error occurred while printing results"));
+      }
+    }
+    OutputFormat::Csv => {
+      println!("{}", T::COLUMNS.join(","));
+      for record in records {
+        let values: Vec<String> = record.values().iter().map(|value| escape_csv(value)).collect();
+        println!("{}", values.join(","));
+      }
+    }
+    OutputFormat::Plain | OutputFormat::Table => {
+      for record in records {
+        println!("{}", record.plain());
+      }
+    }
+  }
+}
+
+/// Quote CSV values containing separators, quotes or line breaks (e.g. `a, b` => `"a, b"`)
+fn escape_csv(value: &str) -> String {
+  if value.contains([',', '"', '\n', '\r']) {
+    format!("\"{}\"", value.replace('"', "\"\""))
+  } else {
+    value.to_string()
+  }
+}
@@ -0,0 +1,48 @@
+use crate::homographs::find_scripts;
+use crate::models::RegistryPolicy;
+use crate::validation::to_unicode;
+
+/// Check a label against the registry policy of an extension before searching it
+/// Returns the reason why the label cannot be registered
+pub fn check_policy(label: &str, policy: &RegistryPolicy) -> Result<(), String> {
+  let is_idn = label.starts_with("xn--");
+  let unicode_label = to_unicode(label);
+  let length = if is_idn { unicode_label.chars().count() } else { label.len() };
+
+  if length < policy.min_length {
+    return Err(format!("At least {} characters", policy.min_length));
+  }
+
+  if length > policy.max_length {
+    return Err(format!("At most {} characters", policy.max_length));
+  }
+
+  if !policy.allow_numeric_only && label.chars().all(|c| c.is_ascii_digit() || c == '-') {
+    return Err(String::from("Numeric-only names not allowed"));
+  }
+
+  if is_idn {
+    match policy.idn_scripts.as_str() {
+      "" => return Err(String::from("Internationalized names not allowed")),
+      "*" => {}
+      idn_scripts => {
+        let allowed: Vec<&str> = idn_scripts.split(',').map(|script| script.trim()).collect();
+
+        if let Some(script) = find_scripts(&unicode_label).into_iter().find(|script| !allowed.contains(script)) {
+          return Err(format!("{} script not allowed", script));
+        }
+      }
+    }
+  }
+
+  Ok(())
+}
+
+/// Registration requirements which do not prevent searching (e.g. local presence)
+pub fn get_policy_requirements(policy: &RegistryPolicy) -> String {
+  if policy.local_presence {
+    String::from("Local presence required")
+  } else {
+    String::new()
+  }
+}
@@ -0,0 +1,31 @@
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// Minimum delay between two requests to the provider, given with `--request-delay`
+static REQUEST_DELAY: OnceLock<Duration> = OnceLock::new();
+
+/// Time of the last request sent to the provider
+static LAST_REQUEST: Mutex<Option<Instant>> = Mutex::const_new(None);
+
+/// Set the minimum delay between two requests, ignored when a delay was already set
+pub fn set_request_delay(delay: Duration) {
+  let _ = REQUEST_DELAY.set(delay);
+}
+
+/// Get the minimum delay between two requests, no delay by default
+pub fn get_request_delay() -> Duration {
+  REQUEST_DELAY.get().copied().unwrap_or(Duration::ZERO)
+}
+
+/// Wait until the delay since the previous request has passed, before sending a request to the provider
+pub async fn wait_for_turn() {
+  let mut last_request = LAST_REQUEST.lock().await;
+
+  if let Some(last_request) = *last_request {
+    tokio::time::sleep_until(last_request + get_request_delay()).await;
+  }
+
+  *last_request = Some(Instant::now());
+}
@@ -0,0 +1,153 @@
+use std::collections::HashSet;
+use lazy_static::lazy_static;
+use crate::thesaurus::DEFAULT_THESAURUS;
+use crate::generators::is_vowel;
+
+/// Most frequent letter pairs in English words
+const COMMON_BIGRAMS: [&str; 60] = [
+  "th", "he", "in", "er", "an", "re", "on", "at", "en", "nd", "ti", "es", "or", "te", "of",
+  "ed", "is", "it", "al", "ar", "st", "to", "nt", "ng", "se", "ha", "as", "ou", "io", "le",
+  "ve", "co", "me", "de", "hi", "ri", "ro", "ic", "ne", "ea", "ra", "ce", "li", "ch", "ll",
+  "be", "ma", "si", "om", "ur", "ca", "el", "ta", "la", "ns", "ge", "ly", "ei", "os", "no",
+];
+
+/// Letters typed with the left hand on a QWERTY keyboard
+const LEFT_HAND_KEYS: &str = "qwertasdfgzxcvb12345";
+
+/// Names up to this length get the best length score
+const IDEAL_LENGTH: usize = 6;
+/// Names from this length get the worst length score
+const MAX_SCORED_LENGTH: usize = 20;
+
+/// Minimum length of a dictionary word found in a name
+const MIN_WORD_LENGTH: usize = 3;
+
+lazy_static! {
+  /// Words of the bundled thesaurus, used to find dictionary words in names
+  static ref DICTIONARY: HashSet<String> = DEFAULT_THESAURUS
+      .split([':', ',', '\n'])
+      .map(|word| word.trim().to_string())
+      .filter(|word| word.len() >= MIN_WORD_LENGTH && word.chars().all(|c| c.is_ascii_lowercase()))
+      .collect();
+}
+
+/// Rate a name from 0 (bad) to 100 (good) on pronounceability, length, dictionary words and typing ease
+pub fn score(name: &str) -> u8 {
+  let name = name.to_lowercase();
+  let score = 0.35 * (0.7 * pronounceability(&name) + 0.3 * bigram_likelihood(&name))
+      + 0.25 * length_score(&name)
+      + 0.2 * dictionary_coverage(&name)
+      + 0.2 * typing_ease(&name);
+
+  (score * 100.0).round().clamp(0.0, 100.0) as u8
+}
+
+/// Rate how easy a name is to pronounce, from 0 (hard) to 1 (easy)
+/// Names alternating vowels and consonants score higher than names with long runs of either
+pub fn pronounceability(name: &str) -> f64 {
+  let letters: Vec<bool> = name
+      .chars()
+      .filter(|c| c.is_ascii_alphabetic())
+      .map(is_vowel)
+      .collect();
+
+  if letters.is_empty() {
+    return 0.0;
+  }
+
+  let vowel_ratio = letters.iter().filter(|v| **v).count() as f64 / letters.len() as f64;
+  let alternations = letters.windows(2).filter(|pair| pair[0] != pair[1]).count();
+  let alternation_ratio = if letters.len() > 1 {
+    alternations as f64 / (letters.len() - 1) as f64
+  } else {
+    1.0
+  };
+
+  let mut penalty = 0.0;
+  for run in letters.chunk_by(|a, b| a == b) {
+    let limit = if run[0] { 2 } else { 3 };
+    if run.len() > limit {
+      penalty += 0.2 * (run.len() - limit) as f64;
+    }
+  }
+
+  let balance = 1.0 - ((vowel_ratio - 0.45).abs() * 2.0).min(1.0);
+  let score = 0.5 * alternation_ratio + 0.3 * balance + 0.2 - penalty;
+  score.clamp(0.0, 1.0)
+}
+
+/// Share of letter pairs which are frequent in English, from 0 to 1
+pub fn bigram_likelihood(name: &str) -> f64 {
+  let chars: Vec<char> = name.chars().collect();
+
+  if chars.len() < 2 {
+    return 0.0;
+  }
+
+  let common = chars
+      .windows(2)
+      .filter(|pair| COMMON_BIGRAMS.contains(&pair.iter().collect::<String>().as_str()))
+      .count();
+
+  // A third of common pairs is already typical of real words
+  (common as f64 * 3.0 / (chars.len() - 1) as f64).min(1.0)
+}
+
+/// Short names score higher, from 0 to 1
+pub fn length_score(name: &str) -> f64 {
+  let length = name.chars().count();
+
+  if length <= IDEAL_LENGTH {
+    1.0
+  } else if length >= MAX_SCORED_LENGTH {
+    0.0
+  } else {
+    1.0 - (length - IDEAL_LENGTH) as f64 / (MAX_SCORED_LENGTH - IDEAL_LENGTH) as f64
+  }
+}
+
+/// Share of the name made of dictionary words, from 0 to 1 (e.g. "cloudnest" => 1)
+pub fn dictionary_coverage(name: &str) -> f64 {
+  let chars: Vec<char> = name.chars().collect();
+
+  if chars.is_empty() {
+    return 0.0;
+  }
+
+  // best[i] is the highest number of letters covered by words in the first i letters
+  let mut best: Vec<usize> = vec![0; chars.len() + 1];
+
+  for end in 1..=chars.len() {
+    best[end] = best[end - 1];
+
+    for start in 0..end.saturating_sub(MIN_WORD_LENGTH - 1) {
+      let word: String = chars[start..end].iter().collect();
+      if DICTIONARY.contains(&word) {
+        best[end] = best[end].max(best[start] + end - start);
+      }
+    }
+  }
+
+  best[chars.len()] as f64 / chars.len() as f64
+}
+
+/// Rate how easy a name is to type, from 0 to 1
+/// Alternating hands is easier than typing several letters with the same hand, hyphens and digits are harder
+pub fn typing_ease(name: &str) -> f64 {
+  let chars: Vec<char> = name.chars().filter(|c| c.is_ascii_alphanumeric()).collect();
+
+  if chars.len() < 2 {
+    return 1.0;
+  }
+
+  let hand_changes = chars
+      .windows(2)
+      .filter(|pair| LEFT_HAND_KEYS.contains(pair[0]) != LEFT_HAND_KEYS.contains(pair[1]))
+      .count();
+  let alternation_ratio = hand_changes as f64 / (chars.len() - 1) as f64;
+
+  let special_characters = name.chars().filter(|c| *c == '-' || c.is_ascii_digit()).count();
+  let penalty = 0.15 * special_characters as f64;
+
+  (0.4 + 0.6 * alternation_ratio - penalty).clamp(0.0, 1.0)
+}
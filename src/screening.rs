@@ -0,0 +1,88 @@
+use lazy_static::lazy_static;
+use crate::database::blocklist_api::find_all_blocked_words;
+use crate::database::migrations::get_default_blocked_words;
+use crate::files::get_workspace;
+use crate::models::BlockedWord;
+use crate::validation::to_unicode;
+
+/// Digits and symbols used in place of letters (e.g. "5h1t" => "shit"), "1" can stand for "i" or "l"
+const LEETSPEAK: [(char, &str); 9] = [
+  ('0', "o"), ('1', "il"), ('3', "e"), ('4', "a"), ('5', "s"), ('7', "t"), ('8', "b"), ('9', "g"), ('$', "s"),
+];
+
+/// Maximum number of readings of a name, ambiguous characters beyond this limit are read one way only
+const MAX_READINGS: usize = 16;
+
+lazy_static! {
+  /// Blocked words of the current workspace, loaded once from the `blocked_word` table, the default blocklist when the
+  /// table is empty or missing
+  static ref BLOCKED_WORDS: Vec<BlockedWord> = match find_all_blocked_words(&get_workspace()) {
+    blocked_words if blocked_words.is_empty() => get_default_blocked_words(),
+    blocked_words => blocked_words,
+  };
+}
+
+/// Check a name against the stored blocklist (e.g. "sh1tbox" => "Blocked word 'shit' (en)")
+pub fn check_blocklist(name: &str) -> Option<String> {
+  check_blocklist_with_words(name, &BLOCKED_WORDS)
+}
+
+/// Find the blocked words contained in a name, after removing hyphens, decoding leetspeak and repeated letters
+pub fn check_blocklist_with_words(name: &str, blocked_words: &[BlockedWord]) -> Option<String> {
+  let readings = get_readings(name);
+
+  let matches: Vec<String> = blocked_words
+      .iter()
+      .filter(|blocked| {
+        let squeezed_word = squeeze(&blocked.word);
+        readings.iter().any(|reading| {
+          if blocked.exact {
+            *reading == blocked.word || squeeze(reading) == squeezed_word
+          } else {
+            reading.contains(&blocked.word) || squeeze(reading).contains(&squeezed_word)
+          }
+        })
+      })
+      .map(|blocked| format!("'{}' ({})", blocked.word, blocked.language))
+      .collect();
+
+  if matches.is_empty() {
+    None
+  } else {
+    Some(format!("Blocked word {}", matches.join(", ")))
+  }
+}
+
+/// Possible readings of a name, without hyphens and with leetspeak decoded (e.g. "b1tch" => "bitch", "bltch")
+fn get_readings(name: &str) -> Vec<String> {
+  let name: String = to_unicode(name).to_lowercase().chars().filter(|c| *c != '-').collect();
+  let mut readings: Vec<String> = vec![String::new()];
+
+  for c in name.chars() {
+    let mut letters = match LEETSPEAK.iter().find(|(symbol, _)| *symbol == c) {
+      Some((_, letters)) => letters.to_string(),
+      None => c.to_string(),
+    };
+    if readings.len() >= MAX_READINGS {
+      letters.truncate(1);
+    }
+
+    readings = readings
+        .iter()
+        .flat_map(|reading| letters.chars().map(move |letter| format!("{}{}", reading, letter)))
+        .collect();
+    readings.dedup();
+  }
+
+  if !readings.contains(&name) {
+    readings.push(name);
+  }
+  readings
+}
+
+/// Collapse repeated letters (e.g. "fuuuck" => "fuck")
+fn squeeze(word: &str) -> String {
+  let mut chars: Vec<char> = word.chars().collect();
+  chars.dedup();
+  chars.into_iter().collect()
+}
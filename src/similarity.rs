@@ -0,0 +1,343 @@
+use lazy_static::lazy_static;
+use crate::database::owned_api::find_owned_domains;
+use crate::database::wishlist_api::find_wishlist;
+use crate::homographs::get_skeleton;
+use crate::models::Domain;
+use crate::validation::to_unicode;
+
+/// Names shorter than this are only compared by skeleton and sound
+const MIN_SIMILAR_LENGTH: usize = 4;
+/// Soundex codes of shorter names match too many names (e.g. "soup" and "shop")
+const MIN_SOUNDEX_LENGTH: usize = 5;
+/// Metaphone codes shorter than this match too many names
+const MIN_SIMILAR_CODE_LENGTH: usize = 3;
+
+lazy_static! {
+  /// Wishlisted and owned domains, loaded once from the database
+  static ref STORED_DOMAINS: Vec<KnownDomain> = get_stored_domains();
+}
+
+/// KnownDomain
+/// Wishlisted or owned domain, with the codes used to compare it to other names
+#[derive(Debug, Clone)]
+pub struct KnownDomain {
+  pub(crate) name: String,
+  pub(crate) origin: &'static str,
+  label: String,
+  skeleton: String,
+  soundex: String,
+  metaphone: String,
+}
+
+impl KnownDomain {
+  pub const WISHLIST: &'static str = "Wishlist";
+  pub const OWNED: &'static str = "Owned";
+
+  pub fn new(label: &str, tld: &str, origin: &'static str) -> Self {
+    let name = to_unicode(&format!("{}.{}", label, tld));
+    let label = to_unicode(label).replace('-', "");
+
+    KnownDomain {
+      name,
+      origin,
+      skeleton: get_skeleton(&label),
+      soundex: soundex(&label),
+      metaphone: metaphone(&label),
+      label,
+    }
+  }
+}
+
+/// Known domain close to a name, and why (e.g. "Sounds like")
+#[derive(Debug, Clone)]
+pub struct SimilarDomain {
+  pub(crate) domain: KnownDomain,
+  pub(crate) reason: &'static str,
+}
+
+/// Wishlisted and owned domains stored in the database
+pub fn get_stored_domains() -> Vec<KnownDomain> {
+  let mut domains: Vec<KnownDomain> = find_owned_domains()
+      .iter()
+      .map(|(label, tld)| KnownDomain::new(label, tld, KnownDomain::OWNED))
+      .collect();
+
+  domains.extend(
+    find_wishlist()
+        .iter()
+        .map(|domain| KnownDomain::new(&domain.domain, &domain.tld, KnownDomain::WISHLIST)),
+  );
+
+  domains
+}
+
+/// Wishlisted and owned domains, as stored when the app started
+pub fn stored_domains() -> &'static [KnownDomain] {
+  &STORED_DOMAINS
+}
+
+/// Owned domains and wishlisted domains, where the wishlist status of the given domains replaces the stored one
+pub fn get_known_domains(domains: &[&Domain]) -> Vec<KnownDomain> {
+  let names: Vec<String> = domains.iter().map(|domain| domain.unicode_domain_name()).collect();
+
+  let mut known_domains: Vec<KnownDomain> = stored_domains()
+      .iter()
+      .filter(|known| known.origin == KnownDomain::OWNED || !names.contains(&known.name))
+      .cloned()
+      .collect();
+
+  known_domains.extend(
+    domains
+        .iter()
+        .filter(|domain| domain.selected)
+        .map(|domain| KnownDomain::new(&domain.domain, &domain.tld, KnownDomain::WISHLIST)),
+  );
+
+  known_domains
+}
+
+/// Find the known domains close to a name, ignoring the ones with the same name (other extensions)
+/// - looks like: same letters once confusable characters are replaced (e.g. "раураl" and "paypal")
+/// - typo of: one or two typos away, depending on the length of the name (e.g. "cloudnest" and "clouddnest")
+/// - sounds like: same Metaphone code, or same Soundex code for longer names (e.g. "fotoshop" and "photoshop")
+pub fn find_similar_domains(label: &str, known_domains: &[KnownDomain]) -> Vec<SimilarDomain> {
+  let candidate = KnownDomain::new(label, "", KnownDomain::WISHLIST);
+  let length = candidate.label.chars().count();
+  let max_distance = if length >= 8 { 2 } else { 1 };
+  let mut similar_domains: Vec<SimilarDomain> = vec![];
+
+  for known in known_domains.iter().filter(|known| known.label != candidate.label) {
+    let reason = if known.skeleton == candidate.skeleton {
+      "Looks like"
+    } else if length >= MIN_SIMILAR_LENGTH
+        && known.label.chars().count().abs_diff(length) <= max_distance
+        && edit_distance(&known.label, &candidate.label) <= max_distance {
+      "Typo of"
+    } else if candidate.metaphone.len() >= MIN_SIMILAR_CODE_LENGTH && known.metaphone == candidate.metaphone
+        || length >= MIN_SOUNDEX_LENGTH
+        && !candidate.soundex.is_empty()
+        && known.soundex == candidate.soundex
+        && known.label.chars().count().abs_diff(length) <= 1 {
+      "Sounds like"
+    } else {
+      continue;
+    };
+
+    if !similar_domains.iter().any(|similar| similar.domain.name == known.name) {
+      similar_domains.push(SimilarDomain { domain: known.clone(), reason });
+    }
+  }
+
+  similar_domains
+}
+
+/// Number of insertions, deletions and substitutions needed to change a name into another (Levenshtein distance)
+pub fn edit_distance(a: &str, b: &str) -> usize {
+  let a: Vec<char> = a.chars().collect();
+  let b: Vec<char> = b.chars().collect();
+  let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+  for (i, a_char) in a.iter().enumerate() {
+    let mut current: Vec<usize> = vec![i + 1];
+
+    for (j, b_char) in b.iter().enumerate() {
+      let substitution = previous[j] + usize::from(a_char != b_char);
+      current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+    }
+
+    previous = current;
+  }
+
+  previous[b.len()]
+}
+
+/// Soundex code of a name, the first letter followed by 3 digits for its consonants (e.g. "robert" => "R163")
+pub fn soundex(name: &str) -> String {
+  let letters: Vec<char> = name.chars().filter(|c| c.is_ascii_alphabetic()).map(|c| c.to_ascii_uppercase()).collect();
+
+  let Some(first) = letters.first() else {
+    return String::new();
+  };
+
+  let get_digit = |c: char| match c {
+    'B' | 'F' | 'P' | 'V' => Some('1'),
+    'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+    'D' | 'T' => Some('3'),
+    'L' => Some('4'),
+    'M' | 'N' => Some('5'),
+    'R' => Some('6'),
+    _ => None,
+  };
+
+  let mut code = first.to_string();
+  let mut previous = get_digit(*first);
+
+  for c in letters.iter().skip(1) {
+    let digit = get_digit(*c);
+
+    if let Some(digit) = digit {
+      if Some(digit) != previous {
+        code.push(digit);
+      }
+    }
+    // H and W do not separate consonants with the same digit, vowels do
+    if !matches!(c, 'H' | 'W') {
+      previous = digit;
+    }
+  }
+
+  format!("{:0<4}", code).chars().take(4).collect()
+}
+
+/// Metaphone code of a name, approximating how it sounds in English (e.g. "knight" => "NT", "phone" => "FN")
+pub fn metaphone(name: &str) -> String {
+  let mut letters: Vec<char> = name.chars().filter(|c| c.is_ascii_alphabetic()).map(|c| c.to_ascii_uppercase()).collect();
+  letters.dedup_by(|a, b| a == b && *a != 'C');
+
+  let word: String = letters.iter().collect();
+  for prefix in ["AE", "GN", "KN", "PN", "WR"] {
+    if word.starts_with(prefix) {
+      letters.remove(0);
+      break;
+    }
+  }
+  if letters.first() == Some(&'X') {
+    letters[0] = 'S';
+  }
+  if letters.starts_with(&['W', 'H']) {
+    letters.remove(1);
+  }
+
+  let is_vowel = |c: Option<&char>| matches!(c, Some('A' | 'E' | 'I' | 'O' | 'U'));
+  let is_front_vowel = |c: Option<&char>| matches!(c, Some('E' | 'I' | 'Y'));
+  let mut code = String::new();
+
+  for (i, c) in letters.iter().enumerate() {
+    let previous = if i > 0 { letters.get(i - 1) } else { None };
+    let next = letters.get(i + 1);
+    let after_next = letters.get(i + 2);
+
+    match c {
+      'A' | 'E' | 'I' | 'O' | 'U' => {
+        if i == 0 {
+          code.push(*c);
+        }
+      }
+      'B' => {
+        if !(previous == Some(&'M') && next.is_none()) {
+          code.push('B');
+        }
+      }
+      'C' => {
+        if next == Some(&'I') && after_next == Some(&'A') || next == Some(&'H') && previous != Some(&'S') {
+          code.push('X');
+        } else if is_front_vowel(next) {
+          if previous != Some(&'S') {
+            code.push('S');
+          }
+        } else {
+          code.push('K');
+        }
+      }
+      'D' => {
+        if next == Some(&'G') && is_front_vowel(after_next) {
+          code.push('J');
+        } else {
+          code.push('T');
+        }
+      }
+      'G' => {
+        if next == Some(&'H') && !is_vowel(after_next) && after_next.is_some() || next == Some(&'N') && after_next.is_none() {
+          // Silent (e.g. "night", "sign")
+        } else if is_front_vowel(next) {
+          code.push('J');
+        } else {
+          code.push('K');
+        }
+      }
+      'H' => {
+        if is_vowel(next) && !matches!(previous, Some('C' | 'S' | 'P' | 'T' | 'G')) {
+          code.push('H');
+        }
+      }
+      'K' => {
+        if previous != Some(&'C') {
+          code.push('K');
+        }
+      }
+      'P' => code.push(if next == Some(&'H') { 'F' } else { 'P' }),
+      'Q' => code.push('K'),
+      'S' => {
+        if next == Some(&'H') || next == Some(&'I') && matches!(after_next, Some('O' | 'A')) {
+          code.push('X');
+        } else {
+          code.push('S');
+        }
+      }
+      'T' => {
+        if next == Some(&'I') && matches!(after_next, Some('O' | 'A')) {
+          code.push('X');
+        } else if next == Some(&'H') {
+          code.push('0');
+        } else if !(next == Some(&'C') && after_next == Some(&'H')) {
+          code.push('T');
+        }
+      }
+      'V' => code.push('F'),
+      'W' | 'Y' => {
+        if is_vowel(next) {
+          code.push(*c);
+        }
+      }
+      'X' => code.push_str("KS"),
+      'Z' => code.push('S'),
+      _ => code.push(*c),
+    }
+  }
+
+  code
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn soundex_codes() {
+    assert_eq!(soundex("robert"), "R163");
+    assert_eq!(soundex("rupert"), "R163");
+    assert_eq!(soundex("ashcraft"), "A261");
+    assert_eq!(soundex("tymczak"), "T522");
+    assert_eq!(soundex("pfister"), "P236");
+    assert_eq!(soundex("sky"), "S000");
+    assert_eq!(soundex("123"), "");
+  }
+
+  #[test]
+  fn metaphone_codes() {
+    assert_eq!(metaphone("knight"), "NT");
+    assert_eq!(metaphone("phone"), "FN");
+    assert_eq!(metaphone("photoshop"), metaphone("fotoshop"));
+    assert_eq!(metaphone("thumb"), "0M");
+    assert_eq!(metaphone("xavier"), "SFR");
+    assert_eq!(metaphone("cider"), "STR");
+  }
+
+  #[test]
+  fn edit_distances() {
+    assert_eq!(edit_distance("cloudnest", "cloudnest"), 0);
+    assert_eq!(edit_distance("cloudnest", "clouddnest"), 1);
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+    assert_eq!(edit_distance("", "abc"), 3);
+    assert_eq!(edit_distance("café", "cafe"), 1);
+  }
+
+  #[test]
+  fn short_names_do_not_sound_alike_by_soundex() {
+    let known_domains = vec![KnownDomain::new("shop", "com", KnownDomain::OWNED)];
+
+    assert!(find_similar_domains("sap", &known_domains).is_empty());
+    assert!(find_similar_domains("soup", &known_domains).is_empty());
+    assert_eq!(find_similar_domains("shopp", &known_domains)[0].reason, "Typo of");
+  }
+}
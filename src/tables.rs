@@ -1,3 +1,4 @@
 pub mod base_table;
 pub mod domains_table; // For domain search and wishlist
-pub mod extensions_table;
\ No newline at end of file
+pub mod extensions_table;
+pub mod jobs_table;
\ No newline at end of file
@@ -47,7 +47,7 @@ pub fn get_header_style(colors: &TableColors) -> Style {
       .bg(colors.header_bg)
 }
 
-pub fn get_table_headers(labels: Vec<&str>, header_style: Style) -> Row {
+pub fn get_table_headers(labels: Vec<&str>, header_style: Style) -> Row<'_> {
   labels.into_iter()
       .map(Cell::from)
       .collect::<Row>()
@@ -84,7 +84,7 @@ pub trait TableBehavior {
   fn previous_row(&mut self);
   fn set_color(&mut self);
   fn render_scrollbar(&mut self, frame: &mut Frame, area: Rect);
-  fn render_footer(&self, frame: &mut Frame, area: Rect, info_texts: [&str; 1]);
+  fn render_footer(&self, frame: &mut Frame, area: Rect, info_texts: &[&str]);
 }
 
 /// BaseTable
@@ -99,7 +99,7 @@ pub struct BaseTable<T> {
 impl<T> BaseTable<T> {
   /// Create a new instance of the Table
   pub fn new(items: Vec<T>) -> Self {
-    let items_length = if items.len() > 0 {
+    let items_length = if !items.is_empty() {
       items.len() - 1
     } else {
       1
@@ -112,10 +112,29 @@ impl<T> BaseTable<T> {
       items,
     }
   }
+
+  /// Replace the items of the table and select the first one
+  pub fn set_items(&mut self, items: Vec<T>) {
+    self.items = items;
+    let items_length = self.items.len().saturating_sub(1).max(1);
+    self.scroll_state = ScrollbarState::new(items_length * ITEM_HEIGHT);
+    self.state.select(Some(0));
+  }
+
+  /// Append items to the table (e.g. results received while a search is running)
+  pub fn extend_items(&mut self, items: Vec<T>) {
+    self.items.extend(items);
+    let items_length = self.items.len().saturating_sub(1).max(1);
+    self.scroll_state = self.scroll_state.content_length(items_length * ITEM_HEIGHT);
+  }
 }
 
 impl<T> TableBehavior for BaseTable<T> {
   fn next_row(&mut self) {
+    if self.items.is_empty() {
+      return;
+    }
+
     let i = match self.state.selected() {
       Some(i) => {
         if i >= self.items.len() - 1 {
@@ -131,6 +150,10 @@ impl<T> TableBehavior for BaseTable<T> {
   }
 
   fn previous_row(&mut self) {
+    if self.items.is_empty() {
+      return;
+    }
+
     let i = match self.state.selected() {
       Some(i) => {
         if i == 0 {
@@ -163,8 +186,8 @@ impl<T> TableBehavior for BaseTable<T> {
     );
   }
 
-  fn render_footer(&self, frame: &mut Frame, area: Rect, info_texts: [&str; 1]) {
-    let info_footer = Paragraph::new(Text::from_iter(info_texts))
+  fn render_footer(&self, frame: &mut Frame, area: Rect, info_texts: &[&str]) {
+    let info_footer = Paragraph::new(Text::from_iter(info_texts.iter().copied()))
         .style(
           Style::new()
               .fg(self.colors.row_fg)
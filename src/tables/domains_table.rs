@@ -1,88 +1,478 @@
+use std::collections::HashMap;
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::time::Duration;
 use color_eyre::Result;
 use ratatui::{
-  crossterm::event::{self, Event, KeyCode, KeyEventKind},
-  layout::{Constraint, Layout, Rect},
-  style::{Stylize},
-  text::Text,
-  widgets::{HighlightSpacing, Table},
+  crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+  layout::{Constraint, Flex, Layout, Rect},
+  style::{Style, Stylize},
+  text::{Line, Text},
+  widgets::{Block, BorderType, Clear, HighlightSpacing, Padding, Paragraph, Table, Wrap},
   DefaultTerminal, Frame,
 };
 use crate::models::{Domain, Selectable};
 use crate::tables::base_table::{get_header_style, get_row_style, get_selected_row_style, get_table_headers, get_table_row, BaseTable, TableBehavior};
 use crate::database::wishlist_api::{add_to_wishlist, remove_from_wishlist};
+use crate::filters::{get_initial_filter, parse_filter, Filter};
+use crate::similarity::{get_known_domains, KnownDomain};
 
-const INFO_TEXTS: [&str; 1] = [
-  "(Esc) quit | (↑) move up | (↓) move down | (w) Add/Remove from wishlist",
+const INFO_TEXTS: [&str; 3] = [
+  "(Esc) quit | (↑) move up | (↓) move down | (w) Add/Remove from wishlist | (a) Add all available to wishlist | (s) Sort by score | (n) Sort by name | (/) Filter | (Enter) Details",
+  "(Enter) apply filter | (Esc) cancel | empty filter shows every domain",
+  "(Enter/Esc) close details | (↑) previous domain | (↓) next domain | (w) Add/Remove from wishlist",
 ];
 
 pub fn display_domains(data: Vec<Domain>) -> Result<()> {
   color_eyre::install()?;
   let terminal = ratatui::init();
-  let app_result = BaseTable::new(data).run(terminal);
+  let mut table = DomainsTable::new(data);
+  table.apply_filter(get_initial_filter());
+  let app_result = table.run(terminal);
   ratatui::restore();
   app_result
 }
 
-impl BaseTable<Domain> {
+/// Display domains as they are received, while the search is still running
+pub fn display_domain_stream(receiver: Receiver<Vec<Domain>>, total: usize) -> Result<()> {
+  color_eyre::install()?;
+  let terminal = ratatui::init();
+  let mut table = DomainsTable::new(vec![]);
+  table.apply_filter(get_initial_filter());
+  let app_result = table.run_stream(terminal, receiver, total);
+  ratatui::restore();
+  app_result
+}
+
+/// FilterState
+/// Filter applied to the domains table, along with the domains it hides
+#[derive(Default)]
+pub struct FilterState {
+  hidden_items: Vec<Domain>, // Domains hidden by the filter
+  filter: Option<Filter>, // Filter applied to the domains
+  prompt: Option<String>, // Filter expression being typed
+  message: String, // Error shown under the filter prompt
+}
+
+impl FilterState {
+  /// Expression of the applied filter, empty without filter
+  fn text(&self) -> String {
+    self.filter.as_ref().map(|filter| filter.text.clone()).unwrap_or_default()
+  }
+}
+
+/// DomainsTable
+/// Table of searched or wishlisted domains, which can be filtered and show the details of a domain
+pub struct DomainsTable {
+  table: BaseTable<Domain>,
+  filter_state: FilterState,
+  show_details: bool, // Details of the selected domain shown over the table
+  known_domains: Option<Vec<KnownDomain>>, // Owned and wishlisted domains, computed again when the wishlist changes
+  warnings: HashMap<String, Option<String>>, // Warning of each domain, computed again when the wishlist changes
+}
+
+impl DomainsTable {
+  /// Create a new instance of the table, without filter
+  pub fn new(items: Vec<Domain>) -> Self {
+    Self {
+      table: BaseTable::new(items),
+      filter_state: FilterState::default(),
+      show_details: false,
+      known_domains: None,
+      warnings: HashMap::new(),
+    }
+  }
+
   /// Add or remove a domain from the wishlist
   pub fn update_row_status(&mut self) {
-    if let Some(index) = self.state.selected() {
-      self.items[index].toggle_status();
+    if let Some(domain) = self.table.state.selected().and_then(|index| self.table.items.get_mut(index)) {
+      domain.toggle_status();
 
-      if self.items[index].selected {
-        add_to_wishlist(&self.items[index]);
+      if domain.selected {
+        add_to_wishlist(domain);
       } else {
-        remove_from_wishlist(&self.items[index]);
+        remove_from_wishlist(domain);
+      }
+      self.clear_warnings();
+    }
+  }
+
+  /// Add every available domain to the wishlist (e.g. to plan defensive registrations)
+  /// Possible homographs, names containing blocked words or trademarks, and names close to a wishlisted or owned
+  /// domain are skipped, they can still be added one by one
+  pub fn add_available_to_wishlist(&mut self) {
+    let mut known_domains = self.get_known_domains();
+
+    for domain in self.table.items.iter_mut() {
+      if domain.status == "True"
+          && !domain.selected
+          && domain.warning().is_none()
+          && domain.similar_domains(&known_domains).is_empty() {
+        domain.toggle_status();
+        add_to_wishlist(domain);
+        known_domains.push(KnownDomain::new(&domain.domain, &domain.tld, KnownDomain::WISHLIST));
       }
     }
+
+    self.clear_warnings();
+  }
+
+  /// Owned domains and wishlisted domains, including the changes made in this table
+  fn get_known_domains(&self) -> Vec<KnownDomain> {
+    let domains: Vec<&Domain> = self.table.items.iter().chain(self.filter_state.hidden_items.iter()).collect();
+    get_known_domains(&domains)
+  }
+
+  /// Owned domains and wishlisted domains, computed once until the wishlist changes
+  fn known_domains(&mut self) -> Vec<KnownDomain> {
+    if self.known_domains.is_none() {
+      self.known_domains = Some(self.get_known_domains());
+    }
+
+    self.known_domains.clone().unwrap_or_default()
+  }
+
+  /// Compute the warnings of the domains which do not have one yet, instead of every domain each time the table is drawn
+  fn update_warnings(&mut self) {
+    if self.table.items.iter().all(|domain| self.warnings.contains_key(&domain.domain_name())) {
+      return;
+    }
+
+    let known_domains = self.known_domains();
+    for domain in self.table.items.iter() {
+      self.warnings.entry(domain.domain_name()).or_insert_with(|| {
+        let warnings: Vec<String> = [domain.warning(), domain.similarity_warning(&known_domains)].into_iter().flatten().collect();
+        Some(warnings.join(" | ")).filter(|warning| !warning.is_empty())
+      });
+    }
+  }
+
+  /// Forget the known domains and warnings once the wishlist changed, similarity warnings depend on it
+  fn clear_warnings(&mut self) {
+    self.known_domains = None;
+    self.warnings.clear();
   }
 
   fn run(mut self, mut terminal: DefaultTerminal) -> Result<()> {
     loop {
-      terminal.draw(|frame| self.draw(frame))?;
+      terminal.draw(|frame| self.draw(frame, INFO_TEXTS[0]))?;
 
       if let Event::Key(key) = event::read()? {
-        if key.kind == KeyEventKind::Press {
-          match key.code {
-            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
-            KeyCode::Char('j') | KeyCode::Down => self.next_row(),
-            KeyCode::Char('k') | KeyCode::Up => self.previous_row(),
-            KeyCode::Char('w') => self.update_row_status(),
-            _ => {}
+        if self.handle_key(key) {
+          return Ok(());
+        }
+      }
+    }
+  }
+
+  /// Same as `run`, but new domains are appended to the table as they are received
+  fn run_stream(mut self, mut terminal: DefaultTerminal, receiver: Receiver<Vec<Domain>>, total: usize) -> Result<()> {
+    let mut searched = 0;
+    let mut is_searching = true;
+
+    loop {
+      while is_searching {
+        match receiver.try_recv() {
+          Ok(domains) => {
+            searched += 1;
+            self.extend_filtered_items(domains);
           }
+          Err(TryRecvError::Empty) => break,
+          Err(TryRecvError::Disconnected) => is_searching = false,
         }
       }
+
+      let info_text = if is_searching {
+        format!("Searching {}/{} | {}", searched, total, INFO_TEXTS[0])
+      } else {
+        INFO_TEXTS[0].to_string()
+      };
+      terminal.draw(|frame| self.draw(frame, &info_text))?;
+
+      if event::poll(Duration::from_millis(100))? {
+        if let Event::Key(key) = event::read()? {
+          if self.handle_key(key) {
+            return Ok(());
+          }
+        }
+      }
+    }
+  }
+
+  /// Show the domains matching a filter and hide the others, or show every domain without filter
+  pub fn apply_filter(&mut self, filter: Option<Filter>) {
+    let known_domains = self.known_domains();
+    let mut domains: Vec<Domain> = self.table.items.drain(..).collect();
+    domains.append(&mut self.filter_state.hidden_items);
+
+    match &filter {
+      Some(filter) => {
+        let (visible, hidden): (Vec<Domain>, Vec<Domain>) =
+            domains.into_iter().partition(|d| filter.matches(d, &known_domains));
+        self.filter_state.hidden_items = hidden;
+        self.table.set_items(visible);
+      }
+      None => self.table.set_items(domains),
+    }
+
+    self.filter_state.filter = filter;
+  }
+
+  /// Append domains received while a search is running, hiding the ones which do not match the filter
+  fn extend_filtered_items(&mut self, domains: Vec<Domain>) {
+    let known_domains = self.known_domains();
+
+    match &self.filter_state.filter {
+      Some(filter) => {
+        let (visible, hidden): (Vec<Domain>, Vec<Domain>) =
+            domains.into_iter().partition(|d| filter.matches(d, &known_domains));
+        self.filter_state.hidden_items.extend(hidden);
+        self.table.extend_items(visible);
+      }
+      None => self.table.extend_items(domains),
     }
   }
 
-  fn draw(&mut self, frame: &mut Frame) {
+  /// Handle a key press while the filter prompt is open
+  fn handle_prompt_key(&mut self, key: KeyEvent) {
+    let Some(prompt) = self.filter_state.prompt.as_mut() else {
+      return;
+    };
+
+    match key.code {
+      KeyCode::Char(c) => prompt.push(c),
+      KeyCode::Backspace => {
+        prompt.pop();
+      }
+      KeyCode::Esc => {
+        self.filter_state.prompt = None;
+        self.filter_state.message = String::new();
+      }
+      KeyCode::Enter => {
+        if prompt.trim().is_empty() {
+          self.filter_state.prompt = None;
+          self.filter_state.message = String::new();
+          self.apply_filter(None);
+          return;
+        }
+
+        match parse_filter(prompt) {
+          Ok(filter) => {
+            self.filter_state.prompt = None;
+            self.filter_state.message = String::new();
+            self.apply_filter(Some(filter));
+          }
+          Err(error) => self.filter_state.message = error,
+        }
+      }
+      _ => {}
+    }
+  }
+
+  /// Sort domains by score, best first, alternatives staying after the results
+  pub fn sort_by_score(&mut self) {
+    self.table.items.sort_by_key(|domain| (domain.alternative, std::cmp::Reverse(domain.score())));
+    if !self.table.items.is_empty() {
+      self.table.state.select(Some(0));
+    }
+  }
+
+  /// Sort domains by name, then extension, alternatives staying after the results
+  pub fn sort_by_name(&mut self) {
+    self.table.items.sort_by(|a, b| a.alternative.cmp(&b.alternative).then(a.domain.cmp(&b.domain)).then(a.tld.cmp(&b.tld)));
+    if !self.table.items.is_empty() {
+      self.table.state.select(Some(0));
+    }
+  }
+
+  /// Handle a key press, returns true when the table should be closed
+  fn handle_key(&mut self, key: KeyEvent) -> bool {
+    if key.kind == KeyEventKind::Press && self.filter_state.prompt.is_some() {
+      self.handle_prompt_key(key);
+    } else if key.kind == KeyEventKind::Press && self.show_details {
+      match key.code {
+        KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q') => self.show_details = false,
+        KeyCode::Char('j') | KeyCode::Down => self.table.next_row(),
+        KeyCode::Char('k') | KeyCode::Up => self.table.previous_row(),
+        KeyCode::Char('w') => self.update_row_status(),
+        _ => {}
+      }
+    } else if key.kind == KeyEventKind::Press {
+      match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => return true,
+        KeyCode::Char('j') | KeyCode::Down => self.table.next_row(),
+        KeyCode::Char('k') | KeyCode::Up => self.table.previous_row(),
+        KeyCode::Char('w') => self.update_row_status(),
+        KeyCode::Char('a') => self.add_available_to_wishlist(),
+        KeyCode::Char('s') => self.sort_by_score(),
+        KeyCode::Char('n') => self.sort_by_name(),
+        KeyCode::Char('/') => self.filter_state.prompt = Some(self.filter_state.text()),
+        KeyCode::Enter => self.show_details = !self.table.items.is_empty(),
+        _ => {}
+      }
+    }
+
+    false
+  }
+
+  fn draw(&mut self, frame: &mut Frame, info_text: &str) {
     let vertical = &Layout::vertical([Constraint::Min(5), Constraint::Length(4)]);
     let rects = vertical.split(frame.area());
 
-    self.set_color();
+    self.table.set_color();
     self.render_table(frame, rects[0]);
-    self.render_scrollbar(frame, rects[0]);
-    self.render_footer(frame, rects[1], INFO_TEXTS);
+    self.table.render_scrollbar(frame, rects[0]);
+    if self.show_details {
+      self.render_details(frame, rects[0]);
+      self.table.render_footer(frame, rects[1], &[INFO_TEXTS[2]]);
+      return;
+    }
+
+    if let Some(prompt) = &self.filter_state.prompt {
+      let prompt_text = format!("Filter: {}_ | {}", prompt, INFO_TEXTS[1]);
+      self.table.render_footer(frame, rects[1], &[&prompt_text, &self.filter_state.message]);
+      return;
+    }
+
+    let info_text = match &self.filter_state.filter {
+      Some(filter) => format!("Filter: {} ({} hidden) | {}", filter.text, self.filter_state.hidden_items.len(), info_text),
+      None => info_text.to_string(),
+    };
+
+    // Rules which gave its value to the selected domain
+    let valuation_text = self.table.state.selected()
+        .and_then(|index| self.table.items.get(index))
+        .and_then(|domain| domain.valuation())
+        .map(|valuation| format!("Value: {} points | {}", valuation.points, valuation.reasons.join(", ")))
+        .unwrap_or_default();
+
+    self.table.render_footer(frame, rects[1], &[&info_text, &valuation_text]);
+  }
+
+  /// Show everything known about the selected domain, including the matched trademarks
+  fn render_details(&self, frame: &mut Frame, area: Rect) {
+    let Some(domain) = self.table.state.selected().and_then(|index| self.table.items.get(index)) else {
+      return;
+    };
+
+    let mut lines: Vec<Line> = vec![
+      Line::from(domain.display_name().bold()),
+      Line::from(""),
+      Line::from(format!("Status: {}", domain.is_available())),
+      Line::from(format!("Wishlist: {}", domain.is_selected())),
+      Line::from(format!("Score: {}", domain.score())),
+    ];
+
+    if let Some(valuation) = domain.valuation() {
+      lines.push(Line::from(format!(
+        "Value: {} ({} points: {})", valuation.tier, valuation.points, valuation.reasons.join(", ")
+      )));
+    }
+    if !domain.source.is_empty() {
+      lines.push(Line::from(format!("Source: {}", domain.source)));
+    }
+    if let Some(warning) = domain.homograph_warning() {
+      lines.push(Line::from(format!("Homograph: {}", warning)));
+    }
+    if let Some(warning) = domain.blocklist_warning() {
+      lines.push(Line::from(warning));
+    }
+
+    let similar_domains = match &self.known_domains {
+      Some(known_domains) => domain.similar_domains(known_domains),
+      None => domain.similar_domains(&self.get_known_domains()),
+    };
+    if !similar_domains.is_empty() {
+      lines.push(Line::from(""));
+      lines.push(Line::from("Similar domains:"));
+      for similar in similar_domains {
+        lines.push(Line::from(format!("  {} {} ({})", similar.reason, similar.domain.name, similar.domain.origin)));
+      }
+    }
+
+    let conflicts = domain.trademark_conflicts();
+    lines.push(Line::from(""));
+    if conflicts.is_empty() {
+      lines.push(Line::from("Trademarks: no conflict with the imported marks"));
+    } else {
+      lines.push(Line::from("Trademarks:"));
+      for conflict in conflicts {
+        lines.push(Line::from(format!("  {} match: {}", conflict.kind, conflict.trademark.summary())));
+      }
+    }
+
+    let [area] = Layout::horizontal([Constraint::Percentage(80)]).flex(Flex::Center).areas(area);
+    let [area] = Layout::vertical([Constraint::Percentage(80)]).flex(Flex::Center).areas(area);
+
+    let details = Paragraph::new(lines)
+        .style(Style::new().fg(self.table.colors.row_fg).bg(self.table.colors.buffer_bg))
+        .wrap(Wrap { trim: false })
+        .block(
+          Block::bordered()
+              .title(" Details ")
+              .padding(Padding::horizontal(1))
+              .border_type(BorderType::Double)
+              .border_style(Style::new().fg(self.table.colors.footer_border_color)),
+        );
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(details, area);
   }
 
   fn render_table(&mut self, frame: &mut Frame, area: Rect) {
-    let header_labels = vec!["Domain", "Status", "Wishlist"];
-    let header_style = get_header_style(&self.colors);
+    // The source column is only shown when domains were generated from other words
+    let show_source = self.table.items.iter().any(|d| !d.source.is_empty());
+    self.update_warnings();
+    let warnings: Vec<Option<String>> = self.table.items
+        .iter()
+        .map(|d| self.warnings.get(&d.domain_name()).cloned().flatten())
+        .collect();
+    let show_warnings = warnings.iter().any(|warning| warning.is_some());
+
+    let mut header_labels = vec!["Domain", "Status", "Wishlist", "Score", "Value"];
+    if show_source {
+      header_labels.push("Source");
+    }
+    if show_warnings {
+      header_labels.push("Warning");
+    }
+    let header_style = get_header_style(&self.table.colors);
     let header = get_table_headers(header_labels, header_style);
 
-    let selected_row_style = get_selected_row_style(&self.colors);
+    let selected_row_style = get_selected_row_style(&self.table.colors);
+
+    let rows = self.table.items.iter().enumerate().map(|(i, data)| {
+      let value = data.valuation().map(|valuation| valuation.tier).unwrap_or_default();
+      let mut row_values = vec![data.display_name(), data.is_available(), data.is_selected(), data.score().to_string(), value];
+      if show_source {
+        row_values.push(data.source.clone());
+      }
+      if show_warnings {
+        row_values.push(warnings[i].clone().unwrap_or_default());
+      }
+      let row_style = get_row_style(i, &self.table.colors);
+      let row = get_table_row(row_values, row_style);
 
-    let rows = self.items.iter().enumerate().map(|(i, data)| {
-      let row_values = vec![data.domain_name(), data.is_available(), data.is_selected()];
-      let row_style = get_row_style(i, &self.colors);
-      get_table_row(row_values, row_style)
+      // Alternatives are shown in a separate section, after the results
+      if data.alternative && (i == 0 || !self.table.items[i - 1].alternative) {
+        row.top_margin(1)
+      } else {
+        row
+      }
     });
 
-    let widths = vec![
-      Constraint::Min(20),
+    let mut widths = vec![
+      Constraint::Min(30),
       Constraint::Min(20),
       Constraint::Min(20),
+      Constraint::Min(10),
+      Constraint::Min(10),
     ];
+    if show_source {
+      widths.push(Constraint::Min(20));
+    }
+    if show_warnings {
+      widths.push(Constraint::Min(30));
+    }
 
     let t = Table::new(rows, widths)
         .header(header)
@@ -92,9 +482,9 @@ impl BaseTable<Domain> {
           " > ".into(),
           "".into(),
         ]))
-        .bg(self.colors.buffer_bg)
+        .bg(self.table.colors.buffer_bg)
         .highlight_spacing(HighlightSpacing::Always);
 
-    frame.render_stateful_widget(t, area, &mut self.state);
+    frame.render_stateful_widget(t, area, &mut self.table.state);
   }
 }
@@ -57,18 +57,18 @@ impl BaseTable<Extension> {
     self.set_color();
     self.render_table(frame, rects[0]);
     self.render_scrollbar(frame, rects[0]);
-    self.render_footer(frame, rects[1], INFO_TEXTS);
+    self.render_footer(frame, rects[1], &INFO_TEXTS);
   }
 
   fn render_table(&mut self, frame: &mut Frame, area: Rect) {
-    let header_labels = vec!["TLD", "Name", "Selected"];
+    let header_labels = vec!["TLD", "Name", "Selected", "Policy"];
     let header_style = get_header_style(&self.colors);
     let header = get_table_headers(header_labels, header_style);
 
     let selected_row_style = get_selected_row_style(&self.colors);
 
     let rows = self.items.iter().enumerate().map(|(i, data)| {
-      let row_values = vec![data.tld.clone(), data.name.clone(), data.is_selected()];
+      let row_values = vec![data.tld.clone(), data.name.clone(), data.is_selected(), data.policy_summary()];
       let row_style = get_row_style(i, &self.colors);
       get_table_row(row_values, row_style)
     });
@@ -76,7 +76,8 @@ impl BaseTable<Extension> {
     let widths = vec![
       Constraint::Min(10),
       Constraint::Min(20),
-      Constraint::Min(20)
+      Constraint::Min(20),
+      Constraint::Min(40)
     ];
 
     let t = Table::new(rows, widths)
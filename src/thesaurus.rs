@@ -0,0 +1,67 @@
+/// Bundled synonyms and related words, one word per line followed by its related words (e.g. "app: application, tool")
+/// Seeded in the `thesaurus` table by the migrations and used as the scoring dictionary
+pub const DEFAULT_THESAURUS: &str = r#"
+app: application, tool, program, software, kit
+bank: vault, fund, treasury, reserve, coffer
+bright: shiny, vivid, radiant, brilliant, luminous, gleam
+build: make, craft, forge, construct, assemble, create
+buy: shop, purchase, acquire, order, cart
+care: tend, nurture, heal, aid, help, support
+city: town, metro, urban, borough, capital
+clean: pure, fresh, clear, spotless, tidy
+cloud: sky, nimbus, vapor, mist, haze, cirrus, stratus
+code: script, program, source, logic, syntax
+connect: link, join, bridge, bind, unite, sync
+data: info, facts, stats, metrics, records, bits
+fast: quick, rapid, swift, speedy, brisk, fleet, zip
+fire: flame, blaze, spark, ember, ignite, torch
+fish: catch, reel, angle, trawl
+food: meal, dish, feast, snack, bite, cuisine
+fresh: new, crisp, green, young, novel
+friend: pal, buddy, mate, ally, companion
+game: play, match, quest, sport, arcade
+garden: yard, grove, orchard, bloom, plant
+gold: golden, aurum, gilt, treasure
+green: verdant, leafy, eco, emerald, sage
+happy: glad, joy, merry, cheerful, jolly, sunny
+health: wellness, vitality, fitness, vigor
+home: house, nest, haven, dwelling, abode, hearth
+idea: concept, notion, thought, insight, vision, spark
+journey: trip, voyage, trek, quest, path, route
+learn: study, know, master, grasp, school, academy
+light: glow, beam, ray, shine, lumen, lux
+market: bazaar, mart, shop, store, exchange, souk
+mind: brain, intellect, psyche, wit, think
+money: cash, coin, capital, funds, wealth, pay
+mountain: peak, summit, ridge, alp, crest, hill
+music: song, tune, melody, sound, rhythm, beat
+nature: wild, earth, flora, fauna, wilderness
+net: web, mesh, grid, network, lattice
+ocean: sea, marine, tide, wave, deep, blue
+path: way, trail, road, route, lane, track
+people: folk, crowd, community, tribe, crew
+photo: picture, image, snap, shot, pixel, lens
+place: spot, site, venue, locale, zone, hub
+plan: blueprint, scheme, map, strategy, draft
+power: energy, force, might, strength, volt, surge
+quick: fast, brisk, nimble, agile, instant
+rock: stone, boulder, granite, pebble, slate
+safe: secure, guarded, shield, vault, trusted
+shop: store, boutique, outlet, market, emporium
+smart: clever, bright, sharp, wise, genius, savvy
+space: cosmos, orbit, galaxy, star, void, nova
+speed: velocity, pace, rush, dash, sprint
+star: sun, nova, stellar, astro, comet
+strong: sturdy, robust, solid, mighty, tough
+sun: sol, solar, sunny, ray, dawn, helio
+team: squad, crew, group, guild, band, unit
+tech: technology, digital, cyber, byte, device
+time: clock, hour, moment, tempo, era, chrono
+travel: tour, roam, wander, journey, trip, go
+tree: oak, pine, cedar, maple, birch, grove
+water: aqua, hydro, wave, stream, drop, rain
+wind: breeze, gust, gale, air, zephyr
+word: term, lexicon, phrase, verb, say
+work: job, task, labor, craft, toil, gig
+world: globe, earth, planet, terra, global
+"#;
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use lazy_static::lazy_static;
+use crate::database::trademarks_api::find_all_trademarks;
+use crate::homographs::get_skeleton;
+use crate::models::Trademark;
+use crate::similarity::edit_distance;
+use crate::validation::to_unicode;
+
+/// Marks shorter than this are only matched exactly
+const MIN_CLOSE_MATCH_LENGTH: usize = 4;
+/// Marks from this length are flagged when they are contained in a name (e.g. "google" in "googlemaps")
+const MIN_CONTAINED_LENGTH: usize = 5;
+
+/// Nice classes given with `--trademark-classes`, every class is checked when empty
+static SELECTED_CLASSES: OnceLock<Vec<u32>> = OnceLock::new();
+
+lazy_static! {
+  /// Imported trademarks, loaded once from the `trademark` table
+  static ref TRADEMARKS: Vec<(String, Trademark)> = find_all_trademarks()
+      .into_iter()
+      .map(|trademark| (normalize_mark(&trademark.mark), trademark))
+      .collect();
+
+  /// Conflicts already found, since tables check every name each time they are drawn
+  static ref CONFLICTS_CACHE: Mutex<HashMap<String, Vec<TrademarkConflict>>> = Mutex::new(HashMap::new());
+}
+
+/// Conflict between a name and a registered mark
+#[derive(Debug, Clone)]
+pub struct TrademarkConflict {
+  pub(crate) trademark: Trademark,
+  pub(crate) kind: &'static str,
+}
+
+/// Lowercase letters and digits of a mark or a name (e.g. "Coca-Cola" => "cocacola")
+pub fn normalize_mark(mark: &str) -> String {
+  mark.to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect()
+}
+
+/// Only check the marks registered in some Nice classes (e.g. [9, 42] for software)
+pub fn set_selected_classes(classes: Vec<u32>) {
+  let _ = SELECTED_CLASSES.set(classes);
+}
+
+/// Find the imported marks conflicting with a name, exact matches first
+pub fn find_trademark_conflicts(name: &str) -> Vec<TrademarkConflict> {
+  let mut cache = CONFLICTS_CACHE.lock().expect("Failed to lock the trademark conflicts cache");
+
+  cache
+      .entry(name.to_string())
+      .or_insert_with(|| {
+        let classes = SELECTED_CLASSES.get().cloned().unwrap_or_default();
+        find_trademark_conflicts_in(name, &TRADEMARKS, &classes)
+      })
+      .clone()
+}
+
+/// Compare a name with every mark of the selected classes
+/// - exact: same letters and digits (e.g. "coca-cola" for "Coca-Cola")
+/// - lookalike: same letters once confusable characters are replaced (e.g. "gооgle" with Cyrillic "о")
+/// - close: one or two typos away, depending on the length of the mark (e.g. "gogle")
+/// - contains: the mark is part of the name (e.g. "googlemaps")
+pub fn find_trademark_conflicts_in(name: &str, trademarks: &[(String, Trademark)], classes: &[u32]) -> Vec<TrademarkConflict> {
+  let name = normalize_mark(&to_unicode(name));
+  let skeleton = get_skeleton(&name);
+  let mut conflicts: Vec<TrademarkConflict> = vec![];
+
+  for (mark, trademark) in trademarks.iter().filter(|(_, trademark)| is_in_classes(trademark, classes)) {
+    let length = mark.chars().count();
+    let max_distance = if length >= 8 { 2 } else { 1 };
+
+    let kind = if *mark == name {
+      "Exact"
+    } else if *mark == skeleton {
+      "Lookalike"
+    } else if length >= MIN_CLOSE_MATCH_LENGTH
+        && name.chars().count().abs_diff(length) <= max_distance
+        && edit_distance(mark, &skeleton) <= max_distance {
+      "Close"
+    } else if length >= MIN_CONTAINED_LENGTH && skeleton.contains(mark.as_str()) {
+      "Contains"
+    } else {
+      continue;
+    };
+
+    conflicts.push(TrademarkConflict { trademark: trademark.clone(), kind });
+  }
+
+  conflicts.sort_by_key(|conflict| ["Exact", "Lookalike", "Close", "Contains"].iter().position(|kind| *kind == conflict.kind));
+  conflicts
+}
+
+fn is_in_classes(trademark: &Trademark, classes: &[u32]) -> bool {
+  classes.is_empty()
+      || trademark.classes.is_empty()
+      || trademark.classes.split(',').filter_map(|class| class.parse::<u32>().ok()).any(|class| classes.contains(&class))
+}
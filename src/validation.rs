@@ -0,0 +1,239 @@
+/// Maximum length of a label (e.g. "example" in "example.com")
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// Maximum length of a complete domain name
+const MAX_DOMAIN_NAME_LENGTH: usize = 253;
+
+/// Common public suffixes made of several labels, under which names are registered (e.g. "example.co.uk")
+const MULTI_LABEL_SUFFIXES: [&str; 32] = [
+  "co.uk", "org.uk", "me.uk", "ltd.uk", "plc.uk", "ac.uk", "gov.uk",
+  "com.au", "net.au", "org.au", "co.nz", "net.nz", "org.nz",
+  "co.jp", "ne.jp", "or.jp", "co.kr", "co.in", "net.in", "org.in",
+  "com.br", "net.br", "org.br", "com.mx", "com.ar", "com.cn", "com.tw", "com.hk",
+  "co.za", "com.sg", "com.tr", "co.il",
+];
+
+/// Clean a domain name pasted by the user
+/// Removes whitespace, scheme, credentials, `www.`, port, path, query, fragment and trailing dots
+/// (e.g. " https://www.Example.com/about?q=1 " => "example.com")
+pub fn normalize_domain_name(input: &str) -> String {
+  let mut name = input.trim().to_lowercase();
+
+  if let Some(index) = name.find("://") {
+    name = name[index + 3..].to_string();
+  }
+  if let Some(index) = name.find(['/', '?', '#']) {
+    name.truncate(index);
+  }
+  if let Some(index) = name.rfind('@') {
+    name = name[index + 1..].to_string();
+  }
+  if let Some(index) = name.rfind(':') {
+    if name[index + 1..].chars().all(|c| c.is_ascii_digit()) {
+      name.truncate(index);
+    }
+  }
+  if let Some(stripped) = name.strip_prefix("www.") {
+    name = stripped.to_string();
+  }
+
+  name.trim_end_matches('.').trim().to_string()
+}
+
+/// Check that a label follows the LDH rule (letters, digits, hyphens)
+/// and the length and hyphen rules of the DNS
+pub fn validate_label(label: &str) -> Result<(), String> {
+  if label.is_empty() {
+    return Err(String::from("Invalid domain name: empty label"));
+  }
+
+  if label.len() > MAX_LABEL_LENGTH {
+    return Err(format!(
+      "Invalid label '{}': {} characters, labels are limited to {} characters",
+      label, label.len(), MAX_LABEL_LENGTH
+    ));
+  }
+
+  if let Some(c) = label.chars().find(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || *c == '-')) {
+    return Err(format!(
+      "Invalid label '{}': '{}' is not allowed, only letters, digits and hyphens can be used",
+      label, c
+    ));
+  }
+
+  if label.starts_with('-') || label.ends_with('-') {
+    return Err(format!("Invalid label '{}': labels cannot start or end with a hyphen", label));
+  }
+
+  if label.get(2..4) == Some("--") && !label.starts_with("xn--") {
+    return Err(format!(
+      "Invalid label '{}': hyphens in 3rd and 4th positions are reserved for internationalized names (xn--)",
+      label
+    ));
+  }
+
+  Ok(())
+}
+
+/// Check every label of a complete domain name (e.g. "example.com") and its total length
+pub fn validate_domain_name(name: &str) -> Result<(), String> {
+  if name.len() > MAX_DOMAIN_NAME_LENGTH {
+    return Err(format!(
+      "Invalid domain name '{}': {} characters, domain names are limited to {} characters",
+      name, name.len(), MAX_DOMAIN_NAME_LENGTH
+    ));
+  }
+
+  name.split('.').try_for_each(validate_label)
+}
+
+/// Convert an internationalized label to its ASCII form (e.g. "café" => "xn--caf-dma") using UTS-46
+/// processing, then validate it
+pub fn to_ascii_label(label: &str) -> Result<String, String> {
+  let label = if label.is_ascii() {
+    label.to_string()
+  } else {
+    idna::domain_to_ascii_strict(label)
+        .map_err(|_| format!("Invalid internationalized label '{}'", label))?
+  };
+
+  validate_label(&label)?;
+  Ok(label)
+}
+
+/// Unicode form of an ASCII domain name (e.g. "xn--caf-dma.com" => "café.com")
+pub fn to_unicode(name: &str) -> String {
+  match idna::domain_to_unicode(name) {
+    (unicode, Ok(_)) => unicode,
+    (_, Err(_)) => name.to_string(),
+  }
+}
+
+/// Split a domain name into its registrable label and its extension, using the longest known suffix
+/// (e.g. "shop.example.co.uk" => ("example", "co.uk")), or the last label if no suffix is known
+pub fn split_domain_name(name: &str, known_tlds: &[String]) -> Option<(String, String)> {
+  let labels: Vec<&str> = name.split('.').collect();
+
+  if labels.len() < 2 {
+    return None;
+  }
+
+  let mut suffix_length = 1;
+  for length in (1..labels.len()).rev() {
+    let suffix = labels[labels.len() - length..].join(".");
+
+    if MULTI_LABEL_SUFFIXES.contains(&suffix.as_str()) || known_tlds.contains(&suffix) {
+      suffix_length = length;
+      break;
+    }
+  }
+
+  let label = labels[labels.len() - suffix_length - 1].to_string();
+  let tld = labels[labels.len() - suffix_length..].join(".");
+  Some((label, tld))
+}
+
+/// Normalize, convert and validate a complete domain name typed by the user (e.g. "Café.fr" => ("xn--caf-dma", "fr"))
+pub fn parse_domain_name(input: &str, known_tlds: &[String]) -> Result<(String, String), String> {
+  let name = normalize_domain_name(input);
+
+  let (label, tld) = match split_domain_name(&name, known_tlds) {
+    Some(result) => result,
+    None => return Err(format!("Invalid domain name '{}': no extension found", input)),
+  };
+
+  let label = to_ascii_label(&label)?;
+  let tld = tld
+      .split('.')
+      .map(to_ascii_label)
+      .collect::<Result<Vec<String>, String>>()?
+      .join(".");
+
+  validate_domain_name(&format!("{}.{}", label, tld))?;
+  Ok((label, tld))
+}
+
+/// Check if a name typed by the user is a complete domain name (e.g. "example.io") rather than a label
+pub fn is_domain_name(input: &str) -> bool {
+  normalize_domain_name(input).contains('.')
+}
+
+/// Normalize, convert and validate a label typed by the user
+pub fn parse_label(input: &str) -> Result<String, String> {
+  to_ascii_label(&normalize_domain_name(input))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn label_length_limit() {
+    assert!(validate_label(&"a".repeat(63)).is_ok());
+    assert!(validate_label(&"a".repeat(64)).unwrap_err().contains("64 characters"));
+    assert!(validate_label("").unwrap_err().contains("empty label"));
+    assert!(validate_label("a").is_ok());
+  }
+
+  #[test]
+  fn domain_name_length_limit() {
+    // 4 labels of 61 characters and ".com": 4 * 62 + 3 = 251 characters, then 253 and 254 characters
+    let name = format!("{}.com", vec!["a".repeat(61); 4].join("."));
+    assert_eq!(name.len(), 251);
+    assert!(validate_domain_name(&name).is_ok());
+    assert!(validate_domain_name(&format!("a.{}", name)).is_ok());
+    assert!(validate_domain_name(&format!("ab.{}", name)).unwrap_err().contains("254 characters"));
+  }
+
+  #[test]
+  fn letters_digits_and_hyphens_only() {
+    assert!(validate_label("my-app2").is_ok());
+    assert!(validate_label("my_app").unwrap_err().contains("'_' is not allowed"));
+    assert!(validate_label("My-app").is_err());
+    assert!(validate_label("-app").unwrap_err().contains("cannot start or end with a hyphen"));
+    assert!(validate_label("app-").is_err());
+  }
+
+  #[test]
+  fn hyphens_in_third_and_fourth_positions() {
+    assert!(validate_label("xn--caf-dma").is_ok());
+    assert!(validate_label("ab--cd").unwrap_err().contains("reserved for internationalized names"));
+    assert!(validate_label("abc--d").is_ok());
+  }
+
+  #[test]
+  fn internationalized_labels() {
+    assert_eq!(to_ascii_label("café"), Ok(String::from("xn--caf-dma")));
+    assert_eq!(to_ascii_label("example"), Ok(String::from("example")));
+    assert!(to_ascii_label("exa mple").is_err());
+    assert_eq!(to_unicode("xn--caf-dma.com"), "café.com");
+  }
+
+  #[test]
+  fn normalized_inputs() {
+    assert_eq!(normalize_domain_name(" https://user@www.Example.com:8080/about?q=1#top "), "example.com");
+    assert_eq!(normalize_domain_name("example.com."), "example.com");
+    assert!(is_domain_name("example.io"));
+    assert!(!is_domain_name("example"));
+  }
+
+  #[test]
+  fn multi_label_suffixes() {
+    let known_tlds: Vec<String> = vec![String::from("com"), String::from("uk")];
+    assert_eq!(split_domain_name("shop.example.co.uk", &known_tlds), Some((String::from("example"), String::from("co.uk"))));
+    assert_eq!(split_domain_name("example.uk", &known_tlds), Some((String::from("example"), String::from("uk"))));
+    assert_eq!(split_domain_name("blog.example.com", &known_tlds), Some((String::from("example"), String::from("com"))));
+    assert_eq!(split_domain_name("example.dev", &known_tlds), Some((String::from("example"), String::from("dev"))));
+    assert_eq!(split_domain_name("example", &known_tlds), None);
+  }
+
+  #[test]
+  fn parsed_domain_names() {
+    let known_tlds: Vec<String> = vec![String::from("fr")];
+    assert_eq!(parse_domain_name("Café.fr", &known_tlds), Ok((String::from("xn--caf-dma"), String::from("fr"))));
+    assert_eq!(parse_domain_name("www.example.com.au", &known_tlds), Ok((String::from("example"), String::from("com.au"))));
+    assert!(parse_domain_name("exam_ple.fr", &known_tlds).is_err());
+    assert!(parse_domain_name("example", &known_tlds).unwrap_err().contains("no extension found"));
+    assert_eq!(parse_label("  Example  "), Ok(String::from("example")));
+  }
+}
@@ -0,0 +1,107 @@
+use lazy_static::lazy_static;
+use crate::database::migrations::get_default_value_rules;
+use crate::database::value_rules_api::find_all_value_rules;
+use crate::models::ValueRule;
+use crate::scoring::dictionary_coverage;
+use crate::validation::to_unicode;
+
+lazy_static! {
+  /// Valuation rules, loaded once from the `value_rule` table, the default rules when the table is empty or missing
+  static ref VALUE_RULES: Vec<ValueRule> = match find_all_value_rules() {
+    rules if rules.is_empty() => get_default_value_rules(),
+    rules => rules,
+  };
+}
+
+/// Valuation of an available domain, with the rules which gave its points
+pub struct Valuation {
+  pub tier: String,
+  pub points: i64,
+  pub reasons: Vec<String>,
+}
+
+/// Evaluate a domain with the stored rule set
+pub fn evaluate(label: &str, tld: &str) -> Valuation {
+  evaluate_with_rules(label, tld, &VALUE_RULES)
+}
+
+/// Add the points of every rule matching a domain, then find its tier
+/// (e.g. "sky.com" => Premium, 87 points: Very short, .com, Dictionary words only, Letters only)
+pub fn evaluate_with_rules(label: &str, tld: &str, rules: &[ValueRule]) -> Valuation {
+  let mut points = 0;
+  let mut reasons: Vec<String> = vec![];
+
+  for rule in rules.iter().filter(|rule| rule.kind != "tier") {
+    if matches_rule(label, tld, rule) {
+      points += rule.points;
+      reasons.push(format!("{} {:+}", rule.description, rule.points));
+    }
+  }
+
+  Valuation {
+    tier: get_tier(points, rules),
+    points,
+    reasons,
+  }
+}
+
+/// Tier with the highest threshold reached by the points, the lowest tier otherwise
+pub fn get_tier(points: i64, rules: &[ValueRule]) -> String {
+  let mut tiers: Vec<&ValueRule> = rules.iter().filter(|rule| rule.kind == "tier").collect();
+  tiers.sort_by_key(|tier| std::cmp::Reverse(tier.points));
+
+  tiers
+      .iter()
+      .find(|tier| points >= tier.points)
+      .or(tiers.last())
+      .map(|tier| tier.value.clone())
+      .unwrap_or_default()
+}
+
+/// Check if a rule matches a domain
+/// - length: number of characters in a range (e.g. "4-5") or exact (e.g. "3")
+/// - tld: extension (e.g. "com")
+/// - dictionary: percentage of the label made of dictionary words in a range (e.g. "50-99") or minimum (e.g. "100")
+/// - keyword: comma separated keywords the label starts or ends with (e.g. "pay,bank")
+/// - charset: "letters" (letters only), "digits", "hyphen" or "idn" (internationalized)
+fn matches_rule(label: &str, tld: &str, rule: &ValueRule) -> bool {
+  let unicode_label = to_unicode(label);
+
+  match rule.kind.as_str() {
+    "length" => {
+      let length = unicode_label.chars().count();
+      let (min, max) = rule.value.split_once('-').unwrap_or((&rule.value, &rule.value));
+
+      match (min.trim().parse::<usize>(), max.trim().parse::<usize>()) {
+        (Ok(min), Ok(max)) => (min..=max).contains(&length),
+        _ => false,
+      }
+    }
+    "tld" => rule.value.trim_start_matches('.').eq_ignore_ascii_case(tld),
+    "dictionary" => {
+      // Whole percents, so that "50-99" and "100" never match the same label
+      let percent = (dictionary_coverage(&unicode_label) * 100.0).floor() as usize;
+
+      match rule.value.split_once('-') {
+        Some((min, max)) => match (min.trim().parse::<usize>(), max.trim().parse::<usize>()) {
+          (Ok(min), Ok(max)) => (min..=max).contains(&percent),
+          _ => false,
+        },
+        None => rule.value.trim().parse::<usize>().is_ok_and(|min| percent >= min),
+      }
+    }
+    "keyword" => rule.value
+        .split(',')
+        .map(|keyword| keyword.trim().to_lowercase())
+        .filter(|keyword| !keyword.is_empty())
+        .any(|keyword| unicode_label.starts_with(&keyword) || unicode_label.ends_with(&keyword)),
+    "charset" => match rule.value.as_str() {
+      "letters" => label.chars().all(|c| c.is_ascii_alphabetic()),
+      "digits" => label.chars().any(|c| c.is_ascii_digit()),
+      "hyphen" => unicode_label.contains('-'),
+      "idn" => label.starts_with("xn--"),
+      _ => false,
+    },
+    _ => false,
+  }
+}